@@ -15,20 +15,82 @@ use utoipa::openapi::security::{ApiKeyValue, HttpAuthScheme};
 
         crate::module::user::topic::controller::page_topic,
         crate::module::user::topic::controller::deleted_topics,
+        crate::module::user::topic::controller::published_topics,
 
         crate::module::user::lesson::controller::page_lesson,
         crate::module::user::lesson::controller::deleted_lessons,
+        crate::module::user::lesson::controller::published_lessons,
         crate::module::user::lesson::controller::increase_listen_count,
         crate::module::user::lesson::controller::update_lesson_progress,
         crate::module::user::lesson::controller::set_favourite,
         crate::module::user::lesson::controller::remove_favourite,
+        crate::module::user::lesson::controller::get_transcript,
+        crate::module::user::lesson::controller::search_transcript,
 
         crate::module::user::snip::controller::create_snip,
         crate::module::user::snip::controller::update_snip,
         crate::module::user::snip::controller::delete_snip,
         crate::module::user::snip::controller::page_snip,
         crate::module::user::snip::controller::deleted_snips,
-        crate::module::user::snip::controller::count_snip
+        crate::module::user::snip::controller::count_snip,
+        crate::module::user::snip::controller::export_snip,
+        crate::module::user::snip::controller::page_trashed_snip,
+        crate::module::user::snip::controller::restore_snip,
+
+        crate::module::user::share::controller::create_share,
+        crate::module::user::share::controller::list_shares,
+        crate::module::user::share::controller::revoke_share,
+        crate::module::user::share::controller::view_share,
+
+        crate::module::user::search::controller::search,
+
+        crate::module::user::playlist::controller::create_playlist,
+        crate::module::user::playlist::controller::page_playlist,
+        crate::module::user::playlist::controller::get_playlist,
+        crate::module::user::playlist::controller::update_playlist,
+        crate::module::user::playlist::controller::delete_playlist,
+        crate::module::user::playlist::controller::page_playlist_item,
+        crate::module::user::playlist::controller::add_playlist_item,
+        crate::module::user::playlist::controller::remove_playlist_item,
+        crate::module::user::playlist::controller::reorder_playlist_items,
+        crate::module::user::playlist::controller::share_playlist,
+        crate::module::user::playlist::controller::unshare_playlist,
+        crate::module::user::playlist::controller::view_shared_playlist,
+        crate::module::user::playlist::controller::page_shared_playlist_item,
+        crate::module::user::queue::controller::get_queue,
+        crate::module::user::queue::controller::enqueue_lesson,
+        crate::module::user::queue::controller::play_lesson_next,
+        crate::module::user::queue::controller::dequeue_lesson,
+        crate::module::user::queue::controller::reorder_queue,
+        crate::module::user::queue::controller::set_queue_current,
+        crate::module::user::queue::controller::clear_queue,
+        crate::module::user::history::controller::page_history,
+        crate::module::user::history::controller::delete_history_entry,
+        crate::module::user::history::controller::clear_history,
+        crate::module::user::listen::controller::heartbeat,
+        crate::module::user::me::controller::get_stats,
+        crate::module::user::me::controller::get_settings,
+        crate::module::user::me::controller::update_settings,
+        crate::module::user::achievement::controller::list_achievements,
+        crate::module::user::follow::controller::follow_author,
+        crate::module::user::follow::controller::unfollow_author,
+        crate::module::user::follow::controller::subscribe_topic,
+        crate::module::user::follow::controller::unsubscribe_topic,
+        crate::module::user::follow::controller::page_followed_authors,
+        crate::module::user::follow::controller::page_subscribed_topics,
+        crate::module::user::follow::controller::page_feed,
+        crate::module::user::notification::controller::page_notification,
+        crate::module::user::notification::controller::unread_count,
+        crate::module::user::notification::controller::mark_read,
+        crate::module::user::notification::controller::mark_all_read,
+        crate::module::user::notification::controller::register_device,
+        crate::module::user::notification::controller::remove_device,
+        crate::module::user::notification::controller::get_settings,
+        crate::module::user::notification::controller::update_settings,
+        crate::module::user::review::controller::page_review,
+        crate::module::user::review::controller::get_own_review,
+        crate::module::user::review::controller::upsert_review,
+        crate::module::user::review::controller::delete_review
     ),
     components(
         schemas(
@@ -53,6 +115,7 @@ pub struct UserApiDoc;
 
         crate::module::common::file::controller::upload,
         crate::module::common::file::controller::upload_url,
+        crate::module::common::file::controller::upload_complete,
         crate::module::common::file::controller::download_file,
 
         crate::module::admin::author::controller::create_author,
@@ -60,18 +123,46 @@ pub struct UserApiDoc;
         crate::module::admin::author::controller::get_author,
         crate::module::admin::author::controller::delete_author,
         crate::module::admin::author::controller::page_author,
+        crate::module::admin::author::controller::get_author_translations,
+        crate::module::admin::author::controller::update_author_translation,
+        crate::module::admin::author::controller::delete_author_translation,
 
         crate::module::admin::topic::controller::create_topic,
         crate::module::admin::topic::controller::update_topic,
         crate::module::admin::topic::controller::get_topic,
         crate::module::admin::topic::controller::delete_topic,
         crate::module::admin::topic::controller::page_topic,
+        crate::module::admin::topic::controller::reorder_lessons,
+        crate::module::admin::topic::controller::get_topic_translations,
+        crate::module::admin::topic::controller::update_topic_translation,
+        crate::module::admin::topic::controller::delete_topic_translation,
 
         crate::module::admin::lesson::controller::create_lesson,
         crate::module::admin::lesson::controller::update_lesson,
         crate::module::admin::lesson::controller::get_lesson,
         crate::module::admin::lesson::controller::delete_lesson,
         crate::module::admin::lesson::controller::page_lesson,
+        crate::module::admin::lesson::controller::get_transcript,
+        crate::module::admin::lesson::controller::update_transcript,
+        crate::module::admin::lesson::controller::upload_transcript,
+        crate::module::admin::lesson::controller::delete_transcript,
+        crate::module::admin::lesson::controller::update_chapters,
+        crate::module::admin::lesson::controller::import_chapters,
+        crate::module::admin::lesson::controller::get_revisions,
+        crate::module::admin::lesson::controller::rollback_lesson,
+        crate::module::admin::lesson::controller::get_lesson_translations,
+        crate::module::admin::lesson::controller::update_lesson_translation,
+        crate::module::admin::lesson::controller::delete_lesson_translation,
+
+        crate::module::admin::file::controller::gc_report,
+        crate::module::admin::file::controller::metadata,
+
+        crate::module::admin::trash::controller::page_trash,
+        crate::module::admin::trash::controller::restore_trash_item,
+
+        crate::module::admin::review::controller::page_review,
+        crate::module::admin::review::controller::hide_review,
+        crate::module::admin::review::controller::unhide_review,
     ),
     components(
         schemas(
@@ -23,6 +23,9 @@ pub fn build_app(state: AppState) -> Router {
         .nest("/v1/admin", admin::author::routes::routes())
         .nest("/v1/admin", admin::topic::routes::routes())
         .nest("/v1/admin", admin::lesson::routes::routes())
+        .nest("/v1/admin", admin::file::routes::routes())
+        .nest("/v1/admin", admin::trash::routes::routes())
+        .nest("/v1/admin", admin::review::routes::routes())
         .layer(middleware::from_fn(origin_middleware))
         .merge(
             SwaggerUi::new("/admin/docs")
@@ -36,6 +39,17 @@ pub fn build_app(state: AppState) -> Router {
         .nest("/v1/user", user::topic::routes::routes())
         .nest("/v1/user", user::lesson::routes::routes())
         .nest("/v1/user", user::snip::routes::routes())
+        .nest("/v1/user", user::share::routes::routes())
+        .nest("/v1/user", user::search::routes::routes())
+        .nest("/v1/user", user::playlist::routes::routes())
+        .nest("/v1/user", user::queue::routes::routes())
+        .nest("/v1/user", user::history::routes::routes())
+        .nest("/v1/user", user::listen::routes::routes())
+        .nest("/v1/user", user::me::routes::routes())
+        .nest("/v1/user", user::achievement::routes::routes())
+        .nest("/v1/user", user::follow::routes::routes())
+        .nest("/v1/user", user::notification::routes::routes())
+        .nest("/v1/user", user::review::routes::routes())
         .nest("/v1/file", common::file::routes::routes())
         .layer(middleware::from_fn(cache_control_middleware))
         .merge(
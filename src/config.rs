@@ -8,10 +8,29 @@ pub struct AppConfig {
     pub jwt_secret_refresh: String,
     pub jwt_secret_access: String,
     pub telegram_bot_token: String,
+    pub telegram_api_base_url: String,
     pub r2_endpoint_url: String,
     pub r2_bucket_name: String,
     pub client_origin: String,
     pub base_path: String,
+    pub gc_interval_minutes: u64,
+    pub gc_grace_period_hours: i64,
+    pub clip_intro_path: Option<String>,
+    pub clip_url_ttl_minutes: u64,
+    pub public_base_url: String,
+    pub publish_interval_minutes: u64,
+    pub trash_retention_days: i64,
+    pub listen_count_threshold: f64,
+    pub notify_interval_minutes: u64,
+    pub streak_reminder_hour: i32,
+    pub weekly_summary_hour: i32,
+    pub fcm_project_id: Option<String>,
+    pub apns_key_path: Option<String>,
+    pub apns_key_id: Option<String>,
+    pub apns_team_id: Option<String>,
+    pub apns_topic: Option<String>,
+    pub apns_sandbox: bool,
+    pub push_dev_sink: bool,
 }
 
 impl AppConfig {
@@ -22,10 +41,41 @@ impl AppConfig {
             jwt_secret_refresh: env::var("JWT_SECRET_REFRESH").expect("JWT_SECRET_REFRESH missing"),
             jwt_secret_access: env::var("JWT_SECRET_ACCESS").expect("JWT_SECRET_ACCESS missing"),
             telegram_bot_token: env::var("TELEGRAM_BOT_TOKEN").expect("TELEGRAM_BOT_TOKEN missing"),
+            telegram_api_base_url: env::var("TELEGRAM_API_BASE_URL")
+                .unwrap_or_else(|_| "https://api.telegram.org".to_string()),
             r2_endpoint_url: env::var("R2_ENDPOINT_URL").expect("R2_ENDPOINT_URL missing"),
             r2_bucket_name: env::var("R2_BUCKET_NAME").expect("R2_BUCKET_NAME missing"),
             client_origin: env::var("CLIENT_ORIGIN").expect("CLIENT_ORIGIN missing"),
             base_path: env::var("BASE_PATH").expect("BASE_PATH missing"),
+            gc_interval_minutes: env::var("GC_INTERVAL_MINUTES").ok()
+                .and_then(|v| v.parse().ok()).unwrap_or(60),
+            gc_grace_period_hours: env::var("GC_GRACE_PERIOD_HOURS").ok()
+                .and_then(|v| v.parse().ok()).unwrap_or(24),
+            clip_intro_path: env::var("CLIP_INTRO_PATH").ok(),
+            clip_url_ttl_minutes: env::var("CLIP_URL_TTL_MINUTES").ok()
+                .and_then(|v| v.parse().ok()).unwrap_or(60),
+            public_base_url: env::var("PUBLIC_BASE_URL").expect("PUBLIC_BASE_URL missing"),
+            publish_interval_minutes: env::var("PUBLISH_INTERVAL_MINUTES").ok()
+                .and_then(|v| v.parse().ok()).unwrap_or(1),
+            trash_retention_days: env::var("TRASH_RETENTION_DAYS").ok()
+                .and_then(|v| v.parse().ok()).unwrap_or(30),
+            listen_count_threshold: env::var("LISTEN_COUNT_THRESHOLD").ok()
+                .and_then(|v| v.parse().ok()).unwrap_or(0.5),
+            notify_interval_minutes: env::var("NOTIFY_INTERVAL_MINUTES").ok()
+                .and_then(|v| v.parse().ok()).unwrap_or(5),
+            streak_reminder_hour: env::var("STREAK_REMINDER_HOUR").ok()
+                .and_then(|v| v.parse().ok()).unwrap_or(20),
+            weekly_summary_hour: env::var("WEEKLY_SUMMARY_HOUR").ok()
+                .and_then(|v| v.parse().ok()).unwrap_or(9),
+            fcm_project_id: env::var("FCM_PROJECT_ID").ok(),
+            apns_key_path: env::var("APNS_KEY_PATH").ok(),
+            apns_key_id: env::var("APNS_KEY_ID").ok(),
+            apns_team_id: env::var("APNS_TEAM_ID").ok(),
+            apns_topic: env::var("APNS_TOPIC").ok(),
+            apns_sandbox: env::var("APNS_SANDBOX").ok()
+                .and_then(|v| v.parse().ok()).unwrap_or(false),
+            push_dev_sink: env::var("PUSH_DEV_SINK").ok()
+                .and_then(|v| v.parse().ok()).unwrap_or(false),
         }
     }
 }
@@ -0,0 +1,9 @@
+use crate::module::common::enums::Achievement;
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+#[derive(Debug, FromRow)]
+pub struct UserAchievementEntity {
+    pub achievement: Achievement,
+    pub earned_at: OffsetDateTime
+}
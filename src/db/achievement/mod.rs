@@ -0,0 +1,2 @@
+pub mod repo;
+pub mod entity;
\ No newline at end of file
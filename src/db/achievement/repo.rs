@@ -0,0 +1,52 @@
+use crate::db::achievement::entity::UserAchievementEntity;
+use crate::module::common::enums::Achievement;
+use sqlx::PgPool;
+
+pub async fn earned(db: &PgPool, user_id: i64) -> Result<Vec<UserAchievementEntity>, sqlx::Error> {
+    sqlx::query_as::<_, UserAchievementEntity>(
+        r#"
+        SELECT achievement, earned_at
+        FROM user_achievement
+        WHERE user_id = $1
+        ORDER BY earned_at, id
+        "#
+    )
+        .bind(user_id)
+        .fetch_all(db)
+        .await
+}
+
+/// Returns `None` when the user already had the achievement.
+pub async fn award(
+    db: &PgPool,
+    user_id: i64,
+    achievement: Achievement
+) -> Result<Option<UserAchievementEntity>, sqlx::Error> {
+    sqlx::query_as::<_, UserAchievementEntity>(
+        r#"
+        INSERT INTO user_achievement (user_id, achievement)
+        VALUES ($1, $2)
+        ON CONFLICT (user_id, achievement) DO NOTHING
+        RETURNING achievement, earned_at
+        "#
+    )
+        .bind(user_id)
+        .bind(achievement)
+        .fetch_optional(db)
+        .await
+}
+
+/// Distinct authors of the lessons the user has a counted listen of.
+pub async fn authors_listened(db: &PgPool, user_id: i64) -> Result<i64, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (i64,)>(
+        r#"
+        SELECT COUNT(DISTINCT lesson.author_id)
+        FROM listen_session
+        JOIN lesson ON lesson.id = listen_session.lesson_id
+        WHERE listen_session.user_id = $1 AND listen_session.counted_at IS NOT NULL
+        "#
+    )
+        .bind(user_id)
+        .fetch_one(db)
+        .await?.0)
+}
@@ -0,0 +1,29 @@
+use crate::module::common::enums::{AssetStorage, UploadStatus};
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+#[derive(Debug, FromRow)]
+pub struct AssetEntity {
+    pub id: i64,
+    pub path: String,
+    pub storage: AssetStorage,
+    pub file_size: i64,
+    pub orphaned_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+    pub mime_type: Option<String>,
+    pub checksum: Option<String>,
+    pub status: UploadStatus,
+    pub uploaded_by: Option<i64>,
+    pub completed_at: Option<OffsetDateTime>
+}
+
+#[derive()]
+pub struct AssetInput {
+    pub path: String,
+    pub storage: AssetStorage,
+    pub file_size: i64,
+    pub mime_type: Option<String>,
+    pub checksum: Option<String>,
+    pub status: UploadStatus,
+    pub uploaded_by: Option<i64>
+}
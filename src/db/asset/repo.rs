@@ -0,0 +1,116 @@
+use crate::db::asset::entity::{AssetEntity, AssetInput};
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+pub async fn insert(db: &PgPool, asset: AssetInput) -> Result<AssetEntity, sqlx::Error> {
+    sqlx::query_as::<_, AssetEntity>(
+        r#"
+            INSERT INTO uploaded_asset (path, storage, file_size, mime_type, checksum, status, uploaded_by, completed_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, CASE WHEN $6 = 'completed'::upload_status THEN NOW() END)
+            ON CONFLICT (path) DO UPDATE
+                SET file_size = EXCLUDED.file_size,
+                    mime_type = EXCLUDED.mime_type,
+                    checksum = EXCLUDED.checksum,
+                    status = EXCLUDED.status,
+                    uploaded_by = EXCLUDED.uploaded_by,
+                    completed_at = EXCLUDED.completed_at,
+                    orphaned_at = NULL
+            RETURNING *
+            "#,
+    )
+        .bind(asset.path)
+        .bind(asset.storage)
+        .bind(asset.file_size)
+        .bind(asset.mime_type)
+        .bind(asset.checksum)
+        .bind(asset.status)
+        .bind(asset.uploaded_by)
+        .fetch_one(db)
+        .await
+}
+
+pub async fn get_by_path(db: &PgPool, path: &str) -> Result<Option<AssetEntity>, sqlx::Error> {
+    sqlx::query_as::<_, AssetEntity>("SELECT * FROM uploaded_asset WHERE path = $1")
+        .bind(path)
+        .fetch_optional(db)
+        .await
+}
+
+pub async fn complete(db: &PgPool, id: i64) -> Result<AssetEntity, sqlx::Error> {
+    sqlx::query_as::<_, AssetEntity>(
+        r#"
+        UPDATE uploaded_asset
+        SET status = 'completed',
+            completed_at = NOW()
+        WHERE id = $1
+        RETURNING *
+        "#,
+    )
+        .bind(id)
+        .fetch_one(db)
+        .await
+}
+
+/// Clears `orphaned_at` on assets that became referenced again and stamps
+/// assets that lost their last reference. Returns `(released, orphaned)`.
+pub async fn mark_orphans(db: &PgPool) -> Result<(u64, u64), sqlx::Error> {
+    let released = sqlx::query(
+        r#"
+        UPDATE uploaded_asset SET orphaned_at = NULL
+        WHERE orphaned_at IS NOT NULL
+          AND EXISTS (SELECT 1 FROM uploaded_asset_reference r WHERE r.path = uploaded_asset.path)
+        "#,
+    )
+        .execute(db)
+        .await?
+        .rows_affected();
+
+    let orphaned = sqlx::query(
+        r#"
+        UPDATE uploaded_asset SET orphaned_at = NOW()
+        WHERE orphaned_at IS NULL
+          AND NOT EXISTS (SELECT 1 FROM uploaded_asset_reference r WHERE r.path = uploaded_asset.path)
+        "#,
+    )
+        .execute(db)
+        .await?
+        .rows_affected();
+
+    Ok((released, orphaned))
+}
+
+pub async fn unreferenced(db: &PgPool) -> Result<Vec<AssetEntity>, sqlx::Error> {
+    sqlx::query_as::<_, AssetEntity>(
+        r#"
+        SELECT * FROM uploaded_asset
+        WHERE NOT EXISTS (SELECT 1 FROM uploaded_asset_reference r WHERE r.path = uploaded_asset.path)
+        ORDER BY COALESCE(orphaned_at, NOW()), id
+        "#,
+    )
+        .fetch_all(db)
+        .await
+}
+
+pub async fn orphaned_before(
+    db: &PgPool,
+    cutoff: OffsetDateTime,
+) -> Result<Vec<AssetEntity>, sqlx::Error> {
+    sqlx::query_as::<_, AssetEntity>(
+        r#"
+        SELECT * FROM uploaded_asset
+        WHERE orphaned_at IS NOT NULL AND orphaned_at < $1
+          AND NOT EXISTS (SELECT 1 FROM uploaded_asset_reference r WHERE r.path = uploaded_asset.path)
+        "#,
+    )
+        .bind(cutoff)
+        .fetch_all(db)
+        .await
+}
+
+pub async fn delete(db: &PgPool, id: i64) -> Result<(), sqlx::Error> {
+    sqlx::query(r#"DELETE FROM uploaded_asset WHERE id = $1"#)
+        .bind(id)
+        .execute(db)
+        .await?;
+    Ok(())
+}
@@ -1,7 +1,10 @@
 use sqlx::{PgConnection, PgPool, Postgres, QueryBuilder};
 use time::OffsetDateTime;
 use crate::db::author::entity::{AuthorEntity, AuthorInput};
+use crate::db::translation;
+use crate::module::common::enums::TranslationKind;
 use crate::module::user::author::dto::AuthorCursor;
+use crate::utils;
 
 pub async fn insert(db: &PgPool, topic: AuthorInput) -> Result<AuthorEntity, sqlx::Error> {
     sqlx::query_as::<_, AuthorEntity>(
@@ -50,6 +53,21 @@ pub async fn delete(
     Ok(result)
 }
 
+pub async fn restore(
+    connection: &mut PgConnection,
+    id: i64
+) -> Result<Option<AuthorEntity>, sqlx::Error> {
+    sqlx::query_as::<_, AuthorEntity>(
+        r#"
+               UPDATE author SET deleted_at = NULL WHERE id = $1 AND deleted_at IS NOT NULL
+               RETURNING *
+            "#
+    )
+        .bind(id)
+        .fetch_optional(connection)
+        .await
+}
+
 pub async fn deleted(
     db: &PgPool,
     since: OffsetDateTime
@@ -74,9 +92,9 @@ fn build_query(
     query.push(" WHERE author.deleted_at IS NULL");
     has_where = true;
     
-    if let Some(search) = search && !search.is_empty() {
-        query.push(if has_where { " AND " } else { " WHERE " })
-            .push("name ILIKE ").push_bind(format!("{}%", search));
+    if let Some(search) = search.as_deref().and_then(utils::search::to_tsquery) {
+        query.push(if has_where { " AND " } else { " WHERE " });
+        translation::repo::push_search(query, TranslationKind::Author, search);
         has_where = true;
     }
     
@@ -156,6 +174,30 @@ pub async fn get_by_id(db: &PgPool, id: i64) -> Result<Option<AuthorEntity>, sql
         .await?)
 }
 
+/// Whether a live author exists, keeping it from being trashed until the
+/// transaction ends.
+pub async fn lock_active(connection: &mut PgConnection, id: i64) -> Result<bool, sqlx::Error> {
+    Ok(sqlx::query("SELECT id FROM author WHERE id = $1 AND deleted_at IS NULL FOR SHARE")
+        .bind(id)
+        .fetch_optional(connection)
+        .await?
+        .is_some())
+}
+
+pub async fn get_by_name(db: &PgPool, name: &str) -> Result<Option<AuthorEntity>, sqlx::Error> {
+    sqlx::query_as::<_, AuthorEntity>(
+        r#"
+        SELECT * FROM author
+        WHERE deleted_at IS NULL AND LOWER(name) = LOWER($1)
+        ORDER BY id
+        LIMIT 1
+        "#
+    )
+        .bind(name)
+        .fetch_optional(db)
+        .await
+}
+
 pub async fn get_by_lesson_id(db: &PgPool, id: i64) -> Result<Option<AuthorEntity>, sqlx::Error> {
     Ok(sqlx::query_as::<_, AuthorEntity>(
         r#"
@@ -187,5 +229,28 @@ pub async fn update_stats(
         .execute(connection)
         .await?;
 
+    Ok(())
+}
+
+/// Recomputes `lesson_count` from the author's live, published lessons.
+pub async fn recount_stats(
+    connection: &mut PgConnection,
+    author_id: i64
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE author
+        SET lesson_count = (
+            SELECT COUNT(*) FROM lesson
+            WHERE lesson.author_id = author.id AND lesson.deleted_at IS NULL
+              AND lesson.publication_status = 'published'
+        )
+        WHERE id = $1
+        "#
+    )
+        .bind(author_id)
+        .execute(connection)
+        .await?;
+
     Ok(())
 }
\ No newline at end of file
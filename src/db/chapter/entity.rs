@@ -0,0 +1,16 @@
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ChapterEntity {
+    pub lesson_id: i64,
+    pub title: String,
+    pub start_ms: i64,
+    pub description: Option<String>
+}
+
+#[derive()]
+pub struct ChapterInput {
+    pub title: String,
+    pub start_ms: i64,
+    pub description: Option<String>
+}
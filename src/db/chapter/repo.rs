@@ -0,0 +1,55 @@
+use crate::db::chapter::entity::{ChapterEntity, ChapterInput};
+use sqlx::{PgConnection, PgPool, Postgres, QueryBuilder};
+
+pub async fn delete_by_lesson_id(
+    connection: &mut PgConnection,
+    lesson_id: i64,
+) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query("DELETE FROM lesson_chapter WHERE lesson_id = $1")
+        .bind(lesson_id)
+        .execute(connection)
+        .await?
+        .rows_affected())
+}
+
+/// Inserts `chapters` in order; positions start at 0.
+pub async fn insert_all(
+    connection: &mut PgConnection,
+    lesson_id: i64,
+    chapters: Vec<ChapterInput>,
+) -> Result<(), sqlx::Error> {
+    if chapters.is_empty() {
+        return Ok(());
+    }
+
+    let mut query = QueryBuilder::<Postgres>::new(
+        "INSERT INTO lesson_chapter (lesson_id, position, title, start_ms, description) "
+    );
+    query.push_values(chapters.into_iter().enumerate(), |mut row, (index, chapter)| {
+        row.push_bind(lesson_id)
+            .push_bind(index as i32)
+            .push_bind(chapter.title)
+            .push_bind(chapter.start_ms)
+            .push_bind(chapter.description);
+    });
+    query.build().execute(connection).await?;
+    Ok(())
+}
+
+/// Chapters of all `lesson_ids`, grouped by lesson and ordered by position.
+pub async fn get_by_lesson_ids(
+    db: &PgPool,
+    lesson_ids: &[i64],
+) -> Result<Vec<ChapterEntity>, sqlx::Error> {
+    sqlx::query_as::<_, ChapterEntity>(
+        r#"
+        SELECT lesson_id, title, start_ms, description
+        FROM lesson_chapter
+        WHERE lesson_id = ANY($1)
+        ORDER BY lesson_id, position
+        "#,
+    )
+        .bind(lesson_ids)
+        .fetch_all(db)
+        .await
+}
@@ -0,0 +1,20 @@
+use crate::db::author::entity::AuthorEntity;
+use crate::db::topic::entity::TopicWithAuthor;
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+#[derive(Debug, FromRow)]
+pub struct FollowedAuthorEntity {
+    #[sqlx(flatten)]
+    pub author: AuthorEntity,
+    pub follow_id: i64,
+    pub followed_at: OffsetDateTime
+}
+
+#[derive(Debug, FromRow)]
+pub struct SubscribedTopicEntity {
+    #[sqlx(flatten)]
+    pub topic: TopicWithAuthor,
+    pub follow_id: i64,
+    pub followed_at: OffsetDateTime
+}
@@ -0,0 +1,230 @@
+use crate::db::follow::entity::{FollowedAuthorEntity, SubscribedTopicEntity};
+use crate::db::lesson::entity::LessonWithAuthorTopic;
+use crate::db::lesson::repo::{NEIGHBOUR_COLUMNS, LISTED};
+use crate::module::user::follow::dto::{FeedCursor, FollowCursor};
+use sqlx::{PgPool, Postgres, QueryBuilder};
+
+pub async fn is_author_visible(db: &PgPool, author_id: i64) -> Result<bool, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (bool,)>(
+        "SELECT EXISTS (SELECT 1 FROM author WHERE id = $1 AND deleted_at IS NULL)"
+    )
+        .bind(author_id)
+        .fetch_one(db)
+        .await?.0)
+}
+
+/// Live topics, including unlisted ones users reach by link.
+pub async fn is_topic_visible(db: &PgPool, topic_id: i64) -> Result<bool, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (bool,)>(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM topic
+            JOIN author ON author.id = topic.author_id AND author.deleted_at IS NULL
+            WHERE topic.id = $1 AND topic.deleted_at IS NULL
+                AND topic.publication_status IN ('published', 'unlisted')
+        )
+        "#
+    )
+        .bind(topic_id)
+        .fetch_one(db)
+        .await?.0)
+}
+
+pub async fn follow_author(db: &PgPool, user_id: i64, author_id: i64) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO author_follow (user_id, author_id) VALUES ($1, $2)
+        ON CONFLICT (user_id, author_id) DO NOTHING
+        "#
+    )
+        .bind(user_id)
+        .bind(author_id)
+        .execute(db)
+        .await?;
+    Ok(())
+}
+
+pub async fn unfollow_author(db: &PgPool, user_id: i64, author_id: i64) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query("DELETE FROM author_follow WHERE user_id = $1 AND author_id = $2")
+        .bind(user_id)
+        .bind(author_id)
+        .execute(db)
+        .await?
+        .rows_affected())
+}
+
+pub async fn subscribe_topic(db: &PgPool, user_id: i64, topic_id: i64) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO topic_subscription (user_id, topic_id) VALUES ($1, $2)
+        ON CONFLICT (user_id, topic_id) DO NOTHING
+        "#
+    )
+        .bind(user_id)
+        .bind(topic_id)
+        .execute(db)
+        .await?;
+    Ok(())
+}
+
+pub async fn unsubscribe_topic(db: &PgPool, user_id: i64, topic_id: i64) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query("DELETE FROM topic_subscription WHERE user_id = $1 AND topic_id = $2")
+        .bind(user_id)
+        .bind(topic_id)
+        .execute(db)
+        .await?
+        .rows_affected())
+}
+
+/// Followed authors, latest follow first. Deleted authors are left out.
+pub async fn page_authors(
+    db: &PgPool,
+    user_id: i64,
+    limit: u32,
+    cursor: Option<FollowCursor>
+) -> Result<Vec<FollowedAuthorEntity>, sqlx::Error> {
+    let mut query = QueryBuilder::<Postgres>::new(
+        r#"
+        SELECT author.*,
+            author_follow.id AS follow_id,
+            author_follow.created_at AS followed_at
+        FROM author_follow
+        JOIN author ON author.id = author_follow.author_id AND author.deleted_at IS NULL
+        WHERE author_follow.user_id = "#
+    );
+    query.push_bind(user_id);
+
+    if let Some(cursor) = cursor {
+        query.push(" AND (author_follow.created_at, author_follow.id) < (")
+            .push_bind(cursor.followed_at).push(", ")
+            .push_bind(cursor.id).push(")");
+    }
+
+    query
+        .push(" ORDER BY author_follow.created_at DESC, author_follow.id DESC LIMIT ")
+        .push_bind(limit as i64)
+        .build_query_as::<FollowedAuthorEntity>()
+        .fetch_all(db)
+        .await
+}
+
+/// Subscribed topics, latest subscription first. Topics that are deleted or
+/// no longer live are left out.
+pub async fn page_topics(
+    db: &PgPool,
+    user_id: i64,
+    limit: u32,
+    cursor: Option<FollowCursor>
+) -> Result<Vec<SubscribedTopicEntity>, sqlx::Error> {
+    let mut query = QueryBuilder::<Postgres>::new(
+        r#"
+        SELECT topic.id,
+            topic.author_id,
+            topic.title,
+            topic.description,
+            topic.cover_image_path,
+            topic.lesson_count,
+            topic.total_duration,
+            topic.snip_count,
+            topic.rating_count,
+            topic.rating_average,
+            topic.publication_status,
+            topic.publish_at,
+            topic.published_at,
+            topic.created_at,
+            author.name AS author_name,
+            author.avatar_path AS author_avatar_path,
+            author.created_at AS author_created_at,
+            author.lesson_count AS author_lesson_count,
+            topic_progress.completed_lesson_count,
+            topic_subscription.id AS follow_id,
+            topic_subscription.created_at AS followed_at
+        FROM topic_subscription
+        JOIN topic ON topic.id = topic_subscription.topic_id AND topic.deleted_at IS NULL
+            AND topic.publication_status IN ('published', 'unlisted')
+        JOIN author ON author.id = topic.author_id AND author.deleted_at IS NULL
+        LEFT JOIN topic_progress ON topic_progress.topic_id = topic.id
+            AND topic_progress.user_id = topic_subscription.user_id
+        WHERE topic_subscription.user_id = "#
+    );
+    query.push_bind(user_id);
+
+    if let Some(cursor) = cursor {
+        query.push(" AND (topic_subscription.created_at, topic_subscription.id) < (")
+            .push_bind(cursor.followed_at).push(", ")
+            .push_bind(cursor.id).push(")");
+    }
+
+    query
+        .push(" ORDER BY topic_subscription.created_at DESC, topic_subscription.id DESC LIMIT ")
+        .push_bind(limit as i64)
+        .build_query_as::<SubscribedTopicEntity>()
+        .fetch_all(db)
+        .await
+}
+
+/// Live lessons of the followed authors and subscribed topics, latest
+/// published first.
+pub async fn feed(
+    db: &PgPool,
+    user_id: i64,
+    limit: u32,
+    cursor: Option<FeedCursor>
+) -> Result<Vec<LessonWithAuthorTopic>, sqlx::Error> {
+    let mut query = QueryBuilder::<Postgres>::new("SELECT");
+    query.push(r#"
+        lesson.*,
+
+        author.name AS author_name,
+        author.avatar_path AS author_avatar_path,
+        author.created_at AS author_created_at,
+        author.lesson_count AS author_lesson_count,
+
+        topic.title AS topic_title,
+        topic.description AS topic_description,
+        topic.cover_image_path AS topic_cover_image_path,
+        topic.created_at AS topic_created_at,
+        topic.lesson_count AS topic_lesson_count,
+        topic.total_duration AS topic_total_duration,
+        topic.snip_count AS topic_snip_count,
+        topic.rating_count AS topic_rating_count,
+        topic.rating_average AS topic_rating_average,
+
+        lesson_progress.user_id,
+        lesson_progress.started_at,
+        lesson_progress.last_position_ms,
+        lesson_progress.status,
+        lesson_progress.completed_at,
+        (favourite_lesson.user_id IS NOT NULL) AS is_favourite
+    "#);
+    query.push(NEIGHBOUR_COLUMNS);
+
+    query.push(r#"
+        FROM lesson
+        JOIN author ON lesson.author_id = author.id AND author.deleted_at IS NULL
+        LEFT JOIN topic ON lesson.topic_id = topic.id
+        LEFT JOIN lesson_progress ON lesson_progress.lesson_id = lesson.id
+            AND lesson_progress.user_id = "#);
+    query.push_bind(user_id);
+    query.push(" LEFT JOIN favourite_lesson ON favourite_lesson.lesson_id = lesson.id AND favourite_lesson.user_id = ");
+    query.push_bind(user_id);
+    query.push(" WHERE ").push(LISTED);
+    query.push(" AND lesson.published_at IS NOT NULL AND (lesson.author_id IN (SELECT author_id FROM author_follow WHERE user_id = ");
+    query.push_bind(user_id);
+    query.push(") OR lesson.topic_id IN (SELECT topic_id FROM topic_subscription WHERE user_id = ");
+    query.push_bind(user_id);
+    query.push("))");
+
+    if let Some(cursor) = cursor {
+        query.push(" AND (lesson.published_at, lesson.id) < (")
+            .push_bind(cursor.published_at).push(", ")
+            .push_bind(cursor.id).push(")");
+    }
+
+    query
+        .push(" ORDER BY lesson.published_at DESC, lesson.id DESC LIMIT ")
+        .push_bind(limit as i64)
+        .build_query_as::<LessonWithAuthorTopic>()
+        .fetch_all(db)
+        .await
+}
@@ -0,0 +1,25 @@
+use crate::db::lesson::entity::LessonWithAuthorTopic;
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+/// A listen session as shown in the history.
+#[derive(Debug, FromRow)]
+pub struct HistoryEntity {
+    pub history_id: i64,
+    pub history_device: Option<String>,
+    /// Where the session stopped
+    pub history_position_ms: i64,
+    pub history_listened_ms: i64,
+    pub history_started_at: OffsetDateTime,
+    pub history_updated_at: OffsetDateTime,
+    /// `YYYY-MM-DD` of `history_started_at` in the requested timezone
+    pub history_day: String
+}
+
+#[derive(FromRow)]
+pub struct HistoryWithLesson {
+    #[sqlx(flatten)]
+    pub history: HistoryEntity,
+    #[sqlx(flatten)]
+    pub lesson: LessonWithAuthorTopic
+}
@@ -0,0 +1,101 @@
+use crate::db::history::entity::HistoryWithLesson;
+use crate::db::lesson::repo::NEIGHBOUR_COLUMNS;
+use crate::module::user::history::dto::HistoryCursor;
+use sqlx::{PgPool, Postgres, QueryBuilder};
+
+/// Sessions of the user, latest first. Sessions of deleted lessons are left out.
+pub async fn page(
+    db: &PgPool,
+    user_id: i64,
+    timezone: &str,
+    limit: u32,
+    cursor: Option<HistoryCursor>
+) -> Result<Vec<HistoryWithLesson>, sqlx::Error> {
+    let mut query = QueryBuilder::<Postgres>::new("SELECT");
+    query.push(r#"
+        lesson.*,
+        listen_session.id AS history_id,
+        listen_session.device AS history_device,
+        listen_session.last_position_ms AS history_position_ms,
+        listen_session.listened_ms AS history_listened_ms,
+        listen_session.created_at AS history_started_at,
+        listen_session.updated_at AS history_updated_at,
+    "#);
+    query.push("TO_CHAR(listen_session.created_at AT TIME ZONE ")
+        .push_bind(timezone.to_string())
+        .push(", 'YYYY-MM-DD') AS history_day,");
+    query.push(r#"
+
+        author.name AS author_name,
+        author.avatar_path AS author_avatar_path,
+        author.created_at AS author_created_at,
+        author.lesson_count AS author_lesson_count,
+
+        topic.title AS topic_title,
+        topic.description AS topic_description,
+        topic.cover_image_path AS topic_cover_image_path,
+        topic.created_at AS topic_created_at,
+        topic.lesson_count AS topic_lesson_count,
+        topic.total_duration AS topic_total_duration,
+        topic.snip_count AS topic_snip_count,
+        topic.rating_count AS topic_rating_count,
+        topic.rating_average AS topic_rating_average,
+
+        lesson_progress.user_id,
+        lesson_progress.started_at,
+        lesson_progress.last_position_ms,
+        lesson_progress.status,
+        lesson_progress.completed_at,
+        (favourite_lesson.user_id IS NOT NULL) AS is_favourite
+    "#);
+    query.push(NEIGHBOUR_COLUMNS);
+
+    query.push(r#"
+        FROM listen_session
+        JOIN lesson ON lesson.id = listen_session.lesson_id AND lesson.deleted_at IS NULL
+        JOIN author ON lesson.author_id = author.id
+        LEFT JOIN topic ON lesson.topic_id = topic.id
+        LEFT JOIN lesson_progress ON lesson_progress.lesson_id = lesson.id
+            AND lesson_progress.user_id = listen_session.user_id
+        LEFT JOIN favourite_lesson ON favourite_lesson.lesson_id = lesson.id
+            AND favourite_lesson.user_id = listen_session.user_id
+        WHERE listen_session.hidden_at IS NULL AND listen_session.user_id = "#);
+    query.push_bind(user_id);
+
+    if let Some(cursor) = cursor {
+        query.push(" AND (listen_session.created_at, listen_session.id) < (")
+            .push_bind(cursor.started_at).push(", ")
+            .push_bind(cursor.id).push(")");
+    }
+
+    query
+        .push(" ORDER BY listen_session.created_at DESC, listen_session.id DESC LIMIT ")
+        .push_bind(limit as i64)
+        .build_query_as::<HistoryWithLesson>()
+        .fetch_all(db)
+        .await
+}
+
+/// Hides the session from the history. The session itself stays, so it is
+/// still counted once and replays of it are still recognised.
+pub async fn hide(db: &PgPool, id: i64, user_id: i64) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query(
+        r#"
+        UPDATE listen_session SET hidden_at = NOW()
+        WHERE id = $1 AND user_id = $2 AND hidden_at IS NULL
+        "#
+    )
+        .bind(id)
+        .bind(user_id)
+        .execute(db)
+        .await?
+        .rows_affected())
+}
+
+pub async fn hide_all(db: &PgPool, user_id: i64) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE listen_session SET hidden_at = NOW() WHERE user_id = $1 AND hidden_at IS NULL")
+        .bind(user_id)
+        .execute(db)
+        .await?;
+    Ok(())
+}
@@ -1,4 +1,5 @@
-use crate::module::common::enums::UserProgressStatus;
+use crate::db::chapter::entity::ChapterEntity;
+use crate::module::common::enums::{PublicationStatus, UserProgressStatus};
 use smart_default::SmartDefault;
 use sqlx::FromRow;
 use time::OffsetDateTime;
@@ -12,7 +13,9 @@ pub struct LessonInput {
     pub cover_image_path: Option<String>,
     pub audio_path: String,
     pub duration: i64,
-    pub file_size: i64
+    pub file_size: i64,
+    pub publication_status: PublicationStatus,
+    pub publish_at: Option<OffsetDateTime>
 }
 
 #[derive(Debug, FromRow, SmartDefault)]
@@ -28,6 +31,14 @@ pub struct LessonEntity {
     pub file_size: i64,
     pub listen_count: i64,
     pub snip_count: i64,
+    pub rating_count: i64,
+    /// 0 without ratings
+    pub rating_average: f64,
+    pub position: i32,
+    #[default(PublicationStatus::Draft)]
+    pub publication_status: PublicationStatus,
+    pub publish_at: Option<OffsetDateTime>,
+    pub published_at: Option<OffsetDateTime>,
     #[default(OffsetDateTime::now_utc())]
     pub created_at: OffsetDateTime
 }
@@ -51,6 +62,8 @@ pub struct LessonWithAuthorTopic {
     pub topic_lesson_count: Option<i64>,
     pub topic_total_duration: Option<i64>,
     pub topic_snip_count: Option<i64>,
+    pub topic_rating_count: Option<i64>,
+    pub topic_rating_average: Option<f64>,
     //favourite
     pub is_favourite: bool,
     //progress
@@ -58,7 +71,13 @@ pub struct LessonWithAuthorTopic {
     pub started_at: Option<OffsetDateTime>,
     pub last_position_ms: Option<i64>,
     pub status: Option<UserProgressStatus>,
-    pub completed_at: Option<OffsetDateTime>
+    pub completed_at: Option<OffsetDateTime>,
+    //topic order
+    pub next_lesson_id: Option<i64>,
+    pub previous_lesson_id: Option<i64>,
+    //chapters, loaded separately
+    #[sqlx(skip)]
+    pub chapters: Vec<ChapterEntity>
 }
 
 #[derive(Debug, FromRow)]
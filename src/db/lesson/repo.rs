@@ -5,10 +5,12 @@ use sqlx::PgPool;
 use sqlx::Postgres;
 use sqlx::QueryBuilder;
 use time::OffsetDateTime;
-use crate::module::common::enums::{UserProgressStatus};
+use crate::module::common::enums::{TranslationKind, UserProgressStatus};
+use crate::db::translation;
 use crate::module::common::lesson::dto::QuerySort;
 use crate::module::common::paging::QueryOrder;
 use crate::module::user::lesson::dto::LessonCursor;
+use crate::utils;
 
 pub async fn insert(
     connection: &mut PgConnection,
@@ -16,8 +18,12 @@ pub async fn insert(
 ) -> Result<i64, sqlx::Error> {
     let id = sqlx::query_as::<_, (i64,)>(
         r#"
-            INSERT INTO lesson (author_id, topic_id, title, description, cover_image_path, audio_path, duration, file_size)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            INSERT INTO lesson (author_id, topic_id, title, description, cover_image_path, audio_path, duration, file_size, position,
+                                publication_status, publish_at, published_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, (
+                SELECT COALESCE(MAX(position) + 1, 0) FROM lesson
+                WHERE topic_id = $2 AND deleted_at IS NULL
+            ), $9, $10, CASE WHEN $9 = 'published' THEN NOW() END)
             RETURNING *
             "#,
     )
@@ -29,6 +35,8 @@ pub async fn insert(
         .bind(lesson.audio_path)
         .bind(lesson.duration)
         .bind(lesson.file_size)
+        .bind(lesson.publication_status)
+        .bind(lesson.publish_at)
         .fetch_one(connection)
         .await?.0;
     Ok(id)
@@ -47,7 +55,22 @@ pub async fn update(
             cover_image_path = $3,
             audio_path = $4,
             duration = $5,
-            file_size = $6
+            file_size = $6,
+            author_id = $10,
+            topic_id = $11,
+            position = CASE
+                WHEN topic_id IS DISTINCT FROM $11 THEN (
+                    SELECT COALESCE(MAX(position) + 1, 0) FROM lesson
+                    WHERE topic_id = $11 AND deleted_at IS NULL
+                )
+                ELSE position
+            END,
+            publication_status = $8,
+            publish_at = $9,
+            published_at = CASE
+                WHEN $8 = 'published' AND publication_status <> 'published' THEN NOW()
+                ELSE published_at
+            END
         WHERE id = $7
         RETURNING *
         "#,
@@ -59,18 +82,84 @@ pub async fn update(
     .bind(lesson.duration)
     .bind(lesson.file_size)
     .bind(id)
+    .bind(lesson.publication_status)
+    .bind(lesson.publish_at)
+    .bind(lesson.author_id)
+    .bind(lesson.topic_id)
     .fetch_optional(connection)
     .await?.map(|t| t.0 );
     Ok(id)
 }
 
+/// Points the progress and snips of a lesson at its new author and topic.
+pub async fn move_related(
+    connection: &mut PgConnection,
+    lesson_id: i64,
+    author_id: i64,
+    topic_id: Option<i64>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE lesson_progress SET author_id = $1, topic_id = $2 WHERE lesson_id = $3")
+        .bind(author_id)
+        .bind(topic_id)
+        .bind(lesson_id)
+        .execute(&mut *connection)
+        .await?;
+    sqlx::query("UPDATE snip SET author_id = $1, topic_id = $2 WHERE lesson_id = $3")
+        .bind(author_id)
+        .bind(topic_id)
+        .bind(lesson_id)
+        .execute(&mut *connection)
+        .await?;
+    Ok(())
+}
+
+/// Fits chapters, transcript cues and snips into a new audio `duration`.
+/// Chapters and cues that start past the end are dropped and the rest end
+/// there at the latest; snips that start past the end are moved to the trash
+/// and the others are cut at the end. Returns whether a snip was trashed.
+pub async fn fit_to_duration(
+    connection: &mut PgConnection,
+    lesson_id: i64,
+    duration: i64,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query("DELETE FROM lesson_chapter WHERE lesson_id = $1 AND start_ms >= $2")
+        .bind(lesson_id)
+        .bind(duration)
+        .execute(&mut *connection)
+        .await?;
+    sqlx::query("DELETE FROM lesson_transcript_segment WHERE lesson_id = $1 AND start_ms >= $2")
+        .bind(lesson_id)
+        .bind(duration)
+        .execute(&mut *connection)
+        .await?;
+    sqlx::query("UPDATE lesson_transcript_segment SET end_ms = $2 WHERE lesson_id = $1 AND end_ms > $2")
+        .bind(lesson_id)
+        .bind(duration)
+        .execute(&mut *connection)
+        .await?;
+    let trashed = sqlx::query(
+        "UPDATE snip SET deleted_at = NOW() WHERE lesson_id = $1 AND start_ms >= $2 AND deleted_at IS NULL"
+    )
+        .bind(lesson_id)
+        .bind(duration)
+        .execute(&mut *connection)
+        .await?
+        .rows_affected();
+    sqlx::query("UPDATE snip SET end_ms = $2 WHERE lesson_id = $1 AND end_ms > $2 AND start_ms < $2")
+        .bind(lesson_id)
+        .bind(duration)
+        .execute(&mut *connection)
+        .await?;
+    Ok(trashed > 0)
+}
+
 pub async fn delete(
     connection: &mut PgConnection,
     id: i64
 ) -> Result<Option<LessonEntity>, sqlx::Error> {
     let result = sqlx::query_as::<_, LessonEntity>(
         r#"
-               UPDATE lesson SET deleted_at = NOW() WHERE id = $1
+               UPDATE lesson SET deleted_at = NOW() WHERE id = $1 AND deleted_at IS NULL
                RETURNING *
             "#
     )
@@ -80,13 +169,33 @@ pub async fn delete(
     Ok(result)
 }
 
+pub async fn restore(
+    connection: &mut PgConnection,
+    id: i64
+) -> Result<Option<LessonEntity>, sqlx::Error> {
+    sqlx::query_as::<_, LessonEntity>(
+        r#"
+               UPDATE lesson SET deleted_at = NULL WHERE id = $1 AND deleted_at IS NOT NULL
+               RETURNING *
+            "#
+    )
+        .bind(id)
+        .fetch_optional(connection)
+        .await
+}
+
 pub async fn deleted(
     db: &PgPool,
     since: OffsetDateTime
 ) -> Result<Vec<(i64, OffsetDateTime)>, sqlx::Error> {
     let result = sqlx::query_as::<_, (i64, OffsetDateTime)>(
         r#"
-               SELECT id, deleted_at FROM lesson WHERE deleted_at IS NOT NULL AND deleted_at >= $1
+               SELECT id, COALESCE(deleted_at, updated_at) FROM lesson
+               WHERE deleted_at >= $1
+                  OR (deleted_at IS NULL
+                      AND publication_status <> 'published'
+                      AND published_at IS NOT NULL
+                      AND updated_at >= $1)
             "#
     )
         .bind(since)
@@ -95,6 +204,68 @@ pub async fn deleted(
     Ok(result)
 }
 
+/// Lessons that became reachable since `since`, with the time they did: a
+/// lesson in a topic that went live later shows up with the topic.
+pub async fn published(
+    db: &PgPool,
+    since: OffsetDateTime
+) -> Result<Vec<(i64, OffsetDateTime)>, sqlx::Error> {
+    sqlx::query_as::<_, (i64, OffsetDateTime)>(
+        &format!(r#"
+               SELECT lesson.id, GREATEST(lesson.published_at, topic.published_at) FROM lesson
+               LEFT JOIN topic ON lesson.topic_id = topic.id
+               WHERE GREATEST(lesson.published_at, topic.published_at) >= $1 AND {REACHABLE}
+            "#)
+    )
+        .bind(since)
+        .fetch_all(db)
+        .await
+}
+
+/// Publishes the scheduled lessons that are due and returns them.
+pub async fn publish_due(connection: &mut PgConnection) -> Result<Vec<LessonEntity>, sqlx::Error> {
+    sqlx::query_as::<_, LessonEntity>(
+        r#"
+        UPDATE lesson
+        SET publication_status = 'published',
+            published_at = NOW()
+        WHERE publication_status = 'scheduled' AND publish_at <= NOW() AND deleted_at IS NULL
+        RETURNING *
+        "#
+    )
+        .fetch_all(connection)
+        .await
+}
+
+/// Lessons users can reach by id or link: published, not deleted, and in no
+/// topic or in a topic that is published or unlisted. Expects `topic` to be joined.
+pub const REACHABLE: &str = "lesson.deleted_at IS NULL \
+    AND lesson.publication_status = 'published' \
+    AND (lesson.topic_id IS NULL OR topic.publication_status IN ('published', 'unlisted'))";
+
+/// Lessons users discover without a link, in global search, feeds and
+/// notifications: like [`REACHABLE`], but an unlisted topic keeps its lessons
+/// out. Expects `topic` to be joined.
+pub const LISTED: &str = "lesson.deleted_at IS NULL \
+    AND lesson.publication_status = 'published' \
+    AND (lesson.topic_id IS NULL OR topic.publication_status = 'published')";
+
+/// Ids of the published lessons before and after `lesson` in its topic's order.
+pub const NEIGHBOUR_COLUMNS: &str = r#"
+        , (SELECT next_lesson.id FROM lesson next_lesson
+           WHERE next_lesson.topic_id = lesson.topic_id AND next_lesson.deleted_at IS NULL
+             AND next_lesson.publication_status = 'published'
+             AND (next_lesson.position, next_lesson.id) > (lesson.position, lesson.id)
+           ORDER BY next_lesson.position, next_lesson.id
+           LIMIT 1) AS next_lesson_id,
+        (SELECT previous_lesson.id FROM lesson previous_lesson
+           WHERE previous_lesson.topic_id = lesson.topic_id AND previous_lesson.deleted_at IS NULL
+             AND previous_lesson.publication_status = 'published'
+             AND (previous_lesson.position, previous_lesson.id) < (lesson.position, lesson.id)
+           ORDER BY previous_lesson.position DESC, previous_lesson.id DESC
+           LIMIT 1) AS previous_lesson_id
+"#;
+
 fn build_query(
     query: &mut QueryBuilder<Postgres>,
     author_id: Option<i64>,
@@ -150,11 +321,9 @@ fn build_query(
         has_where = true;
     }
 
-    if let Some(search) = search {
-        query
-            .push(if has_where { " AND " } else { " WHERE " })
-            .push("lesson.title ILIKE ")
-            .push_bind(format!("{}%", search));
+    if let Some(search) = search.as_deref().and_then(utils::search::to_tsquery) {
+        query.push(if has_where { " AND " } else { " WHERE " });
+        translation::repo::push_search(query, TranslationKind::Lesson, search);
         has_where = true;
     }
 
@@ -191,12 +360,15 @@ pub async fn page(
         topic.created_at AS topic_created_at,
         topic.lesson_count AS topic_lesson_count,
         topic.total_duration AS topic_total_duration,
-        topic.snip_count AS topic_snip_count
+        topic.snip_count AS topic_snip_count,
+        topic.rating_count AS topic_rating_count,
+        topic.rating_average AS topic_rating_average
     "#);
 
     if let Some(_) = user_id {
         query.push(", lesson_progress.*, (favourite_lesson.user_id IS NOT NULL) AS is_favourite");
     }
+    query.push(NEIGHBOUR_COLUMNS);
     query.push(" FROM lesson");
 
     build_query(&mut query, author_id, topic_id, search, user_id, status, favourite);
@@ -206,6 +378,12 @@ pub async fn page(
         Some(QuerySort::SnipCount) => {
             query.push(" snip_count");
         }
+        Some(QuerySort::Position) => {
+            query.push(" lesson.position");
+        }
+        Some(QuerySort::Rating) => {
+            query.push(" lesson.rating_average");
+        }
         _ => {
             query.push(" created_at");
         }
@@ -242,7 +420,10 @@ pub async fn page_cursor(
     sort: Option<QuerySort>,
     order: Option<QueryOrder>,
 ) -> Result<Vec<LessonWithAuthorTopic>, sqlx::Error> {
-    let (order, order_sign) = if order == Some(QueryOrder::Asc)  { ("ASC", ">") }
+    // lessons in topic order read first to last unless asked otherwise
+    let ascending = order == Some(QueryOrder::Asc)
+        || (order.is_none() && sort == Some(QuerySort::Position));
+    let (order, order_sign) = if ascending { ("ASC", ">") }
     else { ("DESC", "<") };
 
     let mut query = QueryBuilder::<Postgres>::new("SELECT");
@@ -260,7 +441,9 @@ pub async fn page_cursor(
         topic.created_at AS topic_created_at,
         topic.lesson_count AS topic_lesson_count,
         topic.total_duration AS topic_total_duration,
-        topic.snip_count AS topic_snip_count
+        topic.snip_count AS topic_snip_count,
+        topic.rating_count AS topic_rating_count,
+        topic.rating_average AS topic_rating_average
     "#);
 
     if user_id.is_some() {
@@ -275,10 +458,14 @@ pub async fn page_cursor(
     if favourite.is_some() {
         query.push(", (favourite_lesson.user_id IS NOT NULL) AS is_favourite");
     }
+    query.push(NEIGHBOUR_COLUMNS);
 
     query.push(" FROM lesson");
 
-    let has_where = build_query(&mut query, author_id, topic_id, search, user_id, status, favourite);
+    let mut has_where = build_query(&mut query, author_id, topic_id, search, user_id, status, favourite);
+    query.push(if has_where { " AND " } else { " WHERE " }).push(REACHABLE);
+    has_where = true;
+
     if let Some(cursor) = cursor {
         query.push(if has_where { " AND " } else { " WHERE " });
         match sort {
@@ -290,6 +477,14 @@ pub async fn page_cursor(
                 query.push("(lesson.created_at,lesson.id)").push(order_sign)
                     .push("(").push_bind(cursor.created_at).push(",").push_bind(cursor.id).push(")");
             }
+            Some(QuerySort::Position) => {
+                query.push("(lesson.position,lesson.id)").push(order_sign)
+                    .push("(").push_bind(cursor.position).push(",").push_bind(cursor.id).push(")");
+            }
+            Some(QuerySort::Rating) => {
+                query.push("(lesson.rating_average,lesson.id)").push(order_sign)
+                    .push("(").push_bind(cursor.rating_average).push(",").push_bind(cursor.id).push(")");
+            }
             _ => {
                 query.push("lesson.id").push(order_sign)
                     .push_bind(cursor.id);
@@ -302,6 +497,8 @@ pub async fn page_cursor(
             match sort {
                 Some(QuerySort::SnipCount) => { format!("lesson.snip_count {order}, lesson.id {order}") },
                 Some(QuerySort::CreatedAt) => { format!("lesson.created_at {order}, lesson.id {order}") }
+                Some(QuerySort::Position) => { format!("lesson.position {order}, lesson.id {order}") }
+                Some(QuerySort::Rating) => { format!("lesson.rating_average {order}, lesson.id {order}") }
                 None => { format!("lesson.id {order}") }
             }
         );
@@ -339,8 +536,20 @@ pub async fn get_by_id(db: &PgPool, id: i64) -> Result<Option<LessonEntity>, sql
         .await?)
 }
 
+/// Locks an active lesson for the rest of the transaction.
+pub async fn lock(connection: &mut PgConnection, id: i64) -> Result<Option<LessonEntity>, sqlx::Error> {
+    sqlx::query_as::<_, LessonEntity>(
+        r#"
+             SELECT * FROM lesson WHERE id = $1 AND deleted_at IS NULL FOR UPDATE
+             "#,
+    )
+        .bind(id)
+        .fetch_optional(connection)
+        .await
+}
+
 pub async fn get_with_author_topic_by_id(db: &PgPool, id: i64) -> Result<Option<LessonWithAuthorTopic>, sqlx::Error> {
-    Ok(sqlx::query_as::<_, LessonWithAuthorTopic>(
+    let mut query = QueryBuilder::<Postgres>::new(
         r#"
              SELECT
                  lesson.*,
@@ -358,16 +567,106 @@ pub async fn get_with_author_topic_by_id(db: &PgPool, id: i64) -> Result<Option<
                  topic.created_at AS topic_created_at,
                  topic.lesson_count AS topic_lesson_count,
                  topic.total_duration AS topic_total_duration,
-                 topic.snip_count AS topic_snip_count
+                 topic.snip_count AS topic_snip_count,
+                 topic.rating_count AS topic_rating_count,
+                 topic.rating_average AS topic_rating_average
+             "#,
+    );
+    query.push(NEIGHBOUR_COLUMNS);
+    query.push(
+        r#"
                  FROM lesson
                  JOIN author ON lesson.author_id = author.id
                  LEFT JOIN topic ON lesson.topic_id = topic.id
-                 WHERE lesson.id = $1
+                 WHERE lesson.id = "#,
+    );
+    query
+        .push_bind(id)
+        .build_query_as::<LessonWithAuthorTopic>()
+        .fetch_optional(db)
+        .await
+}
+
+/// Whether users can reach the lesson by id.
+pub async fn is_visible(db: &PgPool, id: i64) -> Result<bool, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (bool,)>(
+        &format!(r#"
+             SELECT EXISTS (
+                 SELECT 1 FROM lesson
+                 LEFT JOIN topic ON lesson.topic_id = topic.id
+                 WHERE lesson.id = $1 AND {REACHABLE}
+             )
+             "#),
+    )
+        .bind(id)
+        .fetch_one(db)
+        .await?.0)
+}
+
+/// Whether the topic has any active lesson, published or not.
+pub async fn exists_in_topic(connection: &mut PgConnection, topic_id: i64) -> Result<bool, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (bool,)>(
+        r#"
+             SELECT EXISTS (SELECT 1 FROM lesson WHERE topic_id = $1 AND deleted_at IS NULL)
              "#,
     )
-    .bind(id)
-    .fetch_optional(db)
-    .await?)
+        .bind(topic_id)
+        .fetch_one(connection)
+        .await?.0)
+}
+
+/// Whether the author has any active lesson, published or not.
+pub async fn exists_by_author(connection: &mut PgConnection, author_id: i64) -> Result<bool, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (bool,)>(
+        r#"
+             SELECT EXISTS (SELECT 1 FROM lesson WHERE author_id = $1 AND deleted_at IS NULL)
+             "#,
+    )
+        .bind(author_id)
+        .fetch_one(connection)
+        .await?.0)
+}
+
+/// Locks the active lessons of a topic and returns their ids.
+pub async fn lock_topic_lesson_ids(
+    connection: &mut PgConnection,
+    topic_id: i64,
+) -> Result<Vec<i64>, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (i64,)>(
+        r#"
+        SELECT id FROM lesson
+        WHERE topic_id = $1 AND deleted_at IS NULL
+        ORDER BY id
+        FOR UPDATE
+        "#,
+    )
+    .bind(topic_id)
+    .fetch_all(connection)
+    .await?
+    .into_iter()
+    .map(|row| row.0)
+    .collect())
+}
+
+/// Sets each lesson's position to its index in `lesson_ids`.
+pub async fn update_positions(
+    connection: &mut PgConnection,
+    topic_id: i64,
+    lesson_ids: &[i64],
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE lesson
+        SET position = ordered.position - 1
+        FROM UNNEST($2::BIGINT[]) WITH ORDINALITY AS ordered(id, position)
+        WHERE lesson.id = ordered.id AND lesson.topic_id = $1
+        "#,
+    )
+    .bind(topic_id)
+    .bind(lesson_ids)
+    .execute(connection)
+    .await?;
+    Ok(())
 }
 
 pub async fn get_duration(db: &PgPool, audio_path: String) -> Result<Option<i64>, sqlx::Error> {
@@ -400,12 +699,13 @@ pub async fn create_listen_session(
     session_id: String,
     user_id: i64,
     lesson_id: i64,
-    created_at: OffsetDateTime
+    created_at: OffsetDateTime,
+    device: Option<String>
 ) -> Result<(i64, bool), sqlx::Error> {
     sqlx::query_as::<_, (i64, bool)>(
         r#"
-            INSERT INTO listen_session (session_id, user_id, lesson_id, created_at)
-            VALUES ($1, $2, $3, $4)
+            INSERT INTO listen_session (session_id, user_id, lesson_id, created_at, device)
+            VALUES ($1, $2, $3, $4, $5)
             ON CONFLICT (session_id) DO UPDATE
                 SET session_id = EXCLUDED.session_id
             RETURNING lesson_id, (xmax = 0) AS inserted;
@@ -415,10 +715,35 @@ pub async fn create_listen_session(
         .bind(user_id)
         .bind(lesson_id)
         .bind(created_at)
+        .bind(device)
         .fetch_one(connection)
         .await
 }
 
+/// Records where the session stopped. Listening time comes from the heartbeats.
+pub async fn update_listen_session(
+    connection: &mut PgConnection,
+    session_id: String,
+    user_id: i64,
+    lesson_id: i64,
+    last_position_ms: i64
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE listen_session
+        SET last_position_ms = $4
+        WHERE session_id = $1 AND user_id = $2 AND lesson_id = $3
+        "#,
+    )
+        .bind(session_id)
+        .bind(user_id)
+        .bind(lesson_id)
+        .bind(last_position_ms)
+        .execute(connection)
+        .await?;
+    Ok(())
+}
+
 pub async fn increase_listen_count(connection: &mut PgConnection, lesson_id: i64) -> Result<(), sqlx::Error> {
     sqlx::query(
         r#"
@@ -452,6 +777,53 @@ pub async fn increase_snip_count(
     Ok(())
 }
 
+/// Recomputes `snip_count` from the lesson's live snips.
+pub async fn recount_snip_count(
+    connection: &mut PgConnection,
+    lesson_id: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE lesson
+        SET snip_count = (
+            SELECT COUNT(*) FROM snip WHERE snip.lesson_id = lesson.id AND snip.deleted_at IS NULL
+        )
+        WHERE id = $1
+        "#,
+    )
+    .bind(lesson_id)
+    .execute(connection)
+    .await?;
+
+    Ok(())
+}
+
+/// Recomputes the rating aggregates from the lesson's visible reviews and
+/// returns its topic.
+pub async fn recount_rating(
+    connection: &mut PgConnection,
+    lesson_id: i64,
+) -> Result<Option<i64>, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (Option<i64>,)>(
+        r#"
+        UPDATE lesson
+        SET rating_count = reviews.count,
+            rating_sum = reviews.sum
+        FROM (
+            SELECT COUNT(*) AS count, COALESCE(SUM(rating), 0)::BIGINT AS sum
+            FROM lesson_review
+            WHERE lesson_id = $1 AND hidden_at IS NULL
+        ) AS reviews
+        WHERE lesson.id = $1
+        RETURNING lesson.topic_id
+        "#,
+    )
+    .bind(lesson_id)
+    .fetch_optional(connection)
+    .await?
+    .and_then(|row| row.0))
+}
+
 pub async fn update_progress(
     connection: &mut PgConnection,
     user_id: i64,
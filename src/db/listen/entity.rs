@@ -0,0 +1,29 @@
+use sqlx::FromRow;
+use time::{Date, OffsetDateTime};
+
+#[derive(Debug, FromRow)]
+pub struct ListenSessionEntity {
+    pub id: i64,
+    pub session_id: String,
+    pub user_id: i64,
+    pub lesson_id: i64,
+    /// Wall-clock time spent listening
+    pub listened_ms: i64,
+    /// Lesson audio covered; faster playback hears more in the same time
+    pub heard_ms: i64,
+    pub counted_at: Option<OffsetDateTime>
+}
+
+pub struct ListenSessionInput {
+    pub session_id: String,
+    pub lesson_id: i64,
+    pub device: Option<String>,
+    pub events: Vec<ListenEventInput>
+}
+
+pub struct ListenEventInput {
+    pub position_ms: i64,
+    pub playback_rate: f32,
+    pub occurred_at: OffsetDateTime,
+    pub local_day: Date
+}
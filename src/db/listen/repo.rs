@@ -0,0 +1,173 @@
+use crate::db::listen::entity::{ListenEventInput, ListenSessionEntity};
+use sqlx::PgConnection;
+
+/// Steps between consecutive events of a session. Expects `WINDOW step`
+/// from [`STEP_WINDOW`].
+pub const STEP_COLUMNS: &str = r#"
+    listen_event.listen_session_id,
+    listen_event.local_day,
+    listen_event.occurred_at,
+    listen_event.position_ms,
+    listen_event.position_ms - LAG(listen_event.position_ms) OVER step AS advanced_ms,
+    (EXTRACT(EPOCH FROM listen_event.occurred_at - LAG(listen_event.occurred_at) OVER step) * 1000)::BIGINT AS elapsed_ms,
+    LAG(listen_event.playback_rate) OVER step AS playback_rate
+"#;
+
+pub const STEP_WINDOW: &str = " WINDOW step AS (PARTITION BY listen_event.listen_session_id ORDER BY listen_event.occurred_at)";
+
+/// Steps that count as listening. Steps more than a minute apart (paused, app
+/// killed), moving backwards, or moving faster than the playback rate allows
+/// with a quarter of slack for clock jitter (seeks) count nothing.
+pub const COUNTED_STEP: &str = "advanced_ms > 0 \
+    AND elapsed_ms > 0 AND elapsed_ms <= 60000 \
+    AND advanced_ms <= elapsed_ms * playback_rate * 1.25 + 1000";
+
+const SELECT: &str = r#"
+    SELECT id, session_id, user_id, lesson_id, listened_ms, heard_ms, counted_at
+    FROM listen_session
+"#;
+
+pub async fn lock_session(
+    connection: &mut PgConnection,
+    session_id: &str
+) -> Result<Option<ListenSessionEntity>, sqlx::Error> {
+    sqlx::query_as::<_, ListenSessionEntity>(&format!("{SELECT} WHERE session_id = $1 FOR UPDATE"))
+        .bind(session_id)
+        .fetch_optional(connection)
+        .await
+}
+
+/// Stores the events, skipping the ones already received.
+pub async fn insert_events(
+    connection: &mut PgConnection,
+    listen_session_id: i64,
+    events: &[ListenEventInput]
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO listen_event (listen_session_id, position_ms, playback_rate, occurred_at, local_day)
+        SELECT $1, event.*
+        FROM UNNEST($2::BIGINT[], $3::REAL[], $4::TIMESTAMPTZ[], $5::DATE[])
+            AS event(position_ms, playback_rate, occurred_at, local_day)
+        ON CONFLICT (listen_session_id, occurred_at) DO NOTHING
+        "#
+    )
+        .bind(listen_session_id)
+        .bind(events.iter().map(|e| e.position_ms).collect::<Vec<_>>())
+        .bind(events.iter().map(|e| e.playback_rate).collect::<Vec<_>>())
+        .bind(events.iter().map(|e| e.occurred_at).collect::<Vec<_>>())
+        .bind(events.iter().map(|e| e.local_day).collect::<Vec<_>>())
+        .execute(connection)
+        .await?;
+    Ok(())
+}
+
+/// CTEs over the events of session `$1`: `counted` holds its counted steps
+/// with the `[from_ms, to_ms]` range of audio each played, and
+/// `heard (grp, heard_ms)` the length of their merged ranges per `group`, so
+/// audio played again is heard once.
+fn session_ranges(group: &str) -> String {
+    format!(r#"
+        WITH steps AS (
+            SELECT {STEP_COLUMNS}
+            FROM listen_event
+            WHERE listen_event.listen_session_id = $1
+            {STEP_WINDOW}
+        ), counted AS (
+            SELECT listen_session_id, local_day, advanced_ms, playback_rate,
+                position_ms - advanced_ms AS from_ms,
+                position_ms AS to_ms
+            FROM steps
+            WHERE {COUNTED_STEP}
+        ), reach AS (
+            SELECT {group} AS grp, from_ms, to_ms,
+                MAX(to_ms) OVER (PARTITION BY {group} ORDER BY from_ms, to_ms
+                    ROWS BETWEEN UNBOUNDED PRECEDING AND 1 PRECEDING) AS reach_ms
+            FROM counted
+        ), islands AS (
+            SELECT grp, from_ms, to_ms,
+                COUNT(*) FILTER (WHERE reach_ms IS NULL OR from_ms > reach_ms)
+                    OVER (PARTITION BY grp ORDER BY from_ms, to_ms ROWS UNBOUNDED PRECEDING) AS island
+            FROM reach
+        ), heard AS (
+            SELECT grp, SUM(to_ms - from_ms)::BIGINT AS heard_ms
+            FROM (
+                SELECT grp, MIN(from_ms) AS from_ms, MAX(to_ms) AS to_ms
+                FROM islands
+                GROUP BY grp, island
+            ) AS merged
+            GROUP BY grp
+        )
+    "#)
+}
+
+/// Rebuilds the daily totals of the session from all of its events and
+/// stores them on the session. Each counted step adds the audio played to
+/// `listened_ms` of the day of its later event. `heard_ms` is the audio
+/// covered at least once, on that day and in the whole session, so replays
+/// do not add to it.
+pub async fn recompute(
+    connection: &mut PgConnection,
+    listen_session_id: i64
+) -> Result<ListenSessionEntity, sqlx::Error> {
+    sqlx::query("DELETE FROM listen_daily WHERE listen_session_id = $1")
+        .bind(listen_session_id)
+        .execute(&mut *connection)
+        .await?;
+
+    sqlx::query(
+        &format!(r#"
+        {ranges}
+        INSERT INTO listen_daily (listen_session_id, user_id, lesson_id, day, listened_ms, heard_ms)
+        SELECT listen_session.id, listen_session.user_id, listen_session.lesson_id, counted.local_day,
+            SUM(counted.advanced_ms / counted.playback_rate)::BIGINT,
+            heard.heard_ms
+        FROM counted
+        JOIN heard ON heard.grp = counted.local_day
+        JOIN listen_session ON listen_session.id = counted.listen_session_id
+        GROUP BY listen_session.id, counted.local_day, heard.heard_ms
+        "#, ranges = session_ranges("local_day"))
+    )
+        .bind(listen_session_id)
+        .execute(&mut *connection)
+        .await?;
+
+    sqlx::query_as::<_, ListenSessionEntity>(
+        &format!(r#"
+        {ranges}
+        UPDATE listen_session
+        SET listened_ms = (
+                SELECT COALESCE(SUM(listened_ms), 0)::BIGINT FROM listen_daily WHERE listen_session_id = $1
+            ),
+            heard_ms = COALESCE((SELECT heard_ms FROM heard), 0)
+        WHERE listen_session.id = $1
+        RETURNING id, session_id, user_id, lesson_id, listen_session.listened_ms, listen_session.heard_ms, counted_at
+        "#, ranges = session_ranges("listen_session_id"))
+    )
+        .bind(listen_session_id)
+        .fetch_one(connection)
+        .await
+}
+
+/// Marks the session counted once `threshold` of the lesson has been heard.
+/// Returns whether it was counted now.
+pub async fn count(
+    connection: &mut PgConnection,
+    listen_session_id: i64,
+    threshold: f64
+) -> Result<bool, sqlx::Error> {
+    Ok(sqlx::query(
+        r#"
+        UPDATE listen_session SET counted_at = NOW()
+        FROM lesson
+        WHERE listen_session.id = $1 AND listen_session.counted_at IS NULL
+            AND lesson.id = listen_session.lesson_id
+            AND listen_session.heard_ms >= lesson.duration * $2
+        "#
+    )
+        .bind(listen_session_id)
+        .bind(threshold)
+        .execute(connection)
+        .await?
+        .rows_affected() > 0)
+}
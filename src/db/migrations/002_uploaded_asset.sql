@@ -0,0 +1,57 @@
+CREATE TYPE asset_storage AS ENUM (
+    'bucket',
+    'local'
+    );
+
+-- uploaded asset
+CREATE TABLE uploaded_asset
+(
+    id          BIGSERIAL PRIMARY KEY,
+    path        TEXT          NOT NULL,
+    storage     asset_storage NOT NULL,
+    file_size   BIGINT        NOT NULL DEFAULT 0,
+    orphaned_at TIMESTAMPTZ,
+    created_at  TIMESTAMPTZ   NOT NULL DEFAULT NOW(),
+    UNIQUE (path)
+);
+
+CREATE INDEX idx_uploaded_asset_orphaned_at
+    ON uploaded_asset (orphaned_at)
+    WHERE orphaned_at IS NOT NULL;
+
+-- every column that may hold an uploaded asset path
+CREATE VIEW uploaded_asset_reference AS
+SELECT audio_path AS path
+FROM lesson
+WHERE deleted_at IS NULL
+UNION ALL
+SELECT cover_image_path
+FROM lesson
+WHERE deleted_at IS NULL
+  AND cover_image_path IS NOT NULL
+UNION ALL
+SELECT cover_image_path
+FROM topic
+WHERE deleted_at IS NULL
+  AND cover_image_path IS NOT NULL
+UNION ALL
+SELECT avatar_path
+FROM author
+WHERE deleted_at IS NULL
+  AND avatar_path IS NOT NULL;
+
+-- backfill assets uploaded before tracking existed
+INSERT INTO uploaded_asset (path, storage, file_size)
+SELECT DISTINCT ON (audio_path) audio_path, 'bucket'::asset_storage, file_size
+FROM lesson
+ON CONFLICT (path) DO NOTHING;
+
+INSERT INTO uploaded_asset (path, storage)
+SELECT path, 'local'::asset_storage
+FROM (SELECT cover_image_path AS path FROM lesson
+      UNION
+      SELECT cover_image_path FROM topic
+      UNION
+      SELECT avatar_path FROM author) AS images
+WHERE path LIKE 'image/%'
+ON CONFLICT (path) DO NOTHING;
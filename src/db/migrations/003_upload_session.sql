@@ -0,0 +1,14 @@
+CREATE TYPE upload_status AS ENUM (
+    'pending',
+    'completed'
+    );
+
+-- an uploaded asset doubles as the upload session that produced it
+ALTER TABLE uploaded_asset
+    ADD COLUMN mime_type    TEXT,
+    ADD COLUMN checksum     TEXT,
+    ADD COLUMN status       upload_status NOT NULL DEFAULT 'completed',
+    ADD COLUMN uploaded_by  BIGINT,
+    ADD COLUMN completed_at TIMESTAMPTZ;
+
+UPDATE uploaded_asset SET completed_at = created_at;
@@ -0,0 +1,16 @@
+-- snip share
+CREATE TABLE snip_share
+(
+    id             BIGSERIAL PRIMARY KEY,
+    snip_id        BIGINT      NOT NULL,
+    user_id        BIGINT      NOT NULL,
+    token          TEXT        NOT NULL,
+    view_count     BIGINT      NOT NULL DEFAULT 0,
+    last_viewed_at TIMESTAMPTZ,
+    revoked_at     TIMESTAMPTZ,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (token)
+);
+
+CREATE INDEX idx_snip_share_snip_id
+    ON snip_share (snip_id);
@@ -0,0 +1,21 @@
+-- weighted search documents: title/name (A), description (B)
+ALTER TABLE author
+    ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', COALESCE(name, '')), 'A')
+        ) STORED;
+
+ALTER TABLE topic
+    ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', COALESCE(title, '')), 'A') ||
+        setweight(to_tsvector('simple', COALESCE(description, '')), 'B')
+        ) STORED;
+
+ALTER TABLE lesson
+    ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', COALESCE(title, '')), 'A') ||
+        setweight(to_tsvector('simple', COALESCE(description, '')), 'B')
+        ) STORED;
+
+CREATE INDEX idx_author_search_vector ON author USING GIN (search_vector);
+CREATE INDEX idx_topic_search_vector ON topic USING GIN (search_vector);
+CREATE INDEX idx_lesson_search_vector ON lesson USING GIN (search_vector);
@@ -0,0 +1,46 @@
+-- canonical search form: lowercase Uzbek Latin without apostrophes or diacritics,
+-- so "darslar", "дарслар", "o‘zbek", "o'zbek" and "ўзбек" index to the same lexemes
+CREATE FUNCTION search_normalize(input TEXT) RETURNS TEXT
+    LANGUAGE SQL
+    IMMUTABLE
+    PARALLEL SAFE
+    RETURNS NULL ON NULL INPUT
+AS
+$$
+SELECT translate(
+               replace(replace(replace(replace(replace(replace(replace(
+                   -- Cyrillic е at the start of a word or after a vowel reads as "ye"
+                   regexp_replace(
+                       regexp_replace(lower(input), '[''‘’ʻʼ`´ъь]', '', 'g'),
+                       '(^|[^а-яёўқғҳ]|[аеёиоуўэюя])е', '\1ye', 'g'
+                   ),
+                   'ё', 'yo'), 'ю', 'yu'), 'я', 'ya'), 'ш', 'sh'), 'щ', 'sh'), 'ч', 'ch'), 'ц', 'ts'),
+               'абвгдежзийклмнопрстуфхыэўқғҳáàâäãåçéèêëíìîïñóòôöõúùûüýÿğş',
+               'abvgdejziyklmnoprstufxieoqghaaaaaaceeeeiiiinooooouuuuyygs'
+       )
+$$;
+
+-- rebuild the search documents on normalized text
+ALTER TABLE author
+    DROP COLUMN search_vector,
+    ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', COALESCE(search_normalize(name), '')), 'A')
+        ) STORED;
+
+ALTER TABLE topic
+    DROP COLUMN search_vector,
+    ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', COALESCE(search_normalize(title), '')), 'A') ||
+        setweight(to_tsvector('simple', COALESCE(search_normalize(description), '')), 'B')
+        ) STORED;
+
+ALTER TABLE lesson
+    DROP COLUMN search_vector,
+    ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', COALESCE(search_normalize(title), '')), 'A') ||
+        setweight(to_tsvector('simple', COALESCE(search_normalize(description), '')), 'B')
+        ) STORED;
+
+CREATE INDEX idx_author_search_vector ON author USING GIN (search_vector);
+CREATE INDEX idx_topic_search_vector ON topic USING GIN (search_vector);
+CREATE INDEX idx_lesson_search_vector ON lesson USING GIN (search_vector);
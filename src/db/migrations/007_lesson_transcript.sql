@@ -0,0 +1,21 @@
+-- lesson transcript
+CREATE TABLE lesson_transcript_segment
+(
+    id            BIGSERIAL PRIMARY KEY,
+    lesson_id     BIGINT      NOT NULL,
+    position      INT         NOT NULL,
+    start_ms      BIGINT      NOT NULL,
+    end_ms        BIGINT      NOT NULL,
+    text          TEXT        NOT NULL,
+    search_vector TSVECTOR GENERATED ALWAYS AS (
+        to_tsvector('simple', search_normalize(text))
+        ) STORED,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (lesson_id, position)
+);
+
+CREATE INDEX idx_lesson_transcript_segment_range
+    ON lesson_transcript_segment (lesson_id, start_ms, end_ms);
+
+CREATE INDEX idx_lesson_transcript_segment_search_vector
+    ON lesson_transcript_segment USING GIN (search_vector);
@@ -0,0 +1,13 @@
+-- lesson chapters
+CREATE TABLE lesson_chapter
+(
+    id          BIGSERIAL PRIMARY KEY,
+    lesson_id   BIGINT      NOT NULL,
+    position    INT         NOT NULL,
+    title       TEXT        NOT NULL,
+    start_ms    BIGINT      NOT NULL,
+    description TEXT,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (lesson_id, position),
+    UNIQUE (lesson_id, start_ms)
+);
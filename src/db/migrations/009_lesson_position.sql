@@ -0,0 +1,15 @@
+-- explicit lesson order within a topic
+ALTER TABLE lesson
+    ADD COLUMN position INT NOT NULL DEFAULT 0;
+
+UPDATE lesson
+SET position = ordered.position
+FROM (SELECT id,
+             ROW_NUMBER() OVER (PARTITION BY topic_id ORDER BY created_at, id) - 1 AS position
+      FROM lesson
+      WHERE topic_id IS NOT NULL) AS ordered
+WHERE lesson.id = ordered.id;
+
+CREATE INDEX idx_lesson_topic_position
+    ON lesson (topic_id, position, id)
+    WHERE deleted_at IS NULL;
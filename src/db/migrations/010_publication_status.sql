@@ -0,0 +1,30 @@
+-- publication lifecycle of lessons and topics
+CREATE TYPE publication_status AS ENUM (
+    'draft',
+    'scheduled',
+    'published',
+    'unlisted'
+);
+
+-- existing content stays live
+ALTER TABLE topic
+    ADD COLUMN publication_status publication_status NOT NULL DEFAULT 'published',
+    ADD COLUMN publish_at         TIMESTAMPTZ,
+    ADD COLUMN published_at       TIMESTAMPTZ;
+
+ALTER TABLE lesson
+    ADD COLUMN publication_status publication_status NOT NULL DEFAULT 'published',
+    ADD COLUMN publish_at         TIMESTAMPTZ,
+    ADD COLUMN published_at       TIMESTAMPTZ;
+
+UPDATE topic SET published_at = created_at;
+UPDATE lesson SET published_at = created_at;
+
+ALTER TABLE topic ALTER COLUMN publication_status SET DEFAULT 'draft';
+ALTER TABLE lesson ALTER COLUMN publication_status SET DEFAULT 'draft';
+
+CREATE INDEX idx_topic_scheduled ON topic (publish_at) WHERE publication_status = 'scheduled';
+CREATE INDEX idx_lesson_scheduled ON lesson (publish_at) WHERE publication_status = 'scheduled';
+
+CREATE INDEX idx_topic_published_at ON topic (published_at);
+CREATE INDEX idx_lesson_published_at ON lesson (published_at);
@@ -0,0 +1,21 @@
+-- trashed rows keep their assets until they are purged
+CREATE OR REPLACE VIEW uploaded_asset_reference AS
+SELECT audio_path AS path
+FROM lesson
+UNION ALL
+SELECT cover_image_path
+FROM lesson
+WHERE cover_image_path IS NOT NULL
+UNION ALL
+SELECT cover_image_path
+FROM topic
+WHERE cover_image_path IS NOT NULL
+UNION ALL
+SELECT avatar_path
+FROM author
+WHERE avatar_path IS NOT NULL;
+
+CREATE INDEX idx_author_deleted_at ON author (deleted_at) WHERE deleted_at IS NOT NULL;
+CREATE INDEX idx_topic_deleted_at ON topic (deleted_at) WHERE deleted_at IS NOT NULL;
+CREATE INDEX idx_lesson_deleted_at ON lesson (deleted_at) WHERE deleted_at IS NOT NULL;
+CREATE INDEX idx_snip_deleted_at ON snip (deleted_at) WHERE deleted_at IS NOT NULL;
@@ -0,0 +1,47 @@
+-- immutable snapshot of a lesson taken on create and on every update
+CREATE TABLE lesson_revision
+(
+    id               BIGSERIAL PRIMARY KEY,
+    lesson_id        BIGINT      NOT NULL,
+    revision         INT         NOT NULL,
+    admin_id         BIGINT,
+    title            TEXT        NOT NULL,
+    description      TEXT,
+    cover_image_path TEXT,
+    audio_path       TEXT        NOT NULL,
+    duration         BIGINT      NOT NULL,
+    file_size        BIGINT      NOT NULL,
+    changes          JSONB       NOT NULL DEFAULT '[]',
+    restored_from    INT,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (lesson_id, revision)
+);
+
+-- existing lessons start their history at their current state
+INSERT INTO lesson_revision (lesson_id, revision, title, description, cover_image_path, audio_path, duration, file_size, created_at)
+SELECT id, 1, title, description, cover_image_path, audio_path, duration, file_size, updated_at
+FROM lesson;
+
+-- assets of a revision stay referenced so it can be rolled back to
+CREATE OR REPLACE VIEW uploaded_asset_reference AS
+SELECT audio_path AS path
+FROM lesson
+UNION ALL
+SELECT cover_image_path
+FROM lesson
+WHERE cover_image_path IS NOT NULL
+UNION ALL
+SELECT cover_image_path
+FROM topic
+WHERE cover_image_path IS NOT NULL
+UNION ALL
+SELECT avatar_path
+FROM author
+WHERE avatar_path IS NOT NULL
+UNION ALL
+SELECT audio_path
+FROM lesson_revision
+UNION ALL
+SELECT cover_image_path
+FROM lesson_revision
+WHERE cover_image_path IS NOT NULL;
@@ -0,0 +1,68 @@
+-- per-locale content; the columns on author, topic and lesson are the fallback
+CREATE TABLE author_translation
+(
+    id            BIGSERIAL PRIMARY KEY,
+    author_id     BIGINT      NOT NULL,
+    locale        TEXT        NOT NULL,
+    name          TEXT        NOT NULL,
+    search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', COALESCE(search_normalize(name), '')), 'A')
+        ) STORED,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (author_id, locale)
+);
+
+CREATE TABLE topic_translation
+(
+    id            BIGSERIAL PRIMARY KEY,
+    topic_id      BIGINT      NOT NULL,
+    locale        TEXT        NOT NULL,
+    title         TEXT        NOT NULL,
+    description   TEXT,
+    search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', COALESCE(search_normalize(title), '')), 'A') ||
+        setweight(to_tsvector('simple', COALESCE(search_normalize(description), '')), 'B')
+        ) STORED,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (topic_id, locale)
+);
+
+CREATE TABLE lesson_translation
+(
+    id            BIGSERIAL PRIMARY KEY,
+    lesson_id     BIGINT      NOT NULL,
+    locale        TEXT        NOT NULL,
+    title         TEXT        NOT NULL,
+    description   TEXT,
+    search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', COALESCE(search_normalize(title), '')), 'A') ||
+        setweight(to_tsvector('simple', COALESCE(search_normalize(description), '')), 'B')
+        ) STORED,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (lesson_id, locale)
+);
+
+CREATE INDEX idx_author_translation_search_vector ON author_translation USING GIN (search_vector);
+CREATE INDEX idx_topic_translation_search_vector ON topic_translation USING GIN (search_vector);
+CREATE INDEX idx_lesson_translation_search_vector ON lesson_translation USING GIN (search_vector);
+
+CREATE TRIGGER trg_author_translation_set_updated_at
+    BEFORE UPDATE
+    ON author_translation
+    FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
+
+CREATE TRIGGER trg_topic_translation_set_updated_at
+    BEFORE UPDATE
+    ON topic_translation
+    FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
+
+CREATE TRIGGER trg_lesson_translation_set_updated_at
+    BEFORE UPDATE
+    ON lesson_translation
+    FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
@@ -0,0 +1,37 @@
+-- user playlists
+CREATE TABLE playlist
+(
+    id          BIGSERIAL PRIMARY KEY,
+    user_id     BIGINT      NOT NULL,
+    title       TEXT        NOT NULL,
+    description TEXT,
+    share_token TEXT,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (share_token)
+);
+
+CREATE INDEX idx_playlist_user_id
+    ON playlist (user_id, id);
+
+CREATE TRIGGER trg_playlist_set_updated_at
+    BEFORE UPDATE
+    ON playlist
+    FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
+
+CREATE TABLE playlist_item
+(
+    id          BIGSERIAL PRIMARY KEY,
+    playlist_id BIGINT      NOT NULL,
+    lesson_id   BIGINT      NOT NULL,
+    position    INT         NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (playlist_id, lesson_id)
+);
+
+CREATE INDEX idx_playlist_item_position
+    ON playlist_item (playlist_id, position, lesson_id);
+
+CREATE INDEX idx_playlist_item_lesson_id
+    ON playlist_item (lesson_id);
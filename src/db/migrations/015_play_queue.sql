@@ -0,0 +1,33 @@
+-- per-user play queue shared by all devices
+CREATE TABLE play_queue
+(
+    id                BIGSERIAL PRIMARY KEY,
+    user_id           BIGINT      NOT NULL,
+    version           BIGINT      NOT NULL DEFAULT 0,
+    current_lesson_id BIGINT,
+    created_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id)
+);
+
+CREATE TRIGGER trg_play_queue_set_updated_at
+    BEFORE UPDATE
+    ON play_queue
+    FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
+
+CREATE TABLE play_queue_item
+(
+    id         BIGSERIAL PRIMARY KEY,
+    user_id    BIGINT      NOT NULL,
+    lesson_id  BIGINT      NOT NULL,
+    position   INT         NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, lesson_id)
+);
+
+CREATE INDEX idx_play_queue_item_position
+    ON play_queue_item (user_id, position);
+
+CREATE INDEX idx_play_queue_item_lesson_id
+    ON play_queue_item (lesson_id);
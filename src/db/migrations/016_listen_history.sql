@@ -0,0 +1,19 @@
+-- listen sessions double as the listening history
+ALTER TABLE listen_session
+    ADD COLUMN device           TEXT,
+    ADD COLUMN last_position_ms BIGINT      NOT NULL DEFAULT 0,
+    ADD COLUMN listened_ms      BIGINT      NOT NULL DEFAULT 0,
+    ADD COLUMN updated_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    ADD COLUMN hidden_at        TIMESTAMPTZ;
+
+UPDATE listen_session SET updated_at = created_at;
+
+CREATE TRIGGER trg_listen_session_set_updated_at
+    BEFORE UPDATE
+    ON listen_session
+    FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
+
+CREATE INDEX idx_listen_session_history
+    ON listen_session (user_id, created_at DESC, id DESC)
+    WHERE hidden_at IS NULL;
@@ -0,0 +1,37 @@
+-- playback heartbeats; listening time is computed from consecutive events
+ALTER TABLE listen_session
+    ADD COLUMN heard_ms   BIGINT NOT NULL DEFAULT 0,
+    ADD COLUMN counted_at TIMESTAMPTZ;
+
+-- sessions recorded so far were counted when they were created
+UPDATE listen_session SET counted_at = created_at;
+
+CREATE TABLE listen_event
+(
+    id                BIGSERIAL PRIMARY KEY,
+    listen_session_id BIGINT      NOT NULL,
+    position_ms       BIGINT      NOT NULL,
+    playback_rate     REAL        NOT NULL,
+    occurred_at       TIMESTAMPTZ NOT NULL,
+    -- day of occurred_at on the device that sent it
+    local_day         DATE        NOT NULL,
+    created_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (listen_session_id, occurred_at)
+);
+
+CREATE TABLE listen_daily
+(
+    listen_session_id BIGINT NOT NULL,
+    user_id           BIGINT NOT NULL,
+    lesson_id         BIGINT NOT NULL,
+    day               DATE   NOT NULL,
+    listened_ms       BIGINT NOT NULL,
+    heard_ms          BIGINT NOT NULL,
+    PRIMARY KEY (listen_session_id, day)
+);
+
+CREATE INDEX idx_listen_daily_user_day
+    ON listen_daily (user_id, day);
+
+CREATE INDEX idx_listen_daily_lesson_day
+    ON listen_daily (lesson_id, day);
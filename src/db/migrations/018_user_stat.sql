@@ -0,0 +1,25 @@
+-- per-user settings the statistics are computed with
+ALTER TABLE users
+    ADD COLUMN timezone           TEXT NOT NULL DEFAULT 'UTC',
+    ADD COLUMN daily_goal_minutes INT  NOT NULL DEFAULT 15;
+
+-- daily rollup in the user's timezone, rebuilt for the days that change
+CREATE TABLE user_daily_stat
+(
+    user_id           BIGINT NOT NULL,
+    day               DATE   NOT NULL,
+    listened_ms       BIGINT NOT NULL DEFAULT 0,
+    lessons_completed INT    NOT NULL DEFAULT 0,
+    snips_created     INT    NOT NULL DEFAULT 0,
+    PRIMARY KEY (user_id, day)
+);
+
+CREATE INDEX idx_listen_event_occurred_at
+    ON listen_event (occurred_at);
+
+CREATE INDEX idx_lesson_progress_completed_at
+    ON lesson_progress (user_id, completed_at)
+    WHERE completed_at IS NOT NULL;
+
+CREATE INDEX idx_snip_user_created_at
+    ON snip (user_id, created_at);
@@ -0,0 +1,24 @@
+CREATE TYPE achievement AS ENUM (
+    'first_lesson',
+    'first_topic',
+    'week_streak',
+    'month_streak',
+    'ten_snips',
+    'hundred_snips',
+    'ten_authors',
+    'ten_hours'
+);
+
+-- badges a user has earned, kept once earned
+CREATE TABLE user_achievement
+(
+    id          BIGSERIAL PRIMARY KEY,
+    user_id     BIGINT      NOT NULL,
+    achievement achievement NOT NULL,
+    earned_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, achievement)
+);
+
+CREATE INDEX idx_listen_session_user_counted
+    ON listen_session (user_id)
+    WHERE counted_at IS NOT NULL;
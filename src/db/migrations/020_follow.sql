@@ -0,0 +1,26 @@
+CREATE TABLE author_follow
+(
+    id         BIGSERIAL PRIMARY KEY,
+    user_id    BIGINT      NOT NULL,
+    author_id  BIGINT      NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, author_id)
+);
+
+CREATE TABLE topic_subscription
+(
+    id         BIGSERIAL PRIMARY KEY,
+    user_id    BIGINT      NOT NULL,
+    topic_id   BIGINT      NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, topic_id)
+);
+
+-- the feed reads the newest lessons of the followed authors and topics
+CREATE INDEX idx_lesson_author_published_at
+    ON lesson (author_id, published_at DESC, id DESC)
+    WHERE published_at IS NOT NULL;
+
+CREATE INDEX idx_lesson_topic_published_at
+    ON lesson (topic_id, published_at DESC, id DESC)
+    WHERE published_at IS NOT NULL;
@@ -0,0 +1,69 @@
+CREATE TYPE notification_kind AS ENUM (
+    'new_lesson',
+    'streak_at_risk'
+);
+
+CREATE TYPE device_platform AS ENUM (
+    'android',
+    'ios',
+    'web'
+);
+
+CREATE TABLE notification
+(
+    id          BIGSERIAL PRIMARY KEY,
+    user_id     BIGINT            NOT NULL,
+    kind        notification_kind NOT NULL,
+    lesson_id   BIGINT,
+    topic_id    BIGINT,
+    -- day in the user's timezone and streak length of a streak reminder
+    day         DATE,
+    streak_days INT,
+    read_at     TIMESTAMPTZ,
+    pushed_at   TIMESTAMPTZ,
+    created_at  TIMESTAMPTZ       NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_notification_user_created_at
+    ON notification (user_id, created_at DESC, id DESC);
+
+CREATE INDEX idx_notification_unpushed
+    ON notification (created_at)
+    WHERE pushed_at IS NULL;
+
+-- one notification per lesson and one streak reminder per day
+CREATE UNIQUE INDEX idx_notification_unique_lesson
+    ON notification (user_id, lesson_id)
+    WHERE kind = 'new_lesson';
+
+CREATE UNIQUE INDEX idx_notification_unique_streak
+    ON notification (user_id, day)
+    WHERE kind = 'streak_at_risk';
+
+-- a token belongs to the user who registered it last
+CREATE TABLE device_token
+(
+    id         BIGSERIAL PRIMARY KEY,
+    user_id    BIGINT          NOT NULL,
+    platform   device_platform NOT NULL,
+    token      TEXT            NOT NULL,
+    language   TEXT            NOT NULL DEFAULT 'en',
+    created_at TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    UNIQUE (token)
+);
+
+CREATE INDEX idx_device_token_user_id
+    ON device_token (user_id);
+
+-- opt-outs
+ALTER TABLE users
+    ADD COLUMN notify_new_lessons BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN notify_streak      BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN push_enabled       BOOLEAN NOT NULL DEFAULT TRUE;
+
+CREATE TRIGGER trg_device_token_set_updated_at
+    BEFORE UPDATE
+    ON device_token
+    FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
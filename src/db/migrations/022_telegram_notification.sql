@@ -0,0 +1,25 @@
+ALTER TYPE notification_kind ADD VALUE 'weekly_summary';
+
+-- totals of the week starting on `day` for a weekly summary
+ALTER TABLE notification
+    ADD COLUMN listened_ms       BIGINT,
+    ADD COLUMN lessons_completed INT,
+    ADD COLUMN telegram_sent_at  TIMESTAMPTZ;
+
+-- one streak reminder per day and one summary per week; the new enum value
+-- cannot be referenced in this transaction, hence the index over all kinds
+DROP INDEX idx_notification_unique_streak;
+
+CREATE UNIQUE INDEX idx_notification_unique_day
+    ON notification (user_id, kind, day)
+    WHERE day IS NOT NULL;
+
+CREATE INDEX idx_notification_untelegrammed
+    ON notification (created_at)
+    WHERE telegram_sent_at IS NULL;
+
+-- Telegram messages are opt-in and written in `telegram_language`
+ALTER TABLE users
+    ADD COLUMN notify_weekly_summary BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN telegram_enabled      BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN telegram_language     TEXT    NOT NULL DEFAULT 'en';
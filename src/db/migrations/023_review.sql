@@ -0,0 +1,48 @@
+CREATE TABLE lesson_review
+(
+    id         BIGSERIAL PRIMARY KEY,
+    user_id    BIGINT      NOT NULL,
+    lesson_id  BIGINT      NOT NULL,
+    rating     SMALLINT    NOT NULL CHECK (rating BETWEEN 1 AND 5),
+    text       TEXT,
+    -- hidden by an admin: left out of the lists and the aggregates
+    hidden_at  TIMESTAMPTZ,
+    hidden_by  BIGINT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, lesson_id)
+);
+
+CREATE INDEX idx_lesson_review_lesson_created_at
+    ON lesson_review (lesson_id, created_at DESC, id DESC)
+    WHERE hidden_at IS NULL;
+
+CREATE INDEX idx_lesson_review_created_at
+    ON lesson_review (created_at DESC, id DESC);
+
+CREATE TRIGGER trg_lesson_review_set_updated_at
+    BEFORE UPDATE
+    ON lesson_review
+    FOR EACH ROW
+EXECUTE FUNCTION set_updated_at();
+
+-- visible ratings of the lesson, and of the live lessons of the topic
+ALTER TABLE lesson
+    ADD COLUMN rating_count   BIGINT NOT NULL DEFAULT 0,
+    ADD COLUMN rating_sum     BIGINT NOT NULL DEFAULT 0,
+    ADD COLUMN rating_average DOUBLE PRECISION GENERATED ALWAYS AS (
+        CASE WHEN rating_count = 0 THEN 0 ELSE rating_sum::DOUBLE PRECISION / rating_count END
+    ) STORED;
+
+ALTER TABLE topic
+    ADD COLUMN rating_count   BIGINT NOT NULL DEFAULT 0,
+    ADD COLUMN rating_sum     BIGINT NOT NULL DEFAULT 0,
+    ADD COLUMN rating_average DOUBLE PRECISION GENERATED ALWAYS AS (
+        CASE WHEN rating_count = 0 THEN 0 ELSE rating_sum::DOUBLE PRECISION / rating_count END
+    ) STORED;
+
+CREATE INDEX idx_lesson_rating_average
+    ON lesson (rating_average, id);
+
+CREATE INDEX idx_topic_rating_average
+    ON topic (rating_average, id);
@@ -0,0 +1,3 @@
+-- pushes that failed on every device are claimed again on the next run
+ALTER TABLE notification
+    ADD COLUMN push_attempts INT NOT NULL DEFAULT 0;
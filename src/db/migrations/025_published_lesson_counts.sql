@@ -0,0 +1,35 @@
+-- lesson counts, durations and completed counts only cover published lessons
+UPDATE topic
+SET lesson_count = lessons.count,
+    total_duration = lessons.duration
+FROM (
+    SELECT topic.id,
+        COUNT(lesson.id) AS count,
+        COALESCE(SUM(lesson.duration), 0)::BIGINT AS duration
+    FROM topic
+    LEFT JOIN lesson ON lesson.topic_id = topic.id
+        AND lesson.deleted_at IS NULL
+        AND lesson.publication_status = 'published'
+    GROUP BY topic.id
+) AS lessons
+WHERE topic.id = lessons.id;
+
+UPDATE author
+SET lesson_count = (
+    SELECT COUNT(*) FROM lesson
+    WHERE lesson.author_id = author.id
+      AND lesson.deleted_at IS NULL
+      AND lesson.publication_status = 'published'
+);
+
+UPDATE topic_progress
+SET completed_lesson_count = (
+    SELECT COUNT(lesson_progress.id)
+    FROM lesson_progress
+    JOIN lesson ON lesson.id = lesson_progress.lesson_id
+    WHERE lesson_progress.user_id = topic_progress.user_id
+      AND lesson_progress.topic_id = topic_progress.topic_id
+      AND lesson_progress.status = 'completed'
+      AND lesson.deleted_at IS NULL
+      AND lesson.publication_status = 'published'
+);
@@ -0,0 +1,3 @@
+-- Telegram messages that failed are claimed again on the next run
+ALTER TABLE notification
+    ADD COLUMN telegram_attempts INT NOT NULL DEFAULT 0;
@@ -4,4 +4,21 @@ pub mod author;
 pub mod user;
 pub mod lesson;
 pub mod session;
-pub mod snip;
\ No newline at end of file
+pub mod snip;
+pub mod asset;
+pub mod snip_share;
+pub mod search;
+pub mod transcript;
+pub mod chapter;
+pub mod trash;
+pub mod revision;
+pub mod translation;
+pub mod playlist;
+pub mod queue;
+pub mod history;
+pub mod listen;
+pub mod stat;
+pub mod achievement;
+pub mod follow;
+pub mod notification;
+pub mod review;
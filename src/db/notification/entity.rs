@@ -0,0 +1,56 @@
+use crate::module::common::enums::{DevicePlatform, NotificationKind};
+use sqlx::FromRow;
+use time::{Date, OffsetDateTime};
+
+#[derive(Debug, Clone, FromRow)]
+pub struct NotificationEntity {
+    pub id: i64,
+    pub user_id: i64,
+    pub kind: NotificationKind,
+    pub lesson_id: Option<i64>,
+    pub lesson_title: Option<String>,
+    pub topic_id: Option<i64>,
+    pub topic_title: Option<String>,
+    pub streak_days: Option<i32>,
+    pub listened_ms: Option<i64>,
+    pub lessons_completed: Option<i32>,
+    pub read_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime
+}
+
+#[derive(Debug, FromRow)]
+pub struct DeviceTokenEntity {
+    pub id: i64,
+    pub platform: DevicePlatform,
+    pub token: String,
+    /// Language the device's pushes are written in
+    pub language: String,
+    pub updated_at: OffsetDateTime
+}
+
+#[derive(Debug, FromRow)]
+pub struct NotificationSettingEntity {
+    pub notify_new_lessons: bool,
+    pub notify_streak: bool,
+    pub notify_weekly_summary: bool,
+    /// Off keeps notifications in the list only
+    pub push_enabled: bool,
+    /// Opt-in, for users signed in with Telegram
+    pub telegram_enabled: bool
+}
+
+/// A notification claimed for delivery over Telegram.
+#[derive(Debug, FromRow)]
+pub struct TelegramNotificationEntity {
+    #[sqlx(flatten)]
+    pub notification: NotificationEntity,
+    pub telegram_id: i64,
+    pub telegram_language: String
+}
+
+/// A user whose streak ends tonight unless they listen.
+#[derive(Debug, FromRow)]
+pub struct StreakAtRiskEntity {
+    pub user_id: i64,
+    pub today: Date
+}
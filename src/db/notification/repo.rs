@@ -0,0 +1,412 @@
+use crate::db::lesson::repo::LISTED;
+use crate::db::notification::entity::{
+    DeviceTokenEntity, NotificationEntity, NotificationSettingEntity, StreakAtRiskEntity, TelegramNotificationEntity
+};
+use crate::module::common::enums::DevicePlatform;
+use crate::module::user::notification::dto::NotificationCursor;
+use sqlx::{PgPool, Postgres, QueryBuilder};
+use time::{Date, OffsetDateTime};
+
+/// Columns of `NotificationEntity` over `notification` with the lesson and
+/// topic titles joined.
+const NOTIFICATION_COLUMNS: &str = r#"
+        notification.id,
+        notification.user_id,
+        notification.kind,
+        notification.lesson_id,
+        lesson.title AS lesson_title,
+        notification.topic_id,
+        topic.title AS topic_title,
+        notification.streak_days,
+        notification.listened_ms,
+        notification.lessons_completed,
+        notification.read_at,
+        notification.created_at
+"#;
+
+const NOTIFICATION_JOINS: &str = r#"
+        LEFT JOIN lesson ON lesson.id = notification.lesson_id
+        LEFT JOIN topic ON topic.id = notification.topic_id
+"#;
+
+/// Notifications of the user, latest first.
+pub async fn page(
+    db: &PgPool,
+    user_id: i64,
+    unread: bool,
+    limit: u32,
+    cursor: Option<NotificationCursor>
+) -> Result<Vec<NotificationEntity>, sqlx::Error> {
+    let mut query = QueryBuilder::<Postgres>::new("SELECT");
+    query.push(NOTIFICATION_COLUMNS)
+        .push(" FROM notification")
+        .push(NOTIFICATION_JOINS)
+        .push(" WHERE notification.user_id = ")
+        .push_bind(user_id);
+
+    if unread {
+        query.push(" AND notification.read_at IS NULL");
+    }
+    if let Some(cursor) = cursor {
+        query.push(" AND (notification.created_at, notification.id) < (")
+            .push_bind(cursor.created_at).push(", ")
+            .push_bind(cursor.id).push(")");
+    }
+
+    query
+        .push(" ORDER BY notification.created_at DESC, notification.id DESC LIMIT ")
+        .push_bind(limit as i64)
+        .build_query_as::<NotificationEntity>()
+        .fetch_all(db)
+        .await
+}
+
+pub async fn unread_count(db: &PgPool, user_id: i64) -> Result<i64, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (i64,)>(
+        "SELECT COUNT(*) FROM notification WHERE user_id = $1 AND read_at IS NULL"
+    )
+        .bind(user_id)
+        .fetch_one(db)
+        .await?.0)
+}
+
+/// Keeps the first read time of a notification read before.
+pub async fn mark_read(db: &PgPool, id: i64, user_id: i64) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query(
+        "UPDATE notification SET read_at = COALESCE(read_at, NOW()) WHERE id = $1 AND user_id = $2"
+    )
+        .bind(id)
+        .bind(user_id)
+        .execute(db)
+        .await?
+        .rows_affected())
+}
+
+pub async fn mark_all_read(db: &PgPool, user_id: i64) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query("UPDATE notification SET read_at = NOW() WHERE user_id = $1 AND read_at IS NULL")
+        .bind(user_id)
+        .execute(db)
+        .await?
+        .rows_affected())
+}
+
+pub async fn get_settings(db: &PgPool, user_id: i64) -> Result<Option<NotificationSettingEntity>, sqlx::Error> {
+    sqlx::query_as::<_, NotificationSettingEntity>(
+        r#"
+        SELECT notify_new_lessons, notify_streak, notify_weekly_summary, push_enabled, telegram_enabled
+        FROM users
+        WHERE id = $1
+        "#
+    )
+        .bind(user_id)
+        .fetch_optional(db)
+        .await
+}
+
+/// Telegram messages are written in `telegram_language` from then on.
+pub async fn update_settings(
+    db: &PgPool,
+    user_id: i64,
+    settings: NotificationSettingEntity,
+    telegram_language: &str
+) -> Result<Option<NotificationSettingEntity>, sqlx::Error> {
+    sqlx::query_as::<_, NotificationSettingEntity>(
+        r#"
+        UPDATE users
+        SET notify_new_lessons = $2,
+            notify_streak = $3,
+            notify_weekly_summary = $4,
+            push_enabled = $5,
+            telegram_enabled = $6,
+            telegram_language = $7
+        WHERE id = $1
+        RETURNING notify_new_lessons, notify_streak, notify_weekly_summary, push_enabled, telegram_enabled
+        "#
+    )
+        .bind(user_id)
+        .bind(settings.notify_new_lessons)
+        .bind(settings.notify_streak)
+        .bind(settings.notify_weekly_summary)
+        .bind(settings.push_enabled)
+        .bind(settings.telegram_enabled)
+        .bind(telegram_language)
+        .fetch_optional(db)
+        .await
+}
+
+/// Registers the token for the user, taking it over from whoever had it.
+pub async fn upsert_device(
+    db: &PgPool,
+    user_id: i64,
+    platform: DevicePlatform,
+    token: &str,
+    language: &str
+) -> Result<DeviceTokenEntity, sqlx::Error> {
+    sqlx::query_as::<_, DeviceTokenEntity>(
+        r#"
+        INSERT INTO device_token (user_id, platform, token, language)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (token) DO UPDATE
+        SET user_id = EXCLUDED.user_id,
+            platform = EXCLUDED.platform,
+            language = EXCLUDED.language
+        RETURNING id, platform, token, language, updated_at
+        "#
+    )
+        .bind(user_id)
+        .bind(platform)
+        .bind(token)
+        .bind(language)
+        .fetch_one(db)
+        .await
+}
+
+pub async fn delete_device(db: &PgPool, user_id: i64, token: &str) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query("DELETE FROM device_token WHERE user_id = $1 AND token = $2")
+        .bind(user_id)
+        .bind(token)
+        .execute(db)
+        .await?
+        .rows_affected())
+}
+
+/// Drops a token the push provider rejected.
+pub async fn delete_token(db: &PgPool, token: &str) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM device_token WHERE token = $1")
+        .bind(token)
+        .execute(db)
+        .await?;
+    Ok(())
+}
+
+pub async fn devices(db: &PgPool, user_id: i64) -> Result<Vec<DeviceTokenEntity>, sqlx::Error> {
+    sqlx::query_as::<_, DeviceTokenEntity>(
+        "SELECT id, platform, token, language, updated_at FROM device_token WHERE user_id = $1 ORDER BY id"
+    )
+        .bind(user_id)
+        .fetch_all(db)
+        .await
+}
+
+/// Notifies the subscribers of a topic about its lessons published since
+/// `since`, once per lesson. Lessons published before the subscription are
+/// left out.
+pub async fn insert_new_lessons(db: &PgPool, since: OffsetDateTime) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query(
+        &format!(r#"
+        INSERT INTO notification (user_id, kind, lesson_id, topic_id)
+        SELECT topic_subscription.user_id, 'new_lesson', lesson.id, lesson.topic_id
+        FROM lesson
+        JOIN topic ON topic.id = lesson.topic_id
+        JOIN topic_subscription ON topic_subscription.topic_id = lesson.topic_id
+            AND topic_subscription.created_at <= lesson.published_at
+        JOIN users ON users.id = topic_subscription.user_id AND users.notify_new_lessons
+        WHERE lesson.published_at >= $1 AND {LISTED}
+        ON CONFLICT DO NOTHING
+        "#)
+    )
+        .bind(since)
+        .execute(db)
+        .await?
+        .rows_affected())
+}
+
+/// Users past `hour` in their timezone who were active yesterday but not yet
+/// today, and were not reminded today.
+pub async fn streaks_at_risk(db: &PgPool, hour: i32) -> Result<Vec<StreakAtRiskEntity>, sqlx::Error> {
+    sqlx::query_as::<_, StreakAtRiskEntity>(
+        r#"
+        WITH local AS (
+            SELECT id AS user_id,
+                (NOW() AT TIME ZONE timezone)::DATE AS today,
+                EXTRACT(HOUR FROM NOW() AT TIME ZONE timezone)::INT AS hour
+            FROM users
+            WHERE notify_streak
+        )
+        SELECT local.user_id, local.today
+        FROM local
+        JOIN user_daily_stat yesterday ON yesterday.user_id = local.user_id
+            AND yesterday.day = local.today - 1
+            AND (yesterday.listened_ms > 0 OR yesterday.lessons_completed > 0)
+        WHERE local.hour >= $1
+            AND NOT EXISTS (
+                SELECT 1 FROM user_daily_stat today
+                WHERE today.user_id = local.user_id AND today.day = local.today
+                    AND (today.listened_ms > 0 OR today.lessons_completed > 0)
+            )
+            AND NOT EXISTS (
+                SELECT 1 FROM notification
+                WHERE notification.user_id = local.user_id
+                    AND notification.kind = 'streak_at_risk' AND notification.day = local.today
+            )
+        "#
+    )
+        .bind(hour)
+        .fetch_all(db)
+        .await
+}
+
+pub async fn insert_streak_at_risk(
+    db: &PgPool,
+    user_id: i64,
+    day: Date,
+    streak_days: i64
+) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query(
+        r#"
+        INSERT INTO notification (user_id, kind, day, streak_days)
+        VALUES ($1, 'streak_at_risk', $2, $3)
+        ON CONFLICT DO NOTHING
+        "#
+    )
+        .bind(user_id)
+        .bind(day)
+        .bind(streak_days as i32)
+        .execute(db)
+        .await?
+        .rows_affected())
+}
+
+/// Summarizes the past week, Monday to Sunday in their timezone, for users
+/// who listened or completed something in it. Runs from `hour` on Mondays and
+/// any time later in the week, once per user and week.
+pub async fn insert_weekly_summaries(db: &PgPool, hour: i32) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query(
+        r#"
+        WITH local AS (
+            SELECT id AS user_id,
+                DATE_TRUNC('week', NOW() AT TIME ZONE timezone)::DATE - 7 AS week,
+                EXTRACT(ISODOW FROM NOW() AT TIME ZONE timezone)::INT AS weekday,
+                EXTRACT(HOUR FROM NOW() AT TIME ZONE timezone)::INT AS hour
+            FROM users
+            WHERE notify_weekly_summary
+        )
+        INSERT INTO notification (user_id, kind, day, listened_ms, lessons_completed)
+        SELECT local.user_id, 'weekly_summary', local.week,
+            SUM(stat.listened_ms), SUM(stat.lessons_completed)
+        FROM local
+        JOIN user_daily_stat stat ON stat.user_id = local.user_id
+            AND stat.day BETWEEN local.week AND local.week + 6
+        WHERE local.weekday > 1 OR local.hour >= $1
+        GROUP BY local.user_id, local.week
+        HAVING SUM(stat.listened_ms) > 0 OR SUM(stat.lessons_completed) > 0
+        ON CONFLICT DO NOTHING
+        "#
+    )
+        .bind(hour)
+        .execute(db)
+        .await?
+        .rows_affected())
+}
+
+/// Claims up to `limit` notifications created since `since` that were not
+/// pushed yet and were tried fewer than `max_attempts` times, for users with
+/// push enabled. Claimed rows are marked pushed and the attempt is counted.
+pub async fn claim_unpushed(
+    db: &PgPool,
+    since: OffsetDateTime,
+    limit: i64,
+    max_attempts: i32
+) -> Result<Vec<NotificationEntity>, sqlx::Error> {
+    sqlx::query_as::<_, NotificationEntity>(
+        &format!(r#"
+        WITH claimed AS (
+            UPDATE notification SET pushed_at = NOW(), push_attempts = push_attempts + 1
+            WHERE id IN (
+                SELECT notification.id FROM notification
+                JOIN users ON users.id = notification.user_id AND users.push_enabled
+                WHERE notification.pushed_at IS NULL AND notification.created_at >= $1
+                    AND notification.read_at IS NULL AND notification.push_attempts < $3
+                ORDER BY notification.id
+                LIMIT $2
+                FOR UPDATE OF notification SKIP LOCKED
+            )
+            RETURNING *
+        )
+        SELECT {NOTIFICATION_COLUMNS}
+        FROM claimed AS notification
+        {NOTIFICATION_JOINS}
+        ORDER BY notification.id
+        "#)
+    )
+        .bind(since)
+        .bind(limit)
+        .bind(max_attempts)
+        .fetch_all(db)
+        .await
+}
+
+/// Makes claimed notifications pushable again, e.g. after a provider error.
+pub async fn release_unpushed(db: &PgPool, ids: &[i64]) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE notification SET pushed_at = NULL WHERE id = ANY($1)")
+        .bind(ids)
+        .execute(db)
+        .await?;
+    Ok(())
+}
+
+/// Like `claim_unpushed`, for users with Telegram messages enabled and a
+/// linked Telegram account.
+pub async fn claim_untelegrammed(
+    db: &PgPool,
+    since: OffsetDateTime,
+    limit: i64,
+    max_attempts: i32
+) -> Result<Vec<TelegramNotificationEntity>, sqlx::Error> {
+    sqlx::query_as::<_, TelegramNotificationEntity>(
+        &format!(r#"
+        WITH claimed AS (
+            UPDATE notification SET telegram_sent_at = NOW(), telegram_attempts = telegram_attempts + 1
+            WHERE id IN (
+                SELECT notification.id FROM notification
+                JOIN users ON users.id = notification.user_id
+                    AND users.telegram_enabled AND users.telegram_id IS NOT NULL
+                WHERE notification.telegram_sent_at IS NULL AND notification.created_at >= $1
+                    AND notification.read_at IS NULL AND notification.telegram_attempts < $3
+                ORDER BY notification.id
+                LIMIT $2
+                FOR UPDATE OF notification SKIP LOCKED
+            )
+            RETURNING *
+        )
+        SELECT {NOTIFICATION_COLUMNS}, users.telegram_id, users.telegram_language
+        FROM claimed AS notification
+        JOIN users ON users.id = notification.user_id
+        {NOTIFICATION_JOINS}
+        ORDER BY notification.id
+        "#)
+    )
+        .bind(since)
+        .bind(limit)
+        .bind(max_attempts)
+        .fetch_all(db)
+        .await
+}
+
+/// Makes claimed notifications sendable to Telegram again, e.g. after an API
+/// error.
+pub async fn release_untelegrammed(db: &PgPool, ids: &[i64]) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE notification SET telegram_sent_at = NULL WHERE id = ANY($1)")
+        .bind(ids)
+        .execute(db)
+        .await?;
+    Ok(())
+}
+
+/// Turns Telegram messages off for a user who blocked the bot.
+pub async fn disable_telegram(db: &PgPool, user_id: i64) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE users SET telegram_enabled = FALSE WHERE id = $1")
+        .bind(user_id)
+        .execute(db)
+        .await?;
+    Ok(())
+}
+
+pub async fn has_telegram(db: &PgPool, user_id: i64) -> Result<bool, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (bool,)>(
+        "SELECT EXISTS (SELECT 1 FROM users WHERE id = $1 AND telegram_id IS NOT NULL)"
+    )
+        .bind(user_id)
+        .fetch_one(db)
+        .await?.0)
+}
@@ -0,0 +1,33 @@
+use crate::db::lesson::entity::LessonWithAuthorTopic;
+use smart_default::SmartDefault;
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+#[derive(Debug, FromRow, SmartDefault)]
+pub struct PlaylistEntity {
+    pub id: i64,
+    pub user_id: i64,
+    pub title: String,
+    pub description: Option<String>,
+    pub share_token: Option<String>,
+    /// Items whose lesson is visible to users
+    pub item_count: i64,
+    pub total_duration: i64,
+    #[default(OffsetDateTime::now_utc())]
+    pub created_at: OffsetDateTime,
+    #[default(OffsetDateTime::now_utc())]
+    pub updated_at: OffsetDateTime
+}
+
+#[derive()]
+pub struct PlaylistInput {
+    pub title: String,
+    pub description: Option<String>
+}
+
+#[derive(FromRow)]
+pub struct PlaylistItemEntity {
+    #[sqlx(flatten)]
+    pub lesson: LessonWithAuthorTopic,
+    pub item_position: i32
+}
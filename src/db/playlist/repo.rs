@@ -0,0 +1,319 @@
+use crate::db::lesson::repo::{NEIGHBOUR_COLUMNS, REACHABLE};
+use crate::db::playlist::entity::{PlaylistEntity, PlaylistInput, PlaylistItemEntity};
+use crate::module::user::playlist::dto::{PlaylistCursor, PlaylistItemCursor};
+use sqlx::{PgConnection, PgPool, Postgres, QueryBuilder};
+
+fn select() -> String {
+    format!(r#"
+        SELECT playlist.*,
+            items.item_count,
+            COALESCE(items.total_duration, 0) AS total_duration
+        FROM playlist
+        CROSS JOIN LATERAL (
+            SELECT COUNT(*) AS item_count, SUM(lesson.duration)::BIGINT AS total_duration
+            FROM playlist_item
+            JOIN lesson ON lesson.id = playlist_item.lesson_id
+            LEFT JOIN topic ON topic.id = lesson.topic_id
+            WHERE playlist_item.playlist_id = playlist.id AND {REACHABLE}
+        ) AS items
+    "#)
+}
+
+pub async fn insert(
+    db: &PgPool,
+    user_id: i64,
+    playlist: PlaylistInput
+) -> Result<i64, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (i64,)>(
+        r#"
+        INSERT INTO playlist (user_id, title, description)
+        VALUES ($1, $2, $3)
+        RETURNING id
+        "#
+    )
+        .bind(user_id)
+        .bind(playlist.title)
+        .bind(playlist.description)
+        .fetch_one(db)
+        .await?.0)
+}
+
+pub async fn update(
+    db: &PgPool,
+    id: i64,
+    user_id: i64,
+    playlist: PlaylistInput
+) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query(
+        r#"
+        UPDATE playlist SET title = $3, description = $4
+        WHERE id = $1 AND user_id = $2
+        "#
+    )
+        .bind(id)
+        .bind(user_id)
+        .bind(playlist.title)
+        .bind(playlist.description)
+        .execute(db)
+        .await?
+        .rows_affected())
+}
+
+pub async fn delete(
+    db: &PgPool,
+    id: i64,
+    user_id: i64
+) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (i64,)>(
+        r#"
+        WITH deleted AS (
+            DELETE FROM playlist WHERE id = $1 AND user_id = $2
+            RETURNING id
+        ), items AS (
+            DELETE FROM playlist_item WHERE playlist_id IN (SELECT id FROM deleted)
+        )
+        SELECT COUNT(*) FROM deleted
+        "#
+    )
+        .bind(id)
+        .bind(user_id)
+        .fetch_one(db)
+        .await?.0 as u64)
+}
+
+pub async fn get_by_id(db: &PgPool, id: i64) -> Result<Option<PlaylistEntity>, sqlx::Error> {
+    sqlx::query_as::<_, PlaylistEntity>(&format!("{} WHERE playlist.id = $1", select()))
+        .bind(id)
+        .fetch_optional(db)
+        .await
+}
+
+pub async fn get_by_share_token(db: &PgPool, token: String) -> Result<Option<PlaylistEntity>, sqlx::Error> {
+    sqlx::query_as::<_, PlaylistEntity>(&format!("{} WHERE playlist.share_token = $1", select()))
+        .bind(token)
+        .fetch_optional(db)
+        .await
+}
+
+pub async fn page(
+    db: &PgPool,
+    user_id: i64,
+    limit: u32,
+    cursor: Option<PlaylistCursor>
+) -> Result<Vec<PlaylistEntity>, sqlx::Error> {
+    let mut query = QueryBuilder::<Postgres>::new(select());
+    query.push(" WHERE playlist.user_id = ").push_bind(user_id);
+
+    if let Some(cursor) = cursor {
+        query.push(" AND playlist.id < ").push_bind(cursor.id);
+    }
+
+    query
+        .push(" ORDER BY playlist.id DESC LIMIT ")
+        .push_bind(limit as i64)
+        .build_query_as::<PlaylistEntity>()
+        .fetch_all(db)
+        .await
+}
+
+/// Keeps the current token, so sharing twice returns the same link.
+pub async fn share(
+    db: &PgPool,
+    id: i64,
+    user_id: i64,
+    token: String
+) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query(
+        r#"
+        UPDATE playlist SET share_token = COALESCE(share_token, $3)
+        WHERE id = $1 AND user_id = $2
+        "#
+    )
+        .bind(id)
+        .bind(user_id)
+        .bind(token)
+        .execute(db)
+        .await?
+        .rows_affected())
+}
+
+pub async fn unshare(
+    db: &PgPool,
+    id: i64,
+    user_id: i64
+) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query(
+        r#"
+        UPDATE playlist SET share_token = NULL
+        WHERE id = $1 AND user_id = $2
+        "#
+    )
+        .bind(id)
+        .bind(user_id)
+        .execute(db)
+        .await?
+        .rows_affected())
+}
+
+/// Appends `lesson_id` to the end of the playlist; a lesson already in it
+/// keeps its place.
+pub async fn add_item(
+    db: &PgPool,
+    playlist_id: i64,
+    lesson_id: i64
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO playlist_item (playlist_id, lesson_id, position)
+        SELECT $1, $2, COALESCE(MAX(position) + 1, 0)
+        FROM playlist_item WHERE playlist_id = $1
+        ON CONFLICT (playlist_id, lesson_id) DO NOTHING
+        "#
+    )
+        .bind(playlist_id)
+        .bind(lesson_id)
+        .execute(db)
+        .await?;
+    Ok(())
+}
+
+pub async fn remove_item(
+    db: &PgPool,
+    playlist_id: i64,
+    lesson_id: i64
+) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query("DELETE FROM playlist_item WHERE playlist_id = $1 AND lesson_id = $2")
+        .bind(playlist_id)
+        .bind(lesson_id)
+        .execute(db)
+        .await?
+        .rows_affected())
+}
+
+/// Lesson ids of the items users can see, locking every item of the playlist.
+pub async fn lock_item_lesson_ids(
+    connection: &mut PgConnection,
+    playlist_id: i64
+) -> Result<Vec<i64>, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (i64, bool)>(
+        &format!(r#"
+        SELECT playlist_item.lesson_id, {REACHABLE}
+        FROM playlist_item
+        JOIN lesson ON lesson.id = playlist_item.lesson_id
+        LEFT JOIN topic ON topic.id = lesson.topic_id
+        WHERE playlist_item.playlist_id = $1
+        FOR UPDATE OF playlist_item
+        "#)
+    )
+        .bind(playlist_id)
+        .fetch_all(connection)
+        .await?
+        .into_iter()
+        .filter(|row| row.1)
+        .map(|row| row.0)
+        .collect())
+}
+
+/// Puts the items in `lesson_ids` order. Items left out, whose lessons are
+/// hidden from users, keep their relative order after them.
+pub async fn update_positions(
+    connection: &mut PgConnection,
+    playlist_id: i64,
+    lesson_ids: &[i64]
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE playlist_item
+        SET position = ordered.position
+        FROM (
+            SELECT playlist_item.id,
+                ROW_NUMBER() OVER (
+                    ORDER BY requested.ordinality NULLS LAST, playlist_item.position, playlist_item.lesson_id
+                ) - 1 AS position
+            FROM playlist_item
+            LEFT JOIN UNNEST($2::BIGINT[]) WITH ORDINALITY AS requested(lesson_id, ordinality)
+                ON requested.lesson_id = playlist_item.lesson_id
+            WHERE playlist_item.playlist_id = $1
+        ) AS ordered
+        WHERE playlist_item.id = ordered.id
+        "#
+    )
+        .bind(playlist_id)
+        .bind(lesson_ids)
+        .execute(connection)
+        .await?;
+    Ok(())
+}
+
+/// Items in playlist order. With `user_id` the progress and favourite state of
+/// that user are joined in.
+pub async fn items(
+    db: &PgPool,
+    playlist_id: i64,
+    user_id: Option<i64>,
+    limit: u32,
+    cursor: Option<PlaylistItemCursor>
+) -> Result<Vec<PlaylistItemEntity>, sqlx::Error> {
+    let mut query = QueryBuilder::<Postgres>::new("SELECT");
+    query.push(r#"
+        lesson.*,
+        playlist_item.position AS item_position,
+
+        author.name AS author_name,
+        author.avatar_path AS author_avatar_path,
+        author.created_at AS author_created_at,
+        author.lesson_count AS author_lesson_count,
+
+        topic.title AS topic_title,
+        topic.description AS topic_description,
+        topic.cover_image_path AS topic_cover_image_path,
+        topic.created_at AS topic_created_at,
+        topic.lesson_count AS topic_lesson_count,
+        topic.total_duration AS topic_total_duration,
+        topic.snip_count AS topic_snip_count,
+        topic.rating_count AS topic_rating_count,
+        topic.rating_average AS topic_rating_average
+    "#);
+
+    if user_id.is_some() {
+        query.push(r#"
+        , lesson_progress.user_id,
+        lesson_progress.started_at,
+        lesson_progress.last_position_ms,
+        lesson_progress.status,
+        lesson_progress.completed_at,
+        (favourite_lesson.user_id IS NOT NULL) AS is_favourite
+        "#);
+    }
+    query.push(NEIGHBOUR_COLUMNS);
+
+    query.push(r#"
+        FROM playlist_item
+        JOIN lesson ON lesson.id = playlist_item.lesson_id
+        JOIN author ON lesson.author_id = author.id
+        LEFT JOIN topic ON lesson.topic_id = topic.id
+    "#);
+
+    if let Some(user_id) = user_id {
+        query.push(" LEFT JOIN lesson_progress ON lesson_progress.lesson_id = lesson.id AND lesson_progress.user_id = ")
+            .push_bind(user_id)
+            .push(" LEFT JOIN favourite_lesson ON favourite_lesson.lesson_id = lesson.id AND favourite_lesson.user_id = ")
+            .push_bind(user_id);
+    }
+
+    query.push(" WHERE playlist_item.playlist_id = ").push_bind(playlist_id)
+        .push(" AND ").push(REACHABLE);
+
+    if let Some(cursor) = cursor {
+        query.push(" AND (playlist_item.position, playlist_item.lesson_id) > (")
+            .push_bind(cursor.position).push(", ")
+            .push_bind(cursor.lesson_id).push(")");
+    }
+
+    query
+        .push(" ORDER BY playlist_item.position, playlist_item.lesson_id LIMIT ")
+        .push_bind(limit as i64)
+        .build_query_as::<PlaylistItemEntity>()
+        .fetch_all(db)
+        .await
+}
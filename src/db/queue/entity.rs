@@ -0,0 +1,12 @@
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+#[derive(Debug, FromRow)]
+pub struct QueueEntity {
+    /// Bumped by every change to the queue
+    pub version: i64,
+    pub current_lesson_id: Option<i64>,
+    /// `lesson_progress.last_position_ms` of the current lesson
+    pub current_position_ms: Option<i64>,
+    pub updated_at: OffsetDateTime
+}
@@ -0,0 +1,255 @@
+use crate::db::lesson::entity::LessonWithAuthorTopic;
+use crate::db::lesson::repo::{NEIGHBOUR_COLUMNS, REACHABLE};
+use crate::db::queue::entity::QueueEntity;
+use sqlx::{PgConnection, PgPool, Postgres, QueryBuilder};
+
+const SELECT: &str = r#"
+    SELECT play_queue.version,
+        play_queue.current_lesson_id,
+        lesson_progress.last_position_ms AS current_position_ms,
+        play_queue.updated_at
+    FROM play_queue
+    LEFT JOIN lesson_progress ON lesson_progress.lesson_id = play_queue.current_lesson_id
+        AND lesson_progress.user_id = play_queue.user_id
+    WHERE play_queue.user_id = $1
+"#;
+
+pub async fn get(db: &PgPool, user_id: i64) -> Result<Option<QueueEntity>, sqlx::Error> {
+    sqlx::query_as::<_, QueueEntity>(SELECT)
+        .bind(user_id)
+        .fetch_optional(db)
+        .await
+}
+
+/// Creates the queue on first use and locks it for the rest of the transaction.
+pub async fn lock(connection: &mut PgConnection, user_id: i64) -> Result<QueueEntity, sqlx::Error> {
+    sqlx::query("INSERT INTO play_queue (user_id) VALUES ($1) ON CONFLICT (user_id) DO NOTHING")
+        .bind(user_id)
+        .execute(&mut *connection)
+        .await?;
+
+    sqlx::query_as::<_, QueueEntity>(&format!("{SELECT} FOR UPDATE OF play_queue"))
+        .bind(user_id)
+        .fetch_one(connection)
+        .await
+}
+
+/// Records a change, moving the version on.
+pub async fn bump(
+    connection: &mut PgConnection,
+    user_id: i64,
+    current_lesson_id: Option<i64>
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE play_queue SET version = version + 1, current_lesson_id = $2
+        WHERE user_id = $1
+        "#
+    )
+        .bind(user_id)
+        .bind(current_lesson_id)
+        .execute(connection)
+        .await?;
+    Ok(())
+}
+
+pub async fn get_position(
+    connection: &mut PgConnection,
+    user_id: i64,
+    lesson_id: i64
+) -> Result<Option<i32>, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (i32,)>(
+        "SELECT position FROM play_queue_item WHERE user_id = $1 AND lesson_id = $2"
+    )
+        .bind(user_id)
+        .bind(lesson_id)
+        .fetch_optional(connection)
+        .await?
+        .map(|row| row.0))
+}
+
+/// The lesson queued right after `position`.
+pub async fn next_lesson_id(
+    connection: &mut PgConnection,
+    user_id: i64,
+    position: i32
+) -> Result<Option<i64>, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (i64,)>(
+        r#"
+        SELECT lesson_id FROM play_queue_item
+        WHERE user_id = $1 AND position > $2
+        ORDER BY position
+        LIMIT 1
+        "#
+    )
+        .bind(user_id)
+        .bind(position)
+        .fetch_optional(connection)
+        .await?
+        .map(|row| row.0))
+}
+
+pub async fn append(
+    connection: &mut PgConnection,
+    user_id: i64,
+    lesson_id: i64
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO play_queue_item (user_id, lesson_id, position)
+        SELECT $1, $2, COALESCE(MAX(position) + 1, 0)
+        FROM play_queue_item WHERE user_id = $1
+        "#
+    )
+        .bind(user_id)
+        .bind(lesson_id)
+        .execute(connection)
+        .await?;
+    Ok(())
+}
+
+/// Inserts `lesson_id` at `position`, shifting the items from there on.
+pub async fn insert_at(
+    connection: &mut PgConnection,
+    user_id: i64,
+    lesson_id: i64,
+    position: i32
+) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE play_queue_item SET position = position + 1 WHERE user_id = $1 AND position >= $2")
+        .bind(user_id)
+        .bind(position)
+        .execute(&mut *connection)
+        .await?;
+
+    sqlx::query("INSERT INTO play_queue_item (user_id, lesson_id, position) VALUES ($1, $2, $3)")
+        .bind(user_id)
+        .bind(lesson_id)
+        .bind(position)
+        .execute(connection)
+        .await?;
+    Ok(())
+}
+
+pub async fn remove(
+    connection: &mut PgConnection,
+    user_id: i64,
+    lesson_id: i64
+) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query("DELETE FROM play_queue_item WHERE user_id = $1 AND lesson_id = $2")
+        .bind(user_id)
+        .bind(lesson_id)
+        .execute(connection)
+        .await?
+        .rows_affected())
+}
+
+pub async fn clear(connection: &mut PgConnection, user_id: i64) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM play_queue_item WHERE user_id = $1")
+        .bind(user_id)
+        .execute(connection)
+        .await?;
+    Ok(())
+}
+
+/// Lesson ids of the items users can see.
+pub async fn item_lesson_ids(
+    connection: &mut PgConnection,
+    user_id: i64
+) -> Result<Vec<i64>, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (i64,)>(
+        &format!(r#"
+        SELECT play_queue_item.lesson_id
+        FROM play_queue_item
+        JOIN lesson ON lesson.id = play_queue_item.lesson_id
+        LEFT JOIN topic ON topic.id = lesson.topic_id
+        WHERE play_queue_item.user_id = $1 AND {REACHABLE}
+        "#)
+    )
+        .bind(user_id)
+        .fetch_all(connection)
+        .await?
+        .into_iter()
+        .map(|row| row.0)
+        .collect())
+}
+
+/// Puts the items in `lesson_ids` order. Items left out, whose lessons are
+/// hidden from users, keep their relative order after them.
+pub async fn update_positions(
+    connection: &mut PgConnection,
+    user_id: i64,
+    lesson_ids: &[i64]
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE play_queue_item
+        SET position = ordered.position
+        FROM (
+            SELECT play_queue_item.id,
+                ROW_NUMBER() OVER (
+                    ORDER BY requested.ordinality NULLS LAST, play_queue_item.position
+                ) - 1 AS position
+            FROM play_queue_item
+            LEFT JOIN UNNEST($2::BIGINT[]) WITH ORDINALITY AS requested(lesson_id, ordinality)
+                ON requested.lesson_id = play_queue_item.lesson_id
+            WHERE play_queue_item.user_id = $1
+        ) AS ordered
+        WHERE play_queue_item.id = ordered.id
+        "#
+    )
+        .bind(user_id)
+        .bind(lesson_ids)
+        .execute(connection)
+        .await?;
+    Ok(())
+}
+
+/// Queued lessons in order with the user's progress and favourite state.
+pub async fn items(db: &PgPool, user_id: i64) -> Result<Vec<LessonWithAuthorTopic>, sqlx::Error> {
+    let mut query = QueryBuilder::<Postgres>::new("SELECT");
+    query.push(r#"
+        lesson.*,
+
+        author.name AS author_name,
+        author.avatar_path AS author_avatar_path,
+        author.created_at AS author_created_at,
+        author.lesson_count AS author_lesson_count,
+
+        topic.title AS topic_title,
+        topic.description AS topic_description,
+        topic.cover_image_path AS topic_cover_image_path,
+        topic.created_at AS topic_created_at,
+        topic.lesson_count AS topic_lesson_count,
+        topic.total_duration AS topic_total_duration,
+        topic.snip_count AS topic_snip_count,
+        topic.rating_count AS topic_rating_count,
+        topic.rating_average AS topic_rating_average,
+
+        lesson_progress.user_id,
+        lesson_progress.started_at,
+        lesson_progress.last_position_ms,
+        lesson_progress.status,
+        lesson_progress.completed_at,
+        (favourite_lesson.user_id IS NOT NULL) AS is_favourite
+    "#);
+    query.push(NEIGHBOUR_COLUMNS);
+
+    query.push(r#"
+        FROM play_queue_item
+        JOIN lesson ON lesson.id = play_queue_item.lesson_id
+        JOIN author ON lesson.author_id = author.id
+        LEFT JOIN topic ON lesson.topic_id = topic.id
+        LEFT JOIN lesson_progress ON lesson_progress.lesson_id = lesson.id
+            AND lesson_progress.user_id = play_queue_item.user_id
+        LEFT JOIN favourite_lesson ON favourite_lesson.lesson_id = lesson.id
+            AND favourite_lesson.user_id = play_queue_item.user_id
+        WHERE play_queue_item.user_id = "#);
+
+    query
+        .push_bind(user_id)
+        .push(" AND ").push(REACHABLE)
+        .push(" ORDER BY play_queue_item.position")
+        .build_query_as::<LessonWithAuthorTopic>()
+        .fetch_all(db)
+        .await
+}
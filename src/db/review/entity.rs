@@ -0,0 +1,25 @@
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+#[derive(Debug, FromRow)]
+pub struct ReviewEntity {
+    pub id: i64,
+    pub user_id: i64,
+    pub lesson_id: i64,
+    pub rating: i16,
+    pub text: Option<String>,
+    pub hidden_at: Option<OffsetDateTime>,
+    /// Admin who hid it
+    pub hidden_by: Option<i64>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime
+}
+
+#[derive(Debug, FromRow)]
+pub struct ReviewWithUserEntity {
+    #[sqlx(flatten)]
+    pub review: ReviewEntity,
+    pub user_first_name: String,
+    pub user_avatar_path: Option<String>,
+    pub lesson_title: String
+}
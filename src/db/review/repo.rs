@@ -0,0 +1,225 @@
+use crate::db::review::entity::ReviewWithUserEntity;
+use crate::module::user::review::dto::ReviewCursor;
+use sqlx::{PgConnection, PgPool, Postgres, QueryBuilder};
+
+/// Columns of `ReviewWithUserEntity` over `lesson_review` joined with the
+/// reviewer and the lesson.
+const REVIEW_COLUMNS: &str = r#"
+        lesson_review.*,
+        users.first_name AS user_first_name,
+        users.avatar_path AS user_avatar_path,
+        lesson.title AS lesson_title
+"#;
+
+const REVIEW_JOINS: &str = r#"
+        JOIN users ON users.id = lesson_review.user_id
+        JOIN lesson ON lesson.id = lesson_review.lesson_id
+"#;
+
+/// Whether the user completed the lesson or listened to enough of it for the
+/// listen to count.
+pub async fn has_progress(db: &PgPool, user_id: i64, lesson_id: i64) -> Result<bool, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (bool,)>(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM lesson_progress
+            WHERE user_id = $1 AND lesson_id = $2 AND status = 'completed'
+        ) OR EXISTS (
+            SELECT 1 FROM listen_session
+            WHERE user_id = $1 AND lesson_id = $2 AND counted_at IS NOT NULL
+        )
+        "#
+    )
+        .bind(user_id)
+        .bind(lesson_id)
+        .fetch_one(db)
+        .await?.0)
+}
+
+/// Creates or replaces the user's review of the lesson. A hidden review stays
+/// hidden.
+pub async fn upsert(
+    connection: &mut PgConnection,
+    user_id: i64,
+    lesson_id: i64,
+    rating: i16,
+    text: Option<String>
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO lesson_review (user_id, lesson_id, rating, text)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (user_id, lesson_id) DO UPDATE
+        SET rating = EXCLUDED.rating,
+            text = EXCLUDED.text
+        "#
+    )
+        .bind(user_id)
+        .bind(lesson_id)
+        .bind(rating)
+        .bind(text)
+        .execute(connection)
+        .await?;
+    Ok(())
+}
+
+/// Deletes the user's review unless it is hidden, so a hidden review cannot be
+/// replaced by a fresh one.
+pub async fn delete(
+    connection: &mut PgConnection,
+    user_id: i64,
+    lesson_id: i64
+) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query("DELETE FROM lesson_review WHERE user_id = $1 AND lesson_id = $2 AND hidden_at IS NULL")
+        .bind(user_id)
+        .bind(lesson_id)
+        .execute(connection)
+        .await?
+        .rows_affected())
+}
+
+pub async fn get_by_user(
+    db: &PgPool,
+    user_id: i64,
+    lesson_id: i64
+) -> Result<Option<ReviewWithUserEntity>, sqlx::Error> {
+    sqlx::query_as::<_, ReviewWithUserEntity>(
+        &format!(r#"
+        SELECT {REVIEW_COLUMNS}
+        FROM lesson_review
+        {REVIEW_JOINS}
+        WHERE lesson_review.user_id = $1 AND lesson_review.lesson_id = $2
+        "#)
+    )
+        .bind(user_id)
+        .bind(lesson_id)
+        .fetch_optional(db)
+        .await
+}
+
+/// Visible reviews of the lesson, latest first.
+pub async fn page(
+    db: &PgPool,
+    lesson_id: i64,
+    limit: u32,
+    cursor: Option<ReviewCursor>
+) -> Result<Vec<ReviewWithUserEntity>, sqlx::Error> {
+    let mut query = QueryBuilder::<Postgres>::new("SELECT");
+    query.push(REVIEW_COLUMNS)
+        .push(" FROM lesson_review")
+        .push(REVIEW_JOINS)
+        .push(" WHERE lesson_review.hidden_at IS NULL AND lesson_review.lesson_id = ")
+        .push_bind(lesson_id);
+
+    if let Some(cursor) = cursor {
+        query.push(" AND (lesson_review.created_at, lesson_review.id) < (")
+            .push_bind(cursor.created_at).push(", ")
+            .push_bind(cursor.id).push(")");
+    }
+
+    query
+        .push(" ORDER BY lesson_review.created_at DESC, lesson_review.id DESC LIMIT ")
+        .push_bind(limit as i64)
+        .build_query_as::<ReviewWithUserEntity>()
+        .fetch_all(db)
+        .await
+}
+
+fn build_query(query: &mut QueryBuilder<Postgres>, lesson_id: Option<i64>, hidden: Option<bool>) {
+    let mut has_where = false;
+    if let Some(lesson_id) = lesson_id {
+        query.push(" WHERE lesson_review.lesson_id = ").push_bind(lesson_id);
+        has_where = true;
+    }
+    if let Some(hidden) = hidden {
+        query
+            .push(if has_where { " AND " } else { " WHERE " })
+            .push(if hidden { "lesson_review.hidden_at IS NOT NULL" } else { "lesson_review.hidden_at IS NULL" });
+    }
+}
+
+/// All reviews for moderation, latest first.
+pub async fn page_all(
+    db: &PgPool,
+    limit: u32,
+    offset: u32,
+    lesson_id: Option<i64>,
+    hidden: Option<bool>
+) -> Result<Vec<ReviewWithUserEntity>, sqlx::Error> {
+    let mut query = QueryBuilder::<Postgres>::new("SELECT");
+    query.push(REVIEW_COLUMNS)
+        .push(" FROM lesson_review")
+        .push(REVIEW_JOINS);
+    build_query(&mut query, lesson_id, hidden);
+
+    query
+        .push(" ORDER BY lesson_review.created_at DESC, lesson_review.id DESC LIMIT ")
+        .push_bind(limit as i64)
+        .push(" OFFSET ")
+        .push_bind(offset as i64)
+        .build_query_as::<ReviewWithUserEntity>()
+        .fetch_all(db)
+        .await
+}
+
+pub async fn count_all(
+    db: &PgPool,
+    lesson_id: Option<i64>,
+    hidden: Option<bool>
+) -> Result<i64, sqlx::Error> {
+    let mut query = QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM lesson_review");
+    build_query(&mut query, lesson_id, hidden);
+    Ok(query.build_query_as::<(i64,)>().fetch_one(db).await?.0)
+}
+
+/// Hides the review, keeping who hid it first and when. Returns its lesson.
+pub async fn hide(
+    connection: &mut PgConnection,
+    id: i64,
+    admin_id: i64
+) -> Result<Option<i64>, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (i64,)>(
+        r#"
+        UPDATE lesson_review
+        SET hidden_at = COALESCE(hidden_at, NOW()),
+            hidden_by = COALESCE(hidden_by, $2)
+        WHERE id = $1
+        RETURNING lesson_id
+        "#
+    )
+        .bind(id)
+        .bind(admin_id)
+        .fetch_optional(connection)
+        .await?
+        .map(|row| row.0))
+}
+
+/// Returns the review's lesson.
+pub async fn unhide(connection: &mut PgConnection, id: i64) -> Result<Option<i64>, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (i64,)>(
+        r#"
+        UPDATE lesson_review
+        SET hidden_at = NULL, hidden_by = NULL
+        WHERE id = $1
+        RETURNING lesson_id
+        "#
+    )
+        .bind(id)
+        .fetch_optional(connection)
+        .await?
+        .map(|row| row.0))
+}
+
+pub async fn get(db: &PgPool, id: i64) -> Result<Option<ReviewWithUserEntity>, sqlx::Error> {
+    sqlx::query_as::<_, ReviewWithUserEntity>(
+        &format!(r#"
+        SELECT {REVIEW_COLUMNS}
+        FROM lesson_review
+        {REVIEW_JOINS}
+        WHERE lesson_review.id = $1
+        "#)
+    )
+        .bind(id)
+        .fetch_optional(db)
+        .await
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+/// One field that differs from the previous revision, rendered as text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevisionChange {
+    pub field: String,
+    pub from: Option<String>,
+    pub to: Option<String>
+}
+
+#[derive(Debug, FromRow)]
+pub struct RevisionEntity {
+    pub lesson_id: i64,
+    pub revision: i32,
+    pub admin_id: Option<i64>,
+    pub title: String,
+    pub description: Option<String>,
+    pub cover_image_path: Option<String>,
+    pub audio_path: String,
+    pub duration: i64,
+    pub file_size: i64,
+    pub changes: Json<Vec<RevisionChange>>,
+    pub restored_from: Option<i32>,
+    pub created_at: OffsetDateTime
+}
+
+#[derive()]
+pub struct RevisionInput {
+    pub lesson_id: i64,
+    pub admin_id: i64,
+    pub title: String,
+    pub description: Option<String>,
+    pub cover_image_path: Option<String>,
+    pub audio_path: String,
+    pub duration: i64,
+    pub file_size: i64,
+    pub changes: Vec<RevisionChange>,
+    pub restored_from: Option<i32>
+}
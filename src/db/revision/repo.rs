@@ -0,0 +1,69 @@
+use crate::db::revision::entity::{RevisionEntity, RevisionInput};
+use sqlx::types::Json;
+use sqlx::{PgConnection, PgPool};
+
+/// Appends the next revision of the lesson. Runs after the lesson row is
+/// updated in the same transaction, so its lock serializes the numbering.
+pub async fn insert(
+    connection: &mut PgConnection,
+    revision: RevisionInput,
+) -> Result<i32, sqlx::Error> {
+    Ok(
+        sqlx::query_as::<_, (i32,)>(
+            r#"
+            INSERT INTO lesson_revision (lesson_id, revision, admin_id, title, description, cover_image_path,
+                                         audio_path, duration, file_size, changes, restored_from)
+            SELECT $1, COALESCE(MAX(revision) + 1, 1), $2, $3, $4, $5, $6, $7, $8, $9, $10
+            FROM lesson_revision
+            WHERE lesson_id = $1
+            RETURNING revision
+            "#,
+        )
+            .bind(revision.lesson_id)
+            .bind(revision.admin_id)
+            .bind(revision.title)
+            .bind(revision.description)
+            .bind(revision.cover_image_path)
+            .bind(revision.audio_path)
+            .bind(revision.duration)
+            .bind(revision.file_size)
+            .bind(Json(revision.changes))
+            .bind(revision.restored_from)
+            .fetch_one(connection)
+            .await?.0
+    )
+}
+
+/// Latest first.
+pub async fn get_by_lesson_id(
+    db: &PgPool,
+    lesson_id: i64,
+) -> Result<Vec<RevisionEntity>, sqlx::Error> {
+    sqlx::query_as::<_, RevisionEntity>(
+        r#"
+        SELECT * FROM lesson_revision
+        WHERE lesson_id = $1
+        ORDER BY revision DESC
+        "#,
+    )
+        .bind(lesson_id)
+        .fetch_all(db)
+        .await
+}
+
+pub async fn get(
+    db: &PgPool,
+    lesson_id: i64,
+    revision: i32,
+) -> Result<Option<RevisionEntity>, sqlx::Error> {
+    sqlx::query_as::<_, RevisionEntity>(
+        r#"
+        SELECT * FROM lesson_revision
+        WHERE lesson_id = $1 AND revision = $2
+        "#,
+    )
+        .bind(lesson_id)
+        .bind(revision)
+        .fetch_optional(db)
+        .await
+}
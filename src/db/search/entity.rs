@@ -0,0 +1,15 @@
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow)]
+pub struct SearchResultEntity {
+    /// `author`, `topic` or `lesson`
+    pub kind: String,
+    pub id: i64,
+    pub title: String,
+    pub title_highlight: String,
+    pub snippet: Option<String>,
+    pub image_path: Option<String>,
+    pub author_id: i64,
+    pub author_name: String,
+    pub rank: f32
+}
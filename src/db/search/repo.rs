@@ -0,0 +1,110 @@
+use crate::db::lesson::repo::LISTED;
+use crate::db::search::entity::SearchResultEntity;
+use crate::module::user::search::dto::SearchCursor;
+use crate::utils::search::{HIGHLIGHT_OPTIONS, SNIPPET_OPTIONS};
+use sqlx::{PgPool, Postgres, QueryBuilder};
+
+/// Ranks authors, topics and lessons matching `tsquery` in any locale
+/// together, best first. Results are shown in `locale` when translated.
+/// Highlighting runs on the stored text, so it only marks matches written in
+/// the same script as the query.
+pub async fn search(
+    db: &PgPool,
+    tsquery: String,
+    locale: String,
+    limit: u32,
+    cursor: Option<SearchCursor>,
+) -> Result<Vec<SearchResultEntity>, sqlx::Error> {
+    let mut query = QueryBuilder::<Postgres>::new("WITH q AS (SELECT to_tsquery('simple', search_normalize(");
+    query.push_bind(tsquery).push(")) AS query, ").push_bind(locale).push("::TEXT AS locale)");
+
+    query.push(format!(r#"
+        SELECT * FROM (
+            SELECT 'author' AS kind,
+                author.id,
+                COALESCE(author_tr.name, author.name) AS title,
+                ts_headline('simple', COALESCE(author_tr.name, author.name), q.query, '{HIGHLIGHT_OPTIONS}') AS title_highlight,
+                NULL::TEXT AS snippet,
+                author.avatar_path AS image_path,
+                author.id AS author_id,
+                COALESCE(author_tr.name, author.name) AS author_name,
+                GREATEST(
+                    ts_rank(author.search_vector, q.query),
+                    (SELECT MAX(ts_rank(tr.search_vector, q.query)) FROM author_translation tr
+                        WHERE tr.author_id = author.id AND tr.search_vector @@ q.query)
+                ) AS rank
+            FROM author
+            CROSS JOIN q
+            LEFT JOIN author_translation author_tr ON author_tr.author_id = author.id AND author_tr.locale = q.locale
+            WHERE author.deleted_at IS NULL
+                AND (author.search_vector @@ q.query OR EXISTS (
+                    SELECT 1 FROM author_translation tr WHERE tr.author_id = author.id AND tr.search_vector @@ q.query
+                ))
+
+            UNION ALL
+
+            SELECT 'topic',
+                topic.id,
+                COALESCE(topic_tr.title, topic.title),
+                ts_headline('simple', COALESCE(topic_tr.title, topic.title), q.query, '{HIGHLIGHT_OPTIONS}'),
+                ts_headline('simple', COALESCE(topic_tr.description, topic.description), q.query, '{SNIPPET_OPTIONS}'),
+                topic.cover_image_path,
+                author.id,
+                COALESCE(author_tr.name, author.name),
+                GREATEST(
+                    ts_rank(topic.search_vector, q.query),
+                    (SELECT MAX(ts_rank(tr.search_vector, q.query)) FROM topic_translation tr
+                        WHERE tr.topic_id = topic.id AND tr.search_vector @@ q.query)
+                )
+            FROM topic
+            JOIN author ON author.id = topic.author_id AND author.deleted_at IS NULL
+            CROSS JOIN q
+            LEFT JOIN topic_translation topic_tr ON topic_tr.topic_id = topic.id AND topic_tr.locale = q.locale
+            LEFT JOIN author_translation author_tr ON author_tr.author_id = author.id AND author_tr.locale = q.locale
+            WHERE topic.deleted_at IS NULL AND topic.publication_status = 'published'
+                AND (topic.search_vector @@ q.query OR EXISTS (
+                    SELECT 1 FROM topic_translation tr WHERE tr.topic_id = topic.id AND tr.search_vector @@ q.query
+                ))
+
+            UNION ALL
+
+            SELECT 'lesson',
+                lesson.id,
+                COALESCE(lesson_tr.title, lesson.title),
+                ts_headline('simple', COALESCE(lesson_tr.title, lesson.title), q.query, '{HIGHLIGHT_OPTIONS}'),
+                ts_headline('simple', COALESCE(lesson_tr.description, lesson.description), q.query, '{SNIPPET_OPTIONS}'),
+                lesson.cover_image_path,
+                author.id,
+                COALESCE(author_tr.name, author.name),
+                GREATEST(
+                    ts_rank(lesson.search_vector, q.query),
+                    (SELECT MAX(ts_rank(tr.search_vector, q.query)) FROM lesson_translation tr
+                        WHERE tr.lesson_id = lesson.id AND tr.search_vector @@ q.query)
+                )
+            FROM lesson
+            JOIN author ON author.id = lesson.author_id AND author.deleted_at IS NULL
+            LEFT JOIN topic ON topic.id = lesson.topic_id
+            CROSS JOIN q
+            LEFT JOIN lesson_translation lesson_tr ON lesson_tr.lesson_id = lesson.id AND lesson_tr.locale = q.locale
+            LEFT JOIN author_translation author_tr ON author_tr.author_id = author.id AND author_tr.locale = q.locale
+            WHERE {LISTED}
+                AND (lesson.search_vector @@ q.query OR EXISTS (
+                    SELECT 1 FROM lesson_translation tr WHERE tr.lesson_id = lesson.id AND tr.search_vector @@ q.query
+                ))
+        ) AS result
+    "#));
+
+    if let Some(cursor) = cursor {
+        query.push(" WHERE (rank, kind, id) < (")
+            .push_bind(cursor.rank).push("::REAL, ")
+            .push_bind(cursor.kind).push(", ")
+            .push_bind(cursor.id).push(")");
+    }
+
+    query.push(" ORDER BY rank DESC, kind DESC, id DESC");
+
+    query
+        .push(" LIMIT ").push_bind(limit as i64)
+        .build_query_as::<SearchResultEntity>()
+        .fetch_all(db).await
+}
@@ -1,3 +1,4 @@
+use crate::db::chapter::entity::ChapterEntity;
 use smart_default::SmartDefault;
 use sqlx::FromRow;
 use time::OffsetDateTime;
@@ -43,6 +44,9 @@ pub struct SnipEntityWithLesson {
     pub lesson_file_size: i64,
     pub lesson_listen_count: i64,
     pub lesson_snip_count: i64,
+    pub lesson_rating_count: i64,
+    pub lesson_rating_average: f64,
+    pub lesson_position: i32,
     #[default(OffsetDateTime::now_utc())]
     pub lesson_created_at: OffsetDateTime,
     //author
@@ -59,4 +63,18 @@ pub struct SnipEntityWithLesson {
     pub topic_lesson_count: Option<i64>,
     pub topic_total_duration: Option<i64>,
     pub topic_snip_count: Option<i64>,
+    pub topic_rating_count: Option<i64>,
+    pub topic_rating_average: Option<f64>,
+    //transcript segments overlapping the snip range
+    pub transcript_text: Option<String>,
+    //chapters of the lesson, loaded separately
+    #[sqlx(skip)]
+    pub chapters: Vec<ChapterEntity>
+}
+
+#[derive(Debug, FromRow)]
+pub struct TrashedSnipEntity {
+    #[sqlx(flatten)]
+    pub snip: SnipEntityWithLesson,
+    pub deleted_at: OffsetDateTime
 }
\ No newline at end of file
@@ -1,6 +1,9 @@
-use crate::db::snip::entity::{SnipEntity, SnipEntityWithLesson, SnipInput};
+use crate::db::snip::entity::{SnipEntity, SnipEntityWithLesson, SnipInput, TrashedSnipEntity};
+use crate::db::translation;
+use crate::module::common::enums::TranslationKind;
 use crate::module::common::paging::QueryOrder;
-use crate::module::user::snip::dto::{QuerySort, SnipCursor};
+use crate::module::user::snip::dto::{QuerySort, SnipCursor, TrashedSnipCursor};
+use crate::utils;
 use sqlx::{PgConnection, PgPool, Postgres, QueryBuilder};
 use time::OffsetDateTime;
 
@@ -72,6 +75,24 @@ pub async fn delete(
     Ok(result)
 }
 
+/// Only snips whose lesson is live can be restored.
+pub async fn restore(
+    connection: &mut PgConnection,
+    client_snip_id: String
+) -> Result<Option<SnipEntity>, sqlx::Error> {
+    sqlx::query_as::<_, SnipEntity>(
+        r#"
+               UPDATE snip SET deleted_at = NULL
+               WHERE client_snip_id = $1 AND deleted_at IS NOT NULL
+                 AND EXISTS (SELECT 1 FROM lesson WHERE lesson.id = snip.lesson_id AND lesson.deleted_at IS NULL)
+               RETURNING *
+            "#
+    )
+        .bind(client_snip_id)
+        .fetch_optional(connection)
+        .await
+}
+
 pub async fn deleted(
     db: &PgPool,
     since: OffsetDateTime
@@ -102,9 +123,14 @@ fn build_query(
         query.push(" AND snip.lesson_id = ").push_bind(lesson_id);
     }
 
-    if let Some(search) = search {
-        query.push(" AND (snip.note_text ILIKE ").push_bind(format!("{}%", search))
-            .push(" OR lesson.title ILIKE ").push_bind(format!("{}%", search));
+    if let Some(search) = search && !search.trim().is_empty() {
+        query.push(" AND (strpos(search_normalize(snip.note_text), search_normalize(")
+            .push_bind(search.trim().to_string()).push(")) > 0");
+        if let Some(tsquery) = utils::search::to_tsquery(search) {
+            query.push(" OR ");
+            translation::repo::push_search(query, TranslationKind::Lesson, tsquery);
+        }
+        query.push(")");
     }
 }
 
@@ -135,6 +161,9 @@ pub async fn page(
         lesson.file_size AS lesson_file_size,
         lesson.listen_count AS lesson_listen_count,
         lesson.snip_count AS lesson_snip_count,
+        lesson.rating_count AS lesson_rating_count,
+        lesson.rating_average AS lesson_rating_average,
+        lesson.position AS lesson_position,
         lesson.created_at AS lesson_created_at,
 
         author.name AS author_name,
@@ -148,7 +177,15 @@ pub async fn page(
         topic.created_at AS topic_created_at,
         topic.lesson_count AS topic_lesson_count,
         topic.total_duration AS topic_total_duration,
-        topic.snip_count AS topic_snip_count
+        topic.snip_count AS topic_snip_count,
+        topic.rating_count AS topic_rating_count,
+        topic.rating_average AS topic_rating_average,
+
+        (SELECT string_agg(segment.text, ' ' ORDER BY segment.position)
+         FROM lesson_transcript_segment segment
+         WHERE segment.lesson_id = snip.lesson_id
+           AND segment.end_ms > snip.start_ms
+           AND segment.start_ms < snip.end_ms) AS transcript_text
     "#);
 
     query.push(" FROM snip");
@@ -201,10 +238,7 @@ pub async fn count(
 }
 
 
-pub async fn get_by_id(
-    db: &PgPool,
-    id: i64
-) -> Result<Option<SnipEntityWithLesson>, sqlx::Error> {
+fn select_with_lesson<'a>() -> QueryBuilder<'a, Postgres> {
     let mut query = QueryBuilder::<Postgres>::new("SELECT");
 
     query.push(r#"
@@ -218,6 +252,9 @@ pub async fn get_by_id(
         lesson.file_size AS lesson_file_size,
         lesson.listen_count AS lesson_listen_count,
         lesson.snip_count AS lesson_snip_count,
+        lesson.rating_count AS lesson_rating_count,
+        lesson.rating_average AS lesson_rating_average,
+        lesson.position AS lesson_position,
         lesson.created_at AS lesson_created_at,
 
         author.name AS author_name,
@@ -231,14 +268,64 @@ pub async fn get_by_id(
         topic.created_at AS topic_created_at,
         topic.lesson_count AS topic_lesson_count,
         topic.total_duration AS topic_total_duration,
-        topic.snip_count AS topic_snip_count
+        topic.snip_count AS topic_snip_count,
+        topic.rating_count AS topic_rating_count,
+        topic.rating_average AS topic_rating_average,
+
+        (SELECT string_agg(segment.text, ' ' ORDER BY segment.position)
+         FROM lesson_transcript_segment segment
+         WHERE segment.lesson_id = snip.lesson_id
+           AND segment.end_ms > snip.start_ms
+           AND segment.start_ms < snip.end_ms) AS transcript_text
     "#).push(" FROM snip")
         .push(" JOIN lesson ON lesson.id = snip.lesson_id AND lesson.deleted_at IS NULL")
         .push(" JOIN author ON author.id = snip.author_id")
-        .push(" LEFT JOIN topic ON topic.id = snip.topic_id")
-        .push(" WHERE snip.id = ").push_bind(id);
+        .push(" LEFT JOIN topic ON topic.id = snip.topic_id");
+
+    query
+}
+
+pub async fn get_by_id(
+    db: &PgPool,
+    id: i64
+) -> Result<Option<SnipEntityWithLesson>, sqlx::Error> {
+    let mut query = select_with_lesson();
+    query.push(" WHERE snip.id = ").push_bind(id);
+
+    query
+        .build_query_as::<SnipEntityWithLesson>()
+        .fetch_optional(db).await
+}
+
+pub async fn get_by_client_snip_id(
+    db: &PgPool,
+    client_snip_id: String
+) -> Result<Option<SnipEntityWithLesson>, sqlx::Error> {
+    let mut query = select_with_lesson();
+    query.push(" WHERE snip.deleted_at IS NULL AND snip.client_snip_id = ").push_bind(client_snip_id);
 
     query
         .build_query_as::<SnipEntityWithLesson>()
         .fetch_optional(db).await
+}
+
+/// Trashed snips of `user_id` that can still be restored, latest first.
+pub async fn trashed(
+    db: &PgPool,
+    limit: u32,
+    cursor: Option<TrashedSnipCursor>,
+    user_id: i64
+) -> Result<Vec<TrashedSnipEntity>, sqlx::Error> {
+    let mut query = select_with_lesson();
+    query.push(" WHERE snip.deleted_at IS NOT NULL AND snip.user_id = ").push_bind(user_id);
+
+    if let Some(cursor) = cursor {
+        query.push(" AND (snip.deleted_at,snip.id) < (")
+            .push_bind(cursor.deleted_at).push(",").push_bind(cursor.id).push(")");
+    }
+
+    query.push(" ORDER BY snip.deleted_at DESC, snip.id DESC")
+        .push(" LIMIT ").push_bind(limit as i64)
+        .build_query_as::<TrashedSnipEntity>()
+        .fetch_all(db).await
 }
\ No newline at end of file
@@ -0,0 +1,33 @@
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+#[derive(Debug, FromRow)]
+pub struct SnipShareEntity {
+    pub token: String,
+    pub view_count: i64,
+    pub last_viewed_at: Option<OffsetDateTime>,
+    pub revoked_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime
+}
+
+/// Public subset of a shared snip, joined with its lesson, author and topic.
+#[derive(Debug, FromRow)]
+pub struct SharedSnipEntity {
+    pub snip_id: i64,
+    pub token: String,
+    pub view_count: i64,
+    pub created_at: OffsetDateTime,
+    //snip
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub note_text: Option<String>,
+    //lesson
+    pub lesson_title: String,
+    pub lesson_description: Option<String>,
+    pub cover_image_path: Option<String>,
+    //author
+    pub author_name: String,
+    pub author_avatar_path: Option<String>,
+    //topic
+    pub topic_title: Option<String>
+}
@@ -0,0 +1,103 @@
+use crate::db::snip_share::entity::{SharedSnipEntity, SnipShareEntity};
+use sqlx::PgPool;
+
+pub async fn insert(
+    db: &PgPool,
+    snip_id: i64,
+    user_id: i64,
+    token: String,
+) -> Result<SnipShareEntity, sqlx::Error> {
+    sqlx::query_as::<_, SnipShareEntity>(
+        r#"
+            INSERT INTO snip_share (snip_id, user_id, token)
+            VALUES ($1, $2, $3)
+            RETURNING *
+            "#,
+    )
+        .bind(snip_id)
+        .bind(user_id)
+        .bind(token)
+        .fetch_one(db)
+        .await
+}
+
+pub async fn list_by_snip(db: &PgPool, snip_id: i64) -> Result<Vec<SnipShareEntity>, sqlx::Error> {
+    sqlx::query_as::<_, SnipShareEntity>(
+        r#"
+        SELECT * FROM snip_share
+        WHERE snip_id = $1
+        ORDER BY created_at DESC, id DESC
+        "#,
+    )
+        .bind(snip_id)
+        .fetch_all(db)
+        .await
+}
+
+pub async fn revoke(
+    db: &PgPool,
+    token: String,
+    user_id: i64,
+) -> Result<Option<SnipShareEntity>, sqlx::Error> {
+    sqlx::query_as::<_, SnipShareEntity>(
+        r#"
+        UPDATE snip_share
+        SET revoked_at = COALESCE(revoked_at, NOW())
+        WHERE token = $1 AND user_id = $2
+        RETURNING *
+        "#,
+    )
+        .bind(token)
+        .bind(user_id)
+        .fetch_optional(db)
+        .await
+}
+
+/// Counts a view and returns the shared snip. Revoked tokens and tokens of
+/// deleted snips or lessons resolve to `None`.
+pub async fn view(db: &PgPool, token: String) -> Result<Option<SharedSnipEntity>, sqlx::Error> {
+    sqlx::query_as::<_, SharedSnipEntity>(
+        r#"
+        WITH share AS (
+            UPDATE snip_share
+            SET view_count = view_count + 1,
+                last_viewed_at = NOW()
+            WHERE token = $1
+              AND revoked_at IS NULL
+              AND EXISTS (
+                  SELECT 1 FROM snip
+                  JOIN lesson ON lesson.id = snip.lesson_id AND lesson.deleted_at IS NULL
+                      AND lesson.publication_status IN ('published', 'unlisted')
+                  WHERE snip.id = snip_share.snip_id AND snip.deleted_at IS NULL
+              )
+            RETURNING *
+        )
+        SELECT
+            share.snip_id,
+            share.token,
+            share.view_count,
+            share.created_at,
+
+            snip.start_ms,
+            snip.end_ms,
+            snip.note_text,
+
+            lesson.title AS lesson_title,
+            lesson.description AS lesson_description,
+            COALESCE(lesson.cover_image_path, topic.cover_image_path) AS cover_image_path,
+
+            author.name AS author_name,
+            author.avatar_path AS author_avatar_path,
+
+            topic.title AS topic_title
+        FROM share
+        JOIN snip ON snip.id = share.snip_id
+        JOIN lesson ON lesson.id = snip.lesson_id
+        JOIN author ON author.id = lesson.author_id
+        LEFT JOIN topic ON topic.id = lesson.topic_id
+        "#,
+    )
+        .bind(token)
+        .fetch_optional(db)
+        .await
+}
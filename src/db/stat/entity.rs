@@ -0,0 +1,41 @@
+use crate::db::user::entity::UserSettingEntity;
+use sqlx::FromRow;
+use time::Date;
+
+#[derive(Debug, FromRow)]
+pub struct DailyStatEntity {
+    pub day: Date,
+    pub listened_ms: i64,
+    pub lessons_completed: i32,
+    pub snips_created: i32
+}
+
+/// Totals of the rollup around today in the user's timezone.
+#[derive(Debug, FromRow)]
+pub struct StatSummaryEntity {
+    pub today: Date,
+    pub listened_ms_today: i64,
+    /// Since Monday
+    pub listened_ms_week: i64,
+    pub listened_ms_month: i64,
+    pub listened_ms: i64,
+    pub lessons_completed: i64,
+    pub snips_created: i64
+}
+
+#[derive(Debug, FromRow)]
+pub struct StreakEntity {
+    /// Active days in a row up to today, or up to yesterday while today is
+    /// still open
+    pub current: i64,
+    pub longest: i64
+}
+
+pub struct StatsEntity {
+    pub settings: UserSettingEntity,
+    pub summary: StatSummaryEntity,
+    /// The last seven days, oldest first
+    pub days: Vec<DailyStatEntity>,
+    pub streaks: StreakEntity,
+    pub topics_completed: i64
+}
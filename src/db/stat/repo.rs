@@ -0,0 +1,195 @@
+use crate::db::listen::repo::{COUNTED_STEP, STEP_COLUMNS, STEP_WINDOW};
+use crate::db::stat::entity::{DailyStatEntity, StatSummaryEntity, StreakEntity};
+use sqlx::{PgConnection, PgPool};
+use time::{Date, OffsetDateTime};
+
+/// Rebuilds the rollup of the days between `from` and `to` in the user's
+/// timezone from the listen events, completed lessons and created snips.
+/// Without a range every day is rebuilt, as needed after a timezone change.
+pub async fn refresh(
+    connection: &mut PgConnection,
+    user_id: i64,
+    range: Option<(OffsetDateTime, OffsetDateTime)>
+) -> Result<(), sqlx::Error> {
+    let (from, to) = range.unzip();
+
+    // concurrent refreshes of a user would insert the same days, so they take turns
+    sqlx::query("SELECT id FROM users WHERE id = $1 FOR UPDATE")
+        .bind(user_id)
+        .execute(&mut *connection)
+        .await?;
+
+    sqlx::query(
+        r#"
+        DELETE FROM user_daily_stat
+        USING users
+        WHERE user_daily_stat.user_id = $1 AND users.id = $1
+            AND ($2::TIMESTAMPTZ IS NULL OR user_daily_stat.day BETWEEN
+                ($2::TIMESTAMPTZ AT TIME ZONE users.timezone)::DATE
+                AND ($3::TIMESTAMPTZ AT TIME ZONE users.timezone)::DATE)
+        "#
+    )
+        .bind(user_id)
+        .bind(from)
+        .bind(to)
+        .execute(&mut *connection)
+        .await?;
+
+    // a day in any timezone lies within a day of the same UTC instant
+    sqlx::query(
+        &format!(r#"
+        WITH setting AS (
+            SELECT timezone,
+                ($2::TIMESTAMPTZ AT TIME ZONE timezone)::DATE AS first_day,
+                ($3::TIMESTAMPTZ AT TIME ZONE timezone)::DATE AS last_day
+            FROM users WHERE id = $1
+        ), steps AS (
+            SELECT {STEP_COLUMNS}
+            FROM listen_event
+            JOIN listen_session ON listen_session.id = listen_event.listen_session_id
+            WHERE listen_session.user_id = $1
+                AND ($2::TIMESTAMPTZ IS NULL OR listen_event.listen_session_id IN (
+                    SELECT listen_session_id FROM listen_event
+                    WHERE occurred_at BETWEEN $2::TIMESTAMPTZ - INTERVAL '1 day' AND $3::TIMESTAMPTZ + INTERVAL '1 day'
+                ))
+            {STEP_WINDOW}
+        ), listened AS (
+            SELECT (steps.occurred_at AT TIME ZONE setting.timezone)::DATE AS day,
+                SUM(steps.advanced_ms / steps.playback_rate)::BIGINT AS listened_ms
+            FROM steps, setting
+            WHERE {COUNTED_STEP}
+            GROUP BY 1
+        ), completed AS (
+            SELECT (completed_at AT TIME ZONE setting.timezone)::DATE AS day,
+                COUNT(*)::INT AS lessons_completed
+            FROM lesson_progress, setting
+            WHERE user_id = $1 AND completed_at IS NOT NULL
+                AND ($2::TIMESTAMPTZ IS NULL OR completed_at
+                    BETWEEN $2::TIMESTAMPTZ - INTERVAL '1 day' AND $3::TIMESTAMPTZ + INTERVAL '1 day')
+            GROUP BY 1
+        ), snips AS (
+            SELECT (created_at AT TIME ZONE setting.timezone)::DATE AS day,
+                COUNT(*)::INT AS snips_created
+            FROM snip, setting
+            WHERE user_id = $1
+                AND ($2::TIMESTAMPTZ IS NULL OR created_at
+                    BETWEEN $2::TIMESTAMPTZ - INTERVAL '1 day' AND $3::TIMESTAMPTZ + INTERVAL '1 day')
+            GROUP BY 1
+        ), days AS (
+            SELECT day FROM listened
+            UNION SELECT day FROM completed
+            UNION SELECT day FROM snips
+        )
+        INSERT INTO user_daily_stat (user_id, day, listened_ms, lessons_completed, snips_created)
+        SELECT $1, days.day,
+            COALESCE(listened.listened_ms, 0),
+            COALESCE(completed.lessons_completed, 0),
+            COALESCE(snips.snips_created, 0)
+        FROM days
+        CROSS JOIN setting
+        LEFT JOIN listened USING (day)
+        LEFT JOIN completed USING (day)
+        LEFT JOIN snips USING (day)
+        WHERE $2::TIMESTAMPTZ IS NULL OR days.day BETWEEN setting.first_day AND setting.last_day
+        "#)
+    )
+        .bind(user_id)
+        .bind(from)
+        .bind(to)
+        .execute(connection)
+        .await?;
+    Ok(())
+}
+
+pub async fn summary(db: &PgPool, user_id: i64) -> Result<StatSummaryEntity, sqlx::Error> {
+    sqlx::query_as::<_, StatSummaryEntity>(
+        r#"
+        WITH today AS (
+            SELECT (NOW() AT TIME ZONE timezone)::DATE AS day FROM users WHERE id = $1
+        )
+        SELECT today.day AS today,
+            COALESCE(SUM(stat.listened_ms) FILTER (WHERE stat.day = today.day), 0)::BIGINT AS listened_ms_today,
+            COALESCE(SUM(stat.listened_ms) FILTER (
+                WHERE stat.day BETWEEN DATE_TRUNC('week', today.day)::DATE AND today.day
+            ), 0)::BIGINT AS listened_ms_week,
+            COALESCE(SUM(stat.listened_ms) FILTER (
+                WHERE stat.day BETWEEN DATE_TRUNC('month', today.day)::DATE AND today.day
+            ), 0)::BIGINT AS listened_ms_month,
+            COALESCE(SUM(stat.listened_ms), 0)::BIGINT AS listened_ms,
+            COALESCE(SUM(stat.lessons_completed), 0)::BIGINT AS lessons_completed,
+            COALESCE(SUM(stat.snips_created), 0)::BIGINT AS snips_created
+        FROM today
+        LEFT JOIN user_daily_stat stat ON stat.user_id = $1
+        GROUP BY today.day
+        "#
+    )
+        .bind(user_id)
+        .fetch_one(db)
+        .await
+}
+
+/// Every day from `from` to `to`, with zeros for the days without activity.
+pub async fn daily(
+    db: &PgPool,
+    user_id: i64,
+    from: Date,
+    to: Date
+) -> Result<Vec<DailyStatEntity>, sqlx::Error> {
+    sqlx::query_as::<_, DailyStatEntity>(
+        r#"
+        SELECT series.day::DATE AS day,
+            COALESCE(stat.listened_ms, 0) AS listened_ms,
+            COALESCE(stat.lessons_completed, 0) AS lessons_completed,
+            COALESCE(stat.snips_created, 0) AS snips_created
+        FROM GENERATE_SERIES($2::DATE, $3::DATE, INTERVAL '1 day') AS series(day)
+        LEFT JOIN user_daily_stat stat ON stat.user_id = $1 AND stat.day = series.day::DATE
+        ORDER BY series.day
+        "#
+    )
+        .bind(user_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(db)
+        .await
+}
+
+/// Streaks of days the user listened or completed a lesson on, up to `today`.
+pub async fn streaks(db: &PgPool, user_id: i64, today: Date) -> Result<StreakEntity, sqlx::Error> {
+    sqlx::query_as::<_, StreakEntity>(
+        r#"
+        WITH active AS (
+            SELECT day, day - (ROW_NUMBER() OVER (ORDER BY day))::INT AS island
+            FROM user_daily_stat
+            WHERE user_id = $1 AND day <= $2 AND (listened_ms > 0 OR lessons_completed > 0)
+        ), islands AS (
+            SELECT MAX(day) AS last_day, COUNT(*) AS length
+            FROM active
+            GROUP BY island
+        )
+        SELECT COALESCE(MAX(length) FILTER (WHERE last_day >= $2 - 1), 0)::BIGINT AS current,
+            COALESCE(MAX(length), 0)::BIGINT AS longest
+        FROM islands
+        "#
+    )
+        .bind(user_id)
+        .bind(today)
+        .fetch_one(db)
+        .await
+}
+
+/// Live topics whose every published lesson the user has completed.
+pub async fn topics_completed(db: &PgPool, user_id: i64) -> Result<i64, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (i64,)>(
+        r#"
+        SELECT COUNT(*)
+        FROM topic_progress
+        JOIN topic ON topic.id = topic_progress.topic_id
+        WHERE topic_progress.user_id = $1 AND topic.deleted_at IS NULL
+            AND topic.lesson_count > 0
+            AND topic_progress.completed_lesson_count >= topic.lesson_count
+        "#
+    )
+        .bind(user_id)
+        .fetch_one(db)
+        .await?.0)
+}
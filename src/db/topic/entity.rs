@@ -1,3 +1,4 @@
+use crate::module::common::enums::PublicationStatus;
 use smart_default::SmartDefault;
 use sqlx::FromRow;
 use time::OffsetDateTime;
@@ -13,7 +14,14 @@ pub struct TopicEntity {
     pub created_at: OffsetDateTime,
     pub lesson_count: i64,
     pub total_duration: i64,
-    pub snip_count: i64
+    pub snip_count: i64,
+    pub rating_count: i64,
+    /// 0 without ratings
+    pub rating_average: f64,
+    #[default(PublicationStatus::Draft)]
+    pub publication_status: PublicationStatus,
+    pub publish_at: Option<OffsetDateTime>,
+    pub published_at: Option<OffsetDateTime>
 }
 
 #[derive(Debug, FromRow, SmartDefault)]
@@ -34,5 +42,7 @@ pub struct TopicInput {
     pub author_id: i64,
     pub title: String,
     pub description: Option<String>,
-    pub cover_image_path: Option<String>
+    pub cover_image_path: Option<String>,
+    pub publication_status: PublicationStatus,
+    pub publish_at: Option<OffsetDateTime>
 }
\ No newline at end of file
@@ -1,16 +1,18 @@
 use crate::db::topic::entity::{TopicEntity, TopicInput, TopicWithAuthor};
-use crate::module::common::enums::UserProgressStatus;
+use crate::db::translation;
+use crate::module::common::enums::{TranslationKind, UserProgressStatus};
 use crate::module::common::paging::QueryOrder;
 use crate::module::common::topic::dto::QuerySort;
 use crate::module::user::topic::dto::TopicCursor;
+use crate::utils;
 use sqlx::{PgConnection, PgPool, Postgres, QueryBuilder};
 use time::OffsetDateTime;
 
 pub async fn insert(db: &PgPool, topic: TopicInput) -> Result<TopicEntity, sqlx::Error> {
     sqlx::query_as::<_, TopicEntity>(
         r#"
-            INSERT INTO topic (author_id, title, description, cover_image_path)
-            VALUES ($1, $2, $3, $4)
+            INSERT INTO topic (author_id, title, description, cover_image_path, publication_status, publish_at, published_at)
+            VALUES ($1, $2, $3, $4, $5, $6, CASE WHEN $5 = 'published' THEN NOW() END)
             RETURNING *
             "#,
     )
@@ -18,6 +20,8 @@ pub async fn insert(db: &PgPool, topic: TopicInput) -> Result<TopicEntity, sqlx:
     .bind(topic.title)
     .bind(topic.description)
     .bind(topic.cover_image_path)
+    .bind(topic.publication_status)
+    .bind(topic.publish_at)
     .fetch_one(db)
     .await
 }
@@ -32,7 +36,13 @@ pub async fn update(
         UPDATE topic
         SET title = $1,
             description = $2,
-            cover_image_path = $3
+            cover_image_path = $3,
+            publication_status = $5,
+            publish_at = $6,
+            published_at = CASE
+                WHEN $5 = 'published' AND publication_status <> 'published' THEN NOW()
+                ELSE published_at
+            END
         WHERE id = $4
         RETURNING *
         "#,
@@ -41,6 +51,8 @@ pub async fn update(
     .bind(topic.description)
     .bind(topic.cover_image_path)
     .bind(id)
+    .bind(topic.publication_status)
+    .bind(topic.publish_at)
     .fetch_optional(db)
     .await
 }
@@ -66,7 +78,7 @@ pub async fn delete_by_author_id(
     author_id: i64,
 ) -> Result<(), sqlx::Error> {
     sqlx::query(
-        r#"UPDATE topic SET deleted_at = NOW() WHERE author_id = $1"#,
+        r#"UPDATE topic SET deleted_at = NOW() WHERE author_id = $1 AND deleted_at IS NULL"#,
     )
         .bind(author_id)
         .execute(connection)
@@ -74,13 +86,54 @@ pub async fn delete_by_author_id(
     Ok(())
 }
 
+pub async fn restore(
+    connection: &mut PgConnection,
+    id: i64,
+) -> Result<Option<TopicEntity>, sqlx::Error> {
+    sqlx::query_as::<_, TopicEntity>(
+        r#"
+               UPDATE topic SET deleted_at = NULL WHERE id = $1 AND deleted_at IS NOT NULL
+               RETURNING *
+            "#,
+    )
+    .bind(id)
+    .fetch_optional(connection)
+    .await
+}
+
+/// Restores the topics `delete_by_author_id` trashed along with the author,
+/// recognised by sharing its `deleted_at`. Must run before the author itself
+/// is restored.
+pub async fn restore_by_author_id(
+    connection: &mut PgConnection,
+    author_id: i64,
+) -> Result<Vec<i64>, sqlx::Error> {
+    let ids = sqlx::query_as::<_, (i64,)>(
+        r#"
+        UPDATE topic SET deleted_at = NULL
+        WHERE author_id = $1
+          AND deleted_at = (SELECT deleted_at FROM author WHERE id = $1)
+        RETURNING id
+        "#,
+    )
+    .bind(author_id)
+    .fetch_all(connection)
+    .await?;
+    Ok(ids.into_iter().map(|id| id.0).collect())
+}
+
 pub async fn deleted(
     db: &PgPool,
     since: OffsetDateTime,
 ) -> Result<Vec<(i64, OffsetDateTime)>, sqlx::Error> {
     let result = sqlx::query_as::<_, (i64, OffsetDateTime)>(
         r#"
-               SELECT id, deleted_at FROM topic WHERE deleted_at IS NOT NULL AND deleted_at >= $1
+               SELECT id, COALESCE(deleted_at, updated_at) FROM topic
+               WHERE deleted_at >= $1
+                  OR (deleted_at IS NULL
+                      AND publication_status <> 'published'
+                      AND published_at IS NOT NULL
+                      AND updated_at >= $1)
             "#,
     )
     .bind(since)
@@ -89,6 +142,36 @@ pub async fn deleted(
     Ok(result)
 }
 
+pub async fn published(
+    db: &PgPool,
+    since: OffsetDateTime,
+) -> Result<Vec<(i64, OffsetDateTime)>, sqlx::Error> {
+    sqlx::query_as::<_, (i64, OffsetDateTime)>(
+        r#"
+               SELECT id, published_at FROM topic
+               WHERE deleted_at IS NULL AND publication_status = 'published' AND published_at >= $1
+            "#,
+    )
+    .bind(since)
+    .fetch_all(db)
+    .await
+}
+
+/// Flips scheduled topics whose `publish_at` has passed to published.
+pub async fn publish_due(db: &PgPool) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query(
+        r#"
+        UPDATE topic
+        SET publication_status = 'published',
+            published_at = NOW()
+        WHERE publication_status = 'scheduled' AND publish_at <= NOW()
+        "#,
+    )
+    .execute(db)
+    .await?
+    .rows_affected())
+}
+
 fn build_query(
     query: &mut QueryBuilder<Postgres>,
     search: &Option<String>,
@@ -107,11 +190,9 @@ fn build_query(
         has_where = true;
     }
 
-    if let Some(search) = search {
-        query
-            .push(if has_where { " AND " } else { " WHERE " })
-            .push("title ILIKE ")
-            .push_bind(format!("{}%", search));
+    if let Some(search) = search.as_deref().and_then(utils::search::to_tsquery) {
+        query.push(if has_where { " AND " } else { " WHERE " });
+        translation::repo::push_search(query, TranslationKind::Topic, search);
         has_where = true;
     }
 
@@ -137,6 +218,11 @@ pub async fn page(
         topic.lesson_count,
         topic.total_duration,
         topic.snip_count,
+        topic.rating_count,
+        topic.rating_average,
+        topic.publication_status,
+        topic.publish_at,
+        topic.published_at,
         topic.created_at
     "#,
     );
@@ -160,6 +246,9 @@ pub async fn page(
         Some(sort) if sort == QuerySort::SnipCount => {
             query.push(" topic.snip_count");
         }
+        Some(QuerySort::Rating) => {
+            query.push(" topic.rating_average");
+        }
         _ => {
             query.push(" topic.created_at");
         }
@@ -220,6 +309,11 @@ pub async fn page_cursor(
         topic.lesson_count,
         topic.total_duration,
         topic.snip_count,
+        topic.rating_count,
+        topic.rating_average,
+        topic.publication_status,
+        topic.publish_at,
+        topic.published_at,
         topic.created_at,
     "#,
     );
@@ -248,7 +342,11 @@ pub async fn page_cursor(
             .push_bind(user_id);
     }
 
-    let has_where = build_query(&mut query, search, author_id);
+    let mut has_where = build_query(&mut query, search, author_id);
+    query
+        .push(if has_where { " AND " } else { " WHERE " })
+        .push("topic.publication_status = 'published'");
+    has_where = true;
 
     if let Some(status) = status {
         if let UserProgressStatus::InProgress = status {
@@ -280,6 +378,16 @@ pub async fn page_cursor(
                     .push_bind(cursor.id)
                     .push(")");
             }
+            Some(QuerySort::Rating) => {
+                query
+                    .push("(topic.rating_average,topic.id)")
+                    .push(order_sign)
+                    .push("(")
+                    .push_bind(cursor.rating_average)
+                    .push(",")
+                    .push_bind(cursor.id)
+                    .push(")");
+            }
             _ => {
                 query.push("topic.id").push(order_sign).push_bind(cursor.id);
             }
@@ -293,6 +401,9 @@ pub async fn page_cursor(
         Some(QuerySort::CreatedAt) => {
             format!("topic.created_at {order}, topic.id {order}")
         }
+        Some(QuerySort::Rating) => {
+            format!("topic.rating_average {order}, topic.id {order}")
+        }
         None => {
             format!("topic.id {order}")
         }
@@ -306,6 +417,16 @@ pub async fn page_cursor(
         .await
 }
 
+/// Author of a live topic, keeping the topic from being trashed until the
+/// transaction ends.
+pub async fn lock_active(connection: &mut PgConnection, id: i64) -> Result<Option<i64>, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (i64,)>("SELECT author_id FROM topic WHERE id = $1 AND deleted_at IS NULL FOR SHARE")
+        .bind(id)
+        .fetch_optional(connection)
+        .await?
+        .map(|(author_id,)| author_id))
+}
+
 pub async fn get_by_id(db: &PgPool, id: i64) -> Result<Option<TopicWithAuthor>, sqlx::Error> {
     Ok(sqlx::query_as::<_, TopicWithAuthor>(
         r#"
@@ -318,6 +439,11 @@ pub async fn get_by_id(db: &PgPool, id: i64) -> Result<Option<TopicWithAuthor>,
              topic.lesson_count,
              topic.total_duration,
              topic.snip_count,
+             topic.rating_count,
+             topic.rating_average,
+             topic.publication_status,
+             topic.publish_at,
+             topic.published_at,
              topic.created_at,
              author.name AS author_name,
              author.avatar_path AS author_avatar_path,
@@ -333,6 +459,27 @@ pub async fn get_by_id(db: &PgPool, id: i64) -> Result<Option<TopicWithAuthor>,
     .await?)
 }
 
+pub async fn get_by_title(
+    db: &PgPool,
+    title: &str,
+    author_id: Option<i64>,
+) -> Result<Option<TopicEntity>, sqlx::Error> {
+    sqlx::query_as::<_, TopicEntity>(
+        r#"
+             SELECT * FROM topic
+             WHERE deleted_at IS NULL
+               AND LOWER(title) = LOWER($1)
+               AND ($2::BIGINT IS NULL OR author_id = $2)
+             ORDER BY id
+             LIMIT 1
+            "#,
+    )
+    .bind(title)
+    .bind(author_id)
+    .fetch_optional(db)
+    .await
+}
+
 pub async fn get_by_lesson_id(db: &PgPool, id: i64) -> Result<Option<TopicEntity>, sqlx::Error> {
     Ok(sqlx::query_as::<_, TopicEntity>(
         r#"
@@ -386,6 +533,119 @@ pub async fn increase_snip_count(
     Ok(())
 }
 
+pub async fn update_snip_count(
+    connection: &mut PgConnection,
+    topic_id: i64,
+    snip_delta: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE topic
+        SET snip_count = snip_count + $1
+        WHERE id = $2
+        "#,
+    )
+    .bind(snip_delta)
+    .bind(topic_id)
+    .execute(&mut *connection)
+    .await?;
+    Ok(())
+}
+
+/// Recomputes `lesson_count`, `total_duration`, `snip_count` and the rating
+/// aggregates from the topic's live lessons and snips. Only published lessons
+/// are counted in `lesson_count` and `total_duration`.
+pub async fn recount_stats(
+    connection: &mut PgConnection,
+    topic_id: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE topic
+        SET lesson_count = lessons.count,
+            total_duration = lessons.duration,
+            rating_count = lessons.rating_count,
+            rating_sum = lessons.rating_sum,
+            snip_count = (
+                SELECT COUNT(*) FROM snip WHERE snip.topic_id = topic.id AND snip.deleted_at IS NULL
+            )
+        FROM (
+            SELECT COUNT(*) FILTER (WHERE publication_status = 'published') AS count,
+                COALESCE(SUM(duration) FILTER (WHERE publication_status = 'published'), 0)::BIGINT AS duration,
+                COALESCE(SUM(rating_count), 0)::BIGINT AS rating_count,
+                COALESCE(SUM(rating_sum), 0)::BIGINT AS rating_sum
+            FROM lesson
+            WHERE topic_id = $1 AND deleted_at IS NULL
+        ) AS lessons
+        WHERE topic.id = $1
+        "#,
+    )
+    .bind(topic_id)
+    .execute(&mut *connection)
+    .await?;
+    Ok(())
+}
+
+/// Recomputes the rating aggregates from the topic's live lessons.
+pub async fn recount_rating(
+    connection: &mut PgConnection,
+    topic_id: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE topic
+        SET rating_count = lessons.count,
+            rating_sum = lessons.sum
+        FROM (
+            SELECT COALESCE(SUM(rating_count), 0)::BIGINT AS count,
+                COALESCE(SUM(rating_sum), 0)::BIGINT AS sum
+            FROM lesson
+            WHERE topic_id = $1 AND deleted_at IS NULL
+        ) AS lessons
+        WHERE topic.id = $1
+        "#,
+    )
+    .bind(topic_id)
+    .execute(&mut *connection)
+    .await?;
+    Ok(())
+}
+
+/// Recounts `topic_id` progress for every user with progress on `lesson_id`.
+/// Only completed lessons that are live and published are counted.
+pub async fn refresh_progress_of_lesson_users(
+    connection: &mut PgConnection,
+    lesson_id: i64,
+    author_id: i64,
+    topic_id: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO topic_progress (user_id, author_id, topic_id, completed_lesson_count)
+        SELECT moved.user_id, $2, $3, (
+            SELECT COUNT(lesson_progress.id)
+            FROM lesson_progress
+            JOIN lesson ON lesson.id = lesson_progress.lesson_id
+            WHERE lesson_progress.user_id = moved.user_id
+              AND lesson_progress.topic_id = $3
+              AND lesson_progress.status = 'completed'
+              AND lesson.deleted_at IS NULL
+              AND lesson.publication_status = 'published'
+            )
+        FROM lesson_progress moved
+        WHERE moved.lesson_id = $1
+        ON CONFLICT (user_id, topic_id) DO UPDATE
+            SET completed_lesson_count = EXCLUDED.completed_lesson_count
+        "#,
+    )
+    .bind(lesson_id)
+    .bind(author_id)
+    .bind(topic_id)
+    .execute(&mut *connection)
+    .await?;
+    Ok(())
+}
+
 pub async fn update_progress(
     connection: &mut PgConnection,
     user_id: i64,
@@ -397,12 +657,15 @@ pub async fn update_progress(
         INSERT INTO topic_progress (user_id, author_id, topic_id, completed_lesson_count)
         VALUES (
             $1, $2, $3, (
-            SELECT COUNT(id)
+            SELECT COUNT(lesson_progress.id)
             FROM lesson_progress
-            WHERE user_id = $1
-              AND author_id = $2
-              AND topic_id = $3
-              AND status = 'completed'
+            JOIN lesson ON lesson.id = lesson_progress.lesson_id
+            WHERE lesson_progress.user_id = $1
+              AND lesson_progress.author_id = $2
+              AND lesson_progress.topic_id = $3
+              AND lesson_progress.status = 'completed'
+              AND lesson.deleted_at IS NULL
+              AND lesson.publication_status = 'published'
             ))
         ON CONFLICT (user_id, topic_id) DO UPDATE
             SET completed_lesson_count = EXCLUDED.completed_lesson_count
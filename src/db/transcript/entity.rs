@@ -0,0 +1,34 @@
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow)]
+pub struct TranscriptSegmentEntity {
+    pub position: i32,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub text: String
+}
+
+#[derive()]
+pub struct TranscriptSegmentInput {
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub text: String
+}
+
+/// A transcript segment matching a search, with the lesson it belongs to.
+#[derive(Debug, FromRow)]
+pub struct TranscriptHitEntity {
+    pub id: i64,
+    pub lesson_id: i64,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub text: String,
+    pub highlight: String,
+    pub rank: f32,
+    //lesson
+    pub lesson_title: String,
+    pub lesson_cover_image_path: Option<String>,
+    //author
+    pub author_id: i64,
+    pub author_name: String
+}
@@ -0,0 +1,114 @@
+use crate::db::lesson::repo::{LISTED, REACHABLE};
+use crate::db::transcript::entity::{TranscriptHitEntity, TranscriptSegmentEntity, TranscriptSegmentInput};
+use crate::module::user::lesson::dto::TranscriptCursor;
+use crate::utils::search::HIGHLIGHT_OPTIONS;
+use sqlx::{PgConnection, PgPool, Postgres, QueryBuilder};
+
+pub async fn delete_by_lesson_id(
+    connection: &mut PgConnection,
+    lesson_id: i64,
+) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query("DELETE FROM lesson_transcript_segment WHERE lesson_id = $1")
+        .bind(lesson_id)
+        .execute(connection)
+        .await?
+        .rows_affected())
+}
+
+/// Inserts `segments` in order; positions start at 0.
+pub async fn insert_all(
+    connection: &mut PgConnection,
+    lesson_id: i64,
+    segments: Vec<TranscriptSegmentInput>,
+) -> Result<(), sqlx::Error> {
+    // stay well below the bind parameter limit of a single statement
+    for (chunk_index, chunk) in segments.chunks(1000).enumerate() {
+        let mut query = QueryBuilder::<Postgres>::new(
+            "INSERT INTO lesson_transcript_segment (lesson_id, position, start_ms, end_ms, text) "
+        );
+        query.push_values(chunk.iter().enumerate(), |mut row, (index, segment)| {
+            row.push_bind(lesson_id)
+                .push_bind((chunk_index * 1000 + index) as i32)
+                .push_bind(segment.start_ms)
+                .push_bind(segment.end_ms)
+                .push_bind(segment.text.clone());
+        });
+        query.build().execute(&mut *connection).await?;
+    }
+    Ok(())
+}
+
+/// With `live_only`, returns nothing for lessons that are not published or unlisted.
+pub async fn get_by_lesson_id(
+    db: &PgPool,
+    lesson_id: i64,
+    live_only: bool,
+) -> Result<Vec<TranscriptSegmentEntity>, sqlx::Error> {
+    sqlx::query_as::<_, TranscriptSegmentEntity>(
+        r#"
+        SELECT segment.position, segment.start_ms, segment.end_ms, segment.text
+        FROM lesson_transcript_segment segment
+        JOIN lesson ON lesson.id = segment.lesson_id AND lesson.deleted_at IS NULL
+        WHERE segment.lesson_id = $1
+          AND (NOT $2 OR lesson.publication_status IN ('published', 'unlisted'))
+        ORDER BY segment.position
+        "#,
+    )
+        .bind(lesson_id)
+        .bind(live_only)
+        .fetch_all(db)
+        .await
+}
+
+/// Segments matching `tsquery`, best first: within `lesson_id` if it is
+/// reachable, otherwise across the listed lessons.
+pub async fn search(
+    db: &PgPool,
+    tsquery: String,
+    lesson_id: Option<i64>,
+    limit: u32,
+    cursor: Option<TranscriptCursor>,
+) -> Result<Vec<TranscriptHitEntity>, sqlx::Error> {
+    let mut query = QueryBuilder::<Postgres>::new("WITH q AS (SELECT to_tsquery('simple', search_normalize(");
+    query.push_bind(tsquery).push(")) AS query)");
+
+    let visible = if lesson_id.is_some() { REACHABLE } else { LISTED };
+    query.push(format!(r#"
+        SELECT * FROM (
+            SELECT segment.id,
+                segment.lesson_id,
+                segment.start_ms,
+                segment.end_ms,
+                segment.text,
+                ts_headline('simple', segment.text, q.query, '{HIGHLIGHT_OPTIONS}') AS highlight,
+                ts_rank(segment.search_vector, q.query) AS rank,
+                lesson.title AS lesson_title,
+                lesson.cover_image_path AS lesson_cover_image_path,
+                author.id AS author_id,
+                author.name AS author_name
+            FROM lesson_transcript_segment segment
+            JOIN lesson ON lesson.id = segment.lesson_id
+            JOIN author ON author.id = lesson.author_id AND author.deleted_at IS NULL
+            LEFT JOIN topic ON topic.id = lesson.topic_id, q
+            WHERE {visible} AND segment.search_vector @@ q.query
+    "#));
+
+    if let Some(lesson_id) = lesson_id {
+        query.push(" AND segment.lesson_id = ").push_bind(lesson_id);
+    }
+
+    query.push(") AS result");
+
+    if let Some(cursor) = cursor {
+        query.push(" WHERE (rank, id) < (")
+            .push_bind(cursor.rank).push("::REAL, ")
+            .push_bind(cursor.id).push(")");
+    }
+
+    query.push(" ORDER BY rank DESC, id DESC");
+
+    query
+        .push(" LIMIT ").push_bind(limit as i64)
+        .build_query_as::<TranscriptHitEntity>()
+        .fetch_all(db).await
+}
@@ -0,0 +1,20 @@
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+#[derive(Debug, FromRow)]
+pub struct TranslationEntity {
+    /// Id of the translated author, topic or lesson
+    pub owner_id: i64,
+    pub locale: String,
+    /// Author name, topic or lesson title
+    pub title: String,
+    /// Always `None` for authors
+    pub description: Option<String>,
+    pub updated_at: OffsetDateTime
+}
+
+#[derive()]
+pub struct TranslationInput {
+    pub title: String,
+    pub description: Option<String>
+}
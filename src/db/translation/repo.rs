@@ -0,0 +1,119 @@
+use crate::db::translation::entity::{TranslationEntity, TranslationInput};
+use crate::module::common::enums::TranslationKind;
+use sqlx::{PgPool, Postgres, QueryBuilder};
+
+/// Translation table, owner column, translated owner table and title column.
+fn columns(kind: TranslationKind) -> (&'static str, &'static str, &'static str, &'static str) {
+    match kind {
+        TranslationKind::Author => ("author_translation", "author_id", "author", "name"),
+        TranslationKind::Topic => ("topic_translation", "topic_id", "topic", "title"),
+        TranslationKind::Lesson => ("lesson_translation", "lesson_id", "lesson", "title")
+    }
+}
+
+fn select(kind: TranslationKind) -> String {
+    let (table, owner, _, title) = columns(kind);
+    let description = if kind == TranslationKind::Author { "NULL::TEXT" } else { "description" };
+    format!("SELECT {owner} AS owner_id, locale, {title} AS title, {description} AS description, updated_at FROM {table}")
+}
+
+/// Matches `tsquery` against the search document of the owner row or of any
+/// of its translations. The owner table must be in the query under its name.
+pub fn push_search(
+    query: &mut QueryBuilder<Postgres>,
+    kind: TranslationKind,
+    tsquery: String
+) {
+    let (table, owner, owner_table, _) = columns(kind);
+    query.push(format!("({owner_table}.search_vector @@ to_tsquery('simple', search_normalize("))
+        .push_bind(tsquery.clone())
+        .push(format!(")) OR EXISTS (SELECT 1 FROM {table} tr WHERE tr.{owner} = {owner_table}.id"))
+        .push(" AND tr.search_vector @@ to_tsquery('simple', search_normalize(")
+        .push_bind(tsquery)
+        .push("))))");
+}
+
+pub async fn get_by_owner_id(
+    db: &PgPool,
+    kind: TranslationKind,
+    owner_id: i64
+) -> Result<Vec<TranslationEntity>, sqlx::Error> {
+    let (_, owner, _, _) = columns(kind);
+    sqlx::query_as::<_, TranslationEntity>(
+        &format!("{} WHERE {owner} = $1 ORDER BY locale", select(kind))
+    )
+        .bind(owner_id)
+        .fetch_all(db)
+        .await
+}
+
+pub async fn get_by_owner_ids(
+    db: &PgPool,
+    kind: TranslationKind,
+    owner_ids: &[i64],
+    locale: &str
+) -> Result<Vec<TranslationEntity>, sqlx::Error> {
+    let (_, owner, _, _) = columns(kind);
+    sqlx::query_as::<_, TranslationEntity>(
+        &format!("{} WHERE {owner} = ANY($1) AND locale = $2", select(kind))
+    )
+        .bind(owner_ids)
+        .bind(locale)
+        .fetch_all(db)
+        .await
+}
+
+pub async fn upsert(
+    db: &PgPool,
+    kind: TranslationKind,
+    owner_id: i64,
+    locale: &str,
+    translation: TranslationInput
+) -> Result<TranslationEntity, sqlx::Error> {
+    let (table, owner, _, title) = columns(kind);
+    let description = if kind == TranslationKind::Author { "NULL::TEXT" } else { "description" };
+    let sql = if kind == TranslationKind::Author {
+        format!(r#"
+            INSERT INTO {table} ({owner}, locale, {title})
+            VALUES ($1, $2, $3)
+            ON CONFLICT ({owner}, locale) DO UPDATE
+                SET {title} = EXCLUDED.{title}
+            RETURNING {owner} AS owner_id, locale, {title} AS title, {description} AS description, updated_at
+        "#)
+    } else {
+        format!(r#"
+            INSERT INTO {table} ({owner}, locale, {title}, description)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT ({owner}, locale) DO UPDATE
+                SET {title} = EXCLUDED.{title},
+                    description = EXCLUDED.description
+            RETURNING {owner} AS owner_id, locale, {title} AS title, {description} AS description, updated_at
+        "#)
+    };
+
+    let mut query = sqlx::query_as::<_, TranslationEntity>(&sql)
+        .bind(owner_id)
+        .bind(locale)
+        .bind(translation.title);
+    if kind != TranslationKind::Author {
+        query = query.bind(translation.description);
+    }
+    query.fetch_one(db).await
+}
+
+pub async fn delete(
+    db: &PgPool,
+    kind: TranslationKind,
+    owner_id: i64,
+    locale: &str
+) -> Result<u64, sqlx::Error> {
+    let (table, owner, _, _) = columns(kind);
+    Ok(
+        sqlx::query(&format!("DELETE FROM {table} WHERE {owner} = $1 AND locale = $2"))
+            .bind(owner_id)
+            .bind(locale)
+            .execute(db)
+            .await?
+            .rows_affected()
+    )
+}
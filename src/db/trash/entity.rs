@@ -0,0 +1,16 @@
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+#[derive(Debug, FromRow)]
+pub struct TrashItemEntity {
+    /// `author`, `topic` or `lesson`
+    pub kind: String,
+    pub id: i64,
+    pub title: String,
+    pub image_path: Option<String>,
+    pub author_id: i64,
+    pub topic_id: Option<i64>,
+    /// The author or topic it belongs to is trashed as well
+    pub parent_deleted: bool,
+    pub deleted_at: OffsetDateTime
+}
@@ -0,0 +1,243 @@
+use crate::db::trash::entity::TrashItemEntity;
+use crate::module::common::enums::TrashKind;
+use sqlx::{PgConnection, PgPool, Postgres, QueryBuilder};
+use time::OffsetDateTime;
+
+fn kind_name(kind: TrashKind) -> &'static str {
+    match kind {
+        TrashKind::Author => "author",
+        TrashKind::Topic => "topic",
+        TrashKind::Lesson => "lesson"
+    }
+}
+
+fn build_query(
+    query: &mut QueryBuilder<Postgres>,
+    kind: Option<TrashKind>
+) {
+    query.push(r#"
+        FROM (
+            SELECT 'author' AS kind,
+                author.id,
+                author.name AS title,
+                author.avatar_path AS image_path,
+                author.id AS author_id,
+                NULL::BIGINT AS topic_id,
+                FALSE AS parent_deleted,
+                author.deleted_at
+            FROM author
+            WHERE author.deleted_at IS NOT NULL
+
+            UNION ALL
+
+            SELECT 'topic',
+                topic.id,
+                topic.title,
+                topic.cover_image_path,
+                topic.author_id,
+                NULL,
+                NOT EXISTS (SELECT 1 FROM author WHERE author.id = topic.author_id AND author.deleted_at IS NULL),
+                topic.deleted_at
+            FROM topic
+            WHERE topic.deleted_at IS NOT NULL
+
+            UNION ALL
+
+            SELECT 'lesson',
+                lesson.id,
+                lesson.title,
+                lesson.cover_image_path,
+                lesson.author_id,
+                lesson.topic_id,
+                NOT EXISTS (SELECT 1 FROM author WHERE author.id = lesson.author_id AND author.deleted_at IS NULL)
+                    OR (lesson.topic_id IS NOT NULL AND NOT EXISTS (
+                        SELECT 1 FROM topic WHERE topic.id = lesson.topic_id AND topic.deleted_at IS NULL
+                    )),
+                lesson.deleted_at
+            FROM lesson
+            WHERE lesson.deleted_at IS NOT NULL
+        ) AS trash
+    "#);
+
+    if let Some(kind) = kind {
+        query.push(" WHERE kind = ").push_bind(kind_name(kind));
+    }
+}
+
+pub async fn page(
+    db: &PgPool,
+    limit: u32,
+    offset: u32,
+    kind: Option<TrashKind>
+) -> Result<Vec<TrashItemEntity>, sqlx::Error> {
+    let mut query = QueryBuilder::<Postgres>::new("SELECT *");
+    build_query(&mut query, kind);
+
+    query.push(" ORDER BY deleted_at DESC, id DESC")
+        .push(" LIMIT ").push_bind(limit as i32)
+        .push(" OFFSET ").push_bind(offset as i32)
+        .build_query_as::<TrashItemEntity>()
+        .fetch_all(db).await
+}
+
+pub async fn count(
+    db: &PgPool,
+    kind: Option<TrashKind>
+) -> Result<i64, sqlx::Error> {
+    let mut query = QueryBuilder::<Postgres>::new("SELECT COUNT(*)");
+    build_query(&mut query, kind);
+    Ok(
+        query
+            .build_query_as::<(i64,)>()
+            .fetch_one(db).await?.0
+    )
+}
+
+pub async fn get(
+    db: &PgPool,
+    kind: TrashKind,
+    id: i64
+) -> Result<Option<TrashItemEntity>, sqlx::Error> {
+    let mut query = QueryBuilder::<Postgres>::new("SELECT *");
+    build_query(&mut query, Some(kind));
+    query.push(" AND id = ").push_bind(id);
+
+    query
+        .build_query_as::<TrashItemEntity>()
+        .fetch_optional(db).await
+}
+
+/// Permanently deletes snips trashed before `before`, with their share links.
+pub async fn purge_snips(
+    connection: &mut PgConnection,
+    before: OffsetDateTime
+) -> Result<u64, sqlx::Error> {
+    Ok(
+        sqlx::query_as::<_, (i64,)>(
+            r#"
+            WITH purged AS (
+                DELETE FROM snip WHERE deleted_at < $1
+                RETURNING id
+            ), shares AS (
+                DELETE FROM snip_share WHERE snip_id IN (SELECT id FROM purged)
+            )
+            SELECT COUNT(*) FROM purged
+            "#
+        )
+            .bind(before)
+            .fetch_one(connection)
+            .await?.0 as u64
+    )
+}
+
+/// Permanently deletes lessons trashed before `before` together with every
+/// row hanging off them. Returns the purged `(id, topic_id)` pairs.
+pub async fn purge_lessons(
+    connection: &mut PgConnection,
+    before: OffsetDateTime
+) -> Result<Vec<(i64, Option<i64>)>, sqlx::Error> {
+    sqlx::query_as::<_, (i64, Option<i64>)>(
+        r#"
+        WITH purged AS (
+            DELETE FROM lesson WHERE deleted_at < $1
+            RETURNING id, topic_id
+        ), snips AS (
+            DELETE FROM snip WHERE lesson_id IN (SELECT id FROM purged)
+            RETURNING id
+        ), shares AS (
+            DELETE FROM snip_share WHERE snip_id IN (SELECT id FROM snips)
+        ), progress AS (
+            DELETE FROM lesson_progress WHERE lesson_id IN (SELECT id FROM purged)
+        ), favourites AS (
+            DELETE FROM favourite_lesson WHERE lesson_id IN (SELECT id FROM purged)
+        ), sessions AS (
+            DELETE FROM listen_session WHERE lesson_id IN (SELECT id FROM purged)
+            RETURNING id
+        ), events AS (
+            DELETE FROM listen_event WHERE listen_session_id IN (SELECT id FROM sessions)
+        ), daily AS (
+            DELETE FROM listen_daily WHERE lesson_id IN (SELECT id FROM purged)
+        ), segments AS (
+            DELETE FROM lesson_transcript_segment WHERE lesson_id IN (SELECT id FROM purged)
+        ), chapters AS (
+            DELETE FROM lesson_chapter WHERE lesson_id IN (SELECT id FROM purged)
+        ), revisions AS (
+            DELETE FROM lesson_revision WHERE lesson_id IN (SELECT id FROM purged)
+        ), translations AS (
+            DELETE FROM lesson_translation WHERE lesson_id IN (SELECT id FROM purged)
+        ), playlist_items AS (
+            DELETE FROM playlist_item WHERE lesson_id IN (SELECT id FROM purged)
+        ), queue_items AS (
+            DELETE FROM play_queue_item WHERE lesson_id IN (SELECT id FROM purged)
+        ), queues AS (
+            UPDATE play_queue SET version = version + 1, current_lesson_id = NULL
+            WHERE current_lesson_id IN (SELECT id FROM purged)
+        ), notifications AS (
+            DELETE FROM notification WHERE lesson_id IN (SELECT id FROM purged)
+        ), reviews AS (
+            DELETE FROM lesson_review WHERE lesson_id IN (SELECT id FROM purged)
+        )
+        SELECT id, topic_id FROM purged
+        "#
+    )
+        .bind(before)
+        .fetch_all(connection)
+        .await
+}
+
+/// Topics still holding a trashed lesson wait until that lesson is purged.
+pub async fn purge_topics(
+    connection: &mut PgConnection,
+    before: OffsetDateTime
+) -> Result<u64, sqlx::Error> {
+    Ok(
+        sqlx::query_as::<_, (i64,)>(
+            r#"
+            WITH purged AS (
+                DELETE FROM topic
+                WHERE deleted_at < $1
+                  AND NOT EXISTS (SELECT 1 FROM lesson WHERE lesson.topic_id = topic.id)
+                RETURNING id
+            ), progress AS (
+                DELETE FROM topic_progress WHERE topic_id IN (SELECT id FROM purged)
+            ), translations AS (
+                DELETE FROM topic_translation WHERE topic_id IN (SELECT id FROM purged)
+            ), subscriptions AS (
+                DELETE FROM topic_subscription WHERE topic_id IN (SELECT id FROM purged)
+            )
+            SELECT COUNT(*) FROM purged
+            "#
+        )
+            .bind(before)
+            .fetch_one(connection)
+            .await?.0 as u64
+    )
+}
+
+/// Authors still holding a trashed topic or lesson wait until those are purged.
+pub async fn purge_authors(
+    connection: &mut PgConnection,
+    before: OffsetDateTime
+) -> Result<u64, sqlx::Error> {
+    Ok(
+        sqlx::query_as::<_, (i64,)>(
+            r#"
+            WITH purged AS (
+                DELETE FROM author
+                WHERE deleted_at < $1
+                  AND NOT EXISTS (SELECT 1 FROM topic WHERE topic.author_id = author.id)
+                  AND NOT EXISTS (SELECT 1 FROM lesson WHERE lesson.author_id = author.id)
+                RETURNING id
+            ), translations AS (
+                DELETE FROM author_translation WHERE author_id IN (SELECT id FROM purged)
+            ), follows AS (
+                DELETE FROM author_follow WHERE author_id IN (SELECT id FROM purged)
+            )
+            SELECT COUNT(*) FROM purged
+            "#
+        )
+            .bind(before)
+            .fetch_one(connection)
+            .await?.0 as u64
+    )
+}
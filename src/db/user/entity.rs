@@ -16,3 +16,10 @@ pub struct UserEntity {
     pub created_at: OffsetDateTime,
     pub updated_at: OffsetDateTime
 }
+
+#[derive(Debug, FromRow)]
+pub struct UserSettingEntity {
+    /// IANA name the statistics are computed in
+    pub timezone: String,
+    pub daily_goal_minutes: i32
+}
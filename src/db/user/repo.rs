@@ -1,4 +1,4 @@
-use crate::db::user::entity::UserEntity;
+use crate::db::user::entity::{UserEntity, UserSettingEntity};
 use sqlx::{PgConnection, PgPool};
 
 pub async fn insert(   
@@ -73,3 +73,38 @@ pub async fn find_by_google_id(db: &PgPool, google_id: &str) -> Result<Option<Us
         .fetch_optional(db)
         .await
 }
+
+pub async fn get_settings(db: &PgPool, id: i64) -> Result<Option<UserSettingEntity>, sqlx::Error> {
+    sqlx::query_as::<_, UserSettingEntity>("SELECT timezone, daily_goal_minutes FROM users WHERE id = $1")
+        .bind(id)
+        .fetch_optional(db)
+        .await
+}
+
+/// Returns the settings as they were before the update.
+pub async fn update_settings(
+    connection: &mut PgConnection,
+    id: i64,
+    settings: UserSettingEntity
+) -> Result<Option<UserSettingEntity>, sqlx::Error> {
+    sqlx::query_as::<_, UserSettingEntity>(
+        r#"
+        UPDATE users SET timezone = $2, daily_goal_minutes = $3
+        FROM (SELECT timezone, daily_goal_minutes FROM users WHERE id = $1 FOR UPDATE) AS old
+        WHERE users.id = $1
+        RETURNING old.timezone, old.daily_goal_minutes
+        "#
+    )
+        .bind(id)
+        .bind(settings.timezone)
+        .bind(settings.daily_goal_minutes)
+        .fetch_optional(connection)
+        .await
+}
+
+pub async fn is_timezone(db: &PgPool, timezone: &str) -> Result<bool, sqlx::Error> {
+    Ok(sqlx::query_as::<_, (bool,)>("SELECT EXISTS (SELECT 1 FROM pg_timezone_names WHERE name = $1)")
+        .bind(timezone)
+        .fetch_one(db)
+        .await?.0)
+}
@@ -0,0 +1,40 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use fluent_templates::LanguageIdentifier;
+use serde_json::Value;
+use thiserror::Error;
+use crate::error::AppError;
+use crate::module::common::base::BaseResponse;
+use crate::string_keys::strings;
+use crate::utils::t;
+
+#[derive(Error, Debug, Clone)]
+pub enum FileError {
+    #[error("UploadNotCompleted")]
+    UploadNotCompleted(LanguageIdentifier),
+    #[error("UploadVerificationFailed")]
+    UploadVerificationFailed(LanguageIdentifier)
+}
+
+impl From<FileError> for AppError {
+    fn from(value: FileError) -> Self { AppError::File(value) }
+}
+
+impl IntoResponse for FileError {
+    fn into_response(self) -> Response {
+        let (status, code, lang, message_key, data_payload) = match self {
+            FileError::UploadNotCompleted(lang) => (StatusCode::CONFLICT, 106001, lang, strings::UPLOAD_NOT_COMPLETED, None),
+            FileError::UploadVerificationFailed(lang) => (StatusCode::UNPROCESSABLE_ENTITY, 106002, lang, strings::UPLOAD_VERIFICATION_FAILED, None)
+        };
+
+        let body = axum::Json(
+            BaseResponse::<Value>::error(
+                code,
+                &t(&lang, message_key),
+                data_payload
+            )
+        );
+
+        (status, body).into_response()
+    }
+}
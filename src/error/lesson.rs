@@ -1,7 +1,7 @@
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use fluent_templates::LanguageIdentifier;
-use serde_json::Value;
+use serde_json::{json, Value};
 use thiserror::Error;
 use crate::error::AppError;
 use crate::module::common::base::BaseResponse;
@@ -11,7 +11,11 @@ use crate::utils::t;
 #[derive(Error, Debug, Clone)]
 pub enum LessonError {
     #[error("LessonDeleteTooManyListens")]
-    LessonDeleteTooManyListens(LanguageIdentifier)
+    LessonDeleteTooManyListens(LanguageIdentifier),
+    #[error("InvalidTranscript")]
+    InvalidTranscript(LanguageIdentifier, usize),
+    #[error("InvalidChapter")]
+    InvalidChapter(LanguageIdentifier, usize)
 }
 
 impl From<LessonError> for AppError {
@@ -21,7 +25,9 @@ impl From<LessonError> for AppError {
 impl IntoResponse for LessonError {
     fn into_response(self) -> Response {
         let (status, code, lang, message_key, data_payload) = match self {
-            LessonError::LessonDeleteTooManyListens(lang) => (StatusCode::CONFLICT, 104001, lang, strings::LESSON_DELETE_TOO_MANY_LISTENS, None)
+            LessonError::LessonDeleteTooManyListens(lang) => (StatusCode::CONFLICT, 104001, lang, strings::LESSON_DELETE_TOO_MANY_LISTENS, None),
+            LessonError::InvalidTranscript(lang, segment) => (StatusCode::UNPROCESSABLE_ENTITY, 104002, lang, strings::INVALID_TRANSCRIPT, Some(json!({ "segment": segment }))),
+            LessonError::InvalidChapter(lang, chapter) => (StatusCode::UNPROCESSABLE_ENTITY, 104003, lang, strings::INVALID_CHAPTER, Some(json!({ "chapter": chapter })))
         };
 
         let body = axum::Json(
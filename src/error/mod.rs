@@ -3,6 +3,11 @@ pub mod author;
 pub mod lesson;
 pub mod topic;
 pub mod snip;
+pub mod file;
+pub mod trash;
+pub mod playlist;
+pub mod queue;
+pub mod review;
 
 use crate::error::auth::AuthError;
 use crate::error::author::AuthorError;
@@ -21,6 +26,11 @@ use sqlx::Error;
 use std::fmt::Debug;
 use thiserror::Error;
 use crate::error::snip::SnipError;
+use crate::error::file::FileError;
+use crate::error::trash::TrashError;
+use crate::error::playlist::PlaylistError;
+use crate::error::queue::QueueError;
+use crate::error::review::ReviewError;
 
 #[derive(Error, Debug, Clone)]
 pub enum AppError {
@@ -48,6 +58,16 @@ pub enum AppError {
     Lesson(LessonError),
     #[error(transparent)]
     Snip(SnipError),
+    #[error(transparent)]
+    File(FileError),
+    #[error(transparent)]
+    Trash(TrashError),
+    #[error(transparent)]
+    Playlist(PlaylistError),
+    #[error(transparent)]
+    Queue(QueueError),
+    #[error(transparent)]
+    Review(ReviewError),
 }
 
 impl IntoResponse for AppError {
@@ -89,6 +109,11 @@ impl IntoResponse for AppError {
             AppError::Topic(err) => return err.into_response(),
             AppError::Lesson(err) => return err.into_response(),
             AppError::Snip(err) => return err.into_response(),
+            AppError::File(err) => return err.into_response(),
+            AppError::Trash(err) => return err.into_response(),
+            AppError::Playlist(err) => return err.into_response(),
+            AppError::Queue(err) => return err.into_response(),
+            AppError::Review(err) => return err.into_response(),
         };
 
         let body = axum::Json(BaseResponse::<Value>::error(
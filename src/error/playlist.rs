@@ -0,0 +1,37 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use fluent_templates::LanguageIdentifier;
+use serde_json::Value;
+use thiserror::Error;
+use crate::error::AppError;
+use crate::module::common::base::BaseResponse;
+use crate::string_keys::strings;
+use crate::utils::t;
+
+#[derive(Error, Debug, Clone)]
+pub enum PlaylistError {
+    #[error("PlaylistOrderMismatch")]
+    PlaylistOrderMismatch(LanguageIdentifier)
+}
+
+impl From<PlaylistError> for AppError {
+    fn from(value: PlaylistError) -> Self { AppError::Playlist(value) }
+}
+
+impl IntoResponse for PlaylistError {
+    fn into_response(self) -> Response {
+        let (status, code, lang, message_key, data_payload) = match self {
+            PlaylistError::PlaylistOrderMismatch(lang) => (StatusCode::UNPROCESSABLE_ENTITY, 108001, lang, strings::PLAYLIST_ORDER_MISMATCH, None)
+        };
+
+        let body = axum::Json(
+            BaseResponse::<Value>::error(
+                code,
+                &t(&lang, message_key),
+                data_payload
+            )
+        );
+
+        (status, body).into_response()
+    }
+}
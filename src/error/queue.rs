@@ -0,0 +1,40 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use fluent_templates::LanguageIdentifier;
+use serde_json::{json, Value};
+use thiserror::Error;
+use crate::error::AppError;
+use crate::module::common::base::BaseResponse;
+use crate::string_keys::strings;
+use crate::utils::t;
+
+#[derive(Error, Debug, Clone)]
+pub enum QueueError {
+    #[error("QueueVersionConflict")]
+    QueueVersionConflict(LanguageIdentifier, i64),
+    #[error("QueueOrderMismatch")]
+    QueueOrderMismatch(LanguageIdentifier)
+}
+
+impl From<QueueError> for AppError {
+    fn from(value: QueueError) -> Self { AppError::Queue(value) }
+}
+
+impl IntoResponse for QueueError {
+    fn into_response(self) -> Response {
+        let (status, code, lang, message_key, data_payload) = match self {
+            QueueError::QueueVersionConflict(lang, version) => (StatusCode::CONFLICT, 109001, lang, strings::QUEUE_VERSION_CONFLICT, Some(json!({ "version": version }))),
+            QueueError::QueueOrderMismatch(lang) => (StatusCode::UNPROCESSABLE_ENTITY, 109002, lang, strings::QUEUE_ORDER_MISMATCH, None)
+        };
+
+        let body = axum::Json(
+            BaseResponse::<Value>::error(
+                code,
+                &t(&lang, message_key),
+                data_payload
+            )
+        );
+
+        (status, body).into_response()
+    }
+}
@@ -0,0 +1,40 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use fluent_templates::LanguageIdentifier;
+use serde_json::Value;
+use thiserror::Error;
+use crate::error::AppError;
+use crate::module::common::base::BaseResponse;
+use crate::string_keys::strings;
+use crate::utils::t;
+
+#[derive(Error, Debug, Clone)]
+pub enum ReviewError {
+    #[error("ReviewNeedsProgress")]
+    ReviewNeedsProgress(LanguageIdentifier),
+    #[error("ReviewHidden")]
+    ReviewHidden(LanguageIdentifier)
+}
+
+impl From<ReviewError> for AppError {
+    fn from(value: ReviewError) -> Self { AppError::Review(value) }
+}
+
+impl IntoResponse for ReviewError {
+    fn into_response(self) -> Response {
+        let (status, code, lang, message_key, data_payload) = match self {
+            ReviewError::ReviewNeedsProgress(lang) => (StatusCode::FORBIDDEN, 110001, lang, strings::REVIEW_NEEDS_PROGRESS, None),
+            ReviewError::ReviewHidden(lang) => (StatusCode::FORBIDDEN, 110002, lang, strings::REVIEW_HIDDEN, None)
+        };
+
+        let body = axum::Json(
+            BaseResponse::<Value>::error(
+                code,
+                &t(&lang, message_key),
+                data_payload
+            )
+        );
+
+        (status, body).into_response()
+    }
+}
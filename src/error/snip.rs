@@ -9,11 +9,16 @@ use crate::string_keys::strings;
 use crate::utils::t;
 
 #[derive(Error, Debug, Clone)]
+#[allow(clippy::enum_variant_names)]
 pub enum SnipError {
     #[error("SnipNotOwnedUpdate")]
     SnipNotOwnedUpdate(LanguageIdentifier),
     #[error("SnipNotOwnedDelete")]
-    SnipNotOwnedDelete(LanguageIdentifier)
+    SnipNotOwnedDelete(LanguageIdentifier),
+    #[error("SnipNotOwnedExport")]
+    SnipNotOwnedExport(LanguageIdentifier),
+    #[error("SnipNotOwnedRestore")]
+    SnipNotOwnedRestore(LanguageIdentifier)
 }
 
 impl From<SnipError> for AppError {
@@ -24,7 +29,9 @@ impl IntoResponse for SnipError {
     fn into_response(self) -> Response {
         let (status, code, lang, message_key, data_payload) = match self {
             SnipError::SnipNotOwnedUpdate(lang) => (StatusCode::FORBIDDEN, 105001, lang, strings::SNIP_NOT_OWNED_UPDATE, None),
-            SnipError::SnipNotOwnedDelete(lang) => (StatusCode::FORBIDDEN, 105002, lang, strings::SNIP_NOT_OWNED_DELETE, None)
+            SnipError::SnipNotOwnedDelete(lang) => (StatusCode::FORBIDDEN, 105002, lang, strings::SNIP_NOT_OWNED_DELETE, None),
+            SnipError::SnipNotOwnedExport(lang) => (StatusCode::FORBIDDEN, 105003, lang, strings::SNIP_NOT_OWNED_EXPORT, None),
+            SnipError::SnipNotOwnedRestore(lang) => (StatusCode::FORBIDDEN, 105004, lang, strings::SNIP_NOT_OWNED_RESTORE, None)
         };
 
         let body = axum::Json(
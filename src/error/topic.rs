@@ -11,7 +11,9 @@ use crate::utils::t;
 #[derive(Error, Debug, Clone)]
 pub enum TopicError {
     #[error("AuthorHasLesson")]
-    TopicHasLesson(LanguageIdentifier)
+    TopicHasLesson(LanguageIdentifier),
+    #[error("LessonOrderMismatch")]
+    LessonOrderMismatch(LanguageIdentifier)
 }
 
 impl From<TopicError> for AppError {
@@ -21,7 +23,8 @@ impl From<TopicError> for AppError {
 impl IntoResponse for TopicError {
     fn into_response(self) -> Response {
         let (status, code, lang, message_key, data_payload) = match self {
-            TopicError::TopicHasLesson(lang) => (StatusCode::CONFLICT, 103001, lang, strings::TOPIC_HAS_LESSON, None)
+            TopicError::TopicHasLesson(lang) => (StatusCode::CONFLICT, 103001, lang, strings::TOPIC_HAS_LESSON, None),
+            TopicError::LessonOrderMismatch(lang) => (StatusCode::UNPROCESSABLE_ENTITY, 103002, lang, strings::LESSON_ORDER_MISMATCH, None)
         };
 
         let body = axum::Json(
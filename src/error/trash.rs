@@ -0,0 +1,40 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use fluent_templates::LanguageIdentifier;
+use serde_json::Value;
+use thiserror::Error;
+use crate::error::AppError;
+use crate::module::common::base::BaseResponse;
+use crate::string_keys::strings;
+use crate::utils::t;
+
+#[derive(Error, Debug, Clone)]
+pub enum TrashError {
+    #[error("TrashParentDeleted")]
+    TrashParentDeleted(LanguageIdentifier),
+    #[error("TrashRestoreConflict")]
+    TrashRestoreConflict(LanguageIdentifier)
+}
+
+impl From<TrashError> for AppError {
+    fn from(value: TrashError) -> Self { AppError::Trash(value) }
+}
+
+impl IntoResponse for TrashError {
+    fn into_response(self) -> Response {
+        let (status, code, lang, message_key, data_payload) = match self {
+            TrashError::TrashParentDeleted(lang) => (StatusCode::CONFLICT, 107001, lang, strings::TRASH_PARENT_DELETED, None),
+            TrashError::TrashRestoreConflict(lang) => (StatusCode::CONFLICT, 107002, lang, strings::TRASH_RESTORE_CONFLICT, None)
+        };
+
+        let body = axum::Json(
+            BaseResponse::<Value>::error(
+                code,
+                &t(&lang, message_key),
+                data_payload
+            )
+        );
+
+        (status, body).into_response()
+    }
+}
@@ -0,0 +1,18 @@
+use crate::module::common::file::service;
+use crate::state::AppState;
+use crate::utils::CONFIG;
+use std::time::Duration;
+
+pub async fn run(state: AppState) {
+    let mut interval = tokio::time::interval(Duration::from_mins(CONFIG.gc_interval_minutes));
+    loop {
+        interval.tick().await;
+        match service::collect_garbage(&state.db, &state.s3_client).await {
+            Ok((deleted, failed)) if deleted + failed > 0 => {
+                println!("🧹 GC deleted {} orphaned assets ({} failed)", deleted, failed);
+            }
+            Ok(_) => {}
+            Err(err) => eprintln!("GC run failed: {:?}", err),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+pub mod gc;
+pub mod notify;
+pub mod publish;
+pub mod trash;
+
+use crate::state::AppState;
+
+pub fn spawn(state: AppState) {
+    tokio::spawn(gc::run(state.clone()));
+    tokio::spawn(publish::run(state.clone()));
+    tokio::spawn(notify::run(state.clone()));
+    tokio::spawn(trash::run(state));
+}
@@ -0,0 +1,26 @@
+use crate::module::common::notification;
+use crate::state::AppState;
+use crate::utils::CONFIG;
+use std::time::Duration;
+
+pub async fn run(state: AppState) {
+    let mut interval = tokio::time::interval(Duration::from_mins(CONFIG.notify_interval_minutes));
+    loop {
+        interval.tick().await;
+        match notification::service::create_due(&state.db).await {
+            Ok(created) if created > 0 => println!("🔔 Created {} notifications", created),
+            Ok(_) => {}
+            Err(err) => eprintln!("Notification run failed: {:?}", err),
+        }
+        match notification::service::deliver(&state.db, &state.push).await {
+            Ok(sent) if sent > 0 => println!("📲 Sent {} pushes", sent),
+            Ok(_) => {}
+            Err(err) => eprintln!("Push run failed: {:?}", err),
+        }
+        match notification::service::deliver_telegram(&state.db, &state.push).await {
+            Ok(sent) if sent > 0 => println!("✈️ Sent {} Telegram messages", sent),
+            Ok(_) => {}
+            Err(err) => eprintln!("Telegram run failed: {:?}", err),
+        }
+    }
+}
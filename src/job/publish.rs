@@ -0,0 +1,21 @@
+use crate::module::common::{lesson, topic};
+use crate::state::AppState;
+use crate::utils::CONFIG;
+use std::time::Duration;
+
+pub async fn run(state: AppState) {
+    let mut interval = tokio::time::interval(Duration::from_mins(CONFIG.publish_interval_minutes));
+    loop {
+        interval.tick().await;
+        // topics first, so lessons going live with their topic show up together
+        let topics = topic::service::publish_due(&state.db).await;
+        let lessons = lesson::service::publish_due(&state.db).await;
+        match (topics, lessons) {
+            (Ok(topics), Ok(lessons)) if topics + lessons > 0 => {
+                println!("📣 Published {} scheduled topics and {} scheduled lessons", topics, lessons);
+            }
+            (Ok(_), Ok(_)) => {}
+            (Err(err), _) | (_, Err(err)) => eprintln!("Publish run failed: {:?}", err),
+        }
+    }
+}
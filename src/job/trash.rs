@@ -0,0 +1,22 @@
+use crate::module::common::trash::service;
+use crate::state::AppState;
+use crate::utils::CONFIG;
+use std::time::Duration;
+
+// purged rows release their assets, which the next GC run collects
+pub async fn run(state: AppState) {
+    let mut interval = tokio::time::interval(Duration::from_mins(CONFIG.gc_interval_minutes));
+    loop {
+        interval.tick().await;
+        match service::purge(&state.db).await {
+            Ok((snips, lessons, topics, authors)) if snips + lessons + topics + authors > 0 => {
+                println!(
+                    "🗑️ Purged {} snips, {} lessons, {} topics and {} authors from the trash",
+                    snips, lessons, topics, authors
+                );
+            }
+            Ok(_) => {}
+            Err(err) => eprintln!("Trash purge failed: {:?}", err),
+        }
+    }
+}
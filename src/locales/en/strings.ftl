@@ -1,5 +1,6 @@
 not_found = Not found
 bad_request = Bad request
+publish_at_required = Scheduled content needs a publish_at time.
 internal_error = Internal server error
 unsupported_file_type = Unsupported file type.
 file_too_large = File too large. Maximum allowed size is 100 MB.
@@ -10,9 +11,59 @@ invalid_credentials = Invalid credentials
 author_has_lesson = Cannot delete author because it has an attached lesson.
 
 topic_has_lesson = Cannot delete topic because it has an attached lesson.
+lesson_order_mismatch = The lesson order must list every lesson of the topic exactly once.
 
 lesson_delete_too_many_listens = Cannot delete lesson because it has more than 50 listens.
+invalid_transcript = Transcript segment is invalid: check its timing, order and text.
+invalid_chapter = Chapter is invalid: it must have a title and start inside the lesson, after the previous chapter.
+chapter_default_title = Chapter { $number }
 
 snip_not_owned_update = You can only update your own snips
 snip_not_owned_delete = You can only delete your own snips
+snip_not_owned_export = You can only export your own snips
+snip_not_owned_restore = You can only restore your own snips
 
+upload_not_completed = File upload has not been completed.
+upload_verification_failed = Uploaded file does not match the upload session.
+
+trash_parent_deleted = Restore the author or topic it belongs to first.
+trash_restore_conflict = An item with the same name already exists.
+
+playlist_order_mismatch = The order must list every lesson of the playlist exactly once.
+
+queue_version_conflict = The queue was changed on another device. Reload it and try again.
+queue_order_mismatch = The order must list every lesson of the queue exactly once.
+
+review_needs_progress = Finish the lesson or listen to most of it before reviewing it.
+review_hidden = This review was hidden by a moderator and can't be removed.
+
+achievement_first_lesson_title = First lesson
+achievement_first_lesson_description = Complete your first lesson.
+achievement_first_topic_title = First topic
+achievement_first_topic_description = Complete every lesson of a topic.
+achievement_week_streak_title = Week streak
+achievement_week_streak_description = Listen 7 days in a row.
+achievement_month_streak_title = Month streak
+achievement_month_streak_description = Listen 30 days in a row.
+achievement_ten_snips_title = Snip collector
+achievement_ten_snips_description = Create 10 snips.
+achievement_hundred_snips_title = Snip archivist
+achievement_hundred_snips_description = Create 100 snips.
+achievement_ten_authors_title = Open mind
+achievement_ten_authors_description = Listen to lessons of 10 different authors.
+achievement_ten_hours_title = Ten hours
+achievement_ten_hours_description = Listen for 10 hours in total.
+
+notification_new_lesson_title = New lesson in { $topic }
+notification_new_lesson_body = { $lesson }
+notification_streak_at_risk_title = Your streak is at risk
+notification_streak_at_risk_body = Listen today to keep your { $days }-day streak.
+notification_weekly_summary_title = Your week in review
+notification_weekly_summary_body = Last week you listened for { $minutes } { $minutes ->
+        [one] minute
+       *[other] minutes
+    } and completed { $lessons } { $lessons ->
+        [one] lesson
+       *[other] lessons
+    }.
+telegram_not_linked = Sign in with Telegram before turning on Telegram notifications.
@@ -3,4 +3,31 @@ unknown_error = Unknown error
 invalid_credentials = Xato malutmot
 not_found = Not found
 bad_request = Bad request
-internal_error = Internal server error
\ No newline at end of file
+internal_error = Internal server error
+achievement_first_lesson_title = Birinchi dars
+achievement_first_lesson_description = Birinchi darsingizni tugating.
+achievement_first_topic_title = Birinchi mavzu
+achievement_first_topic_description = Mavzuning barcha darslarini tugating.
+achievement_week_streak_title = Bir haftalik seriya
+achievement_week_streak_description = 7 kun ketma-ket tinglang.
+achievement_month_streak_title = Bir oylik seriya
+achievement_month_streak_description = 30 kun ketma-ket tinglang.
+achievement_ten_snips_title = Parcha yig‘uvchi
+achievement_ten_snips_description = 10 ta parcha saqlang.
+achievement_hundred_snips_title = Parchalar arxivchisi
+achievement_hundred_snips_description = 100 ta parcha saqlang.
+achievement_ten_authors_title = Keng dunyoqarash
+achievement_ten_authors_description = 10 xil muallifning darslarini tinglang.
+achievement_ten_hours_title = O‘n soat
+achievement_ten_hours_description = Jami 10 soat tinglang.
+
+notification_new_lesson_title = «{ $topic }» mavzusida yangi dars
+notification_new_lesson_body = { $lesson }
+notification_streak_at_risk_title = Seriyangiz uzilish arafasida
+notification_streak_at_risk_body = { $days } kunlik seriyangizni saqlab qolish uchun bugun tinglang.
+notification_weekly_summary_title = Haftalik natijalaringiz
+notification_weekly_summary_body = O‘tgan hafta { $minutes } daqiqa tingladingiz va { $lessons } ta darsni tugatdingiz.
+chapter_default_title = { $number }-bob
+review_needs_progress = Baho berishdan oldin darsni tugating yoki uning katta qismini tinglang.
+review_hidden = Bu sharh moderator tomonidan yashirilgan va uni o‘chirib bo‘lmaydi.
+telegram_not_linked = Telegram bildirishnomalarini yoqishdan oldin Telegram orqali kiring.
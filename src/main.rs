@@ -9,8 +9,11 @@ mod string_keys;
 mod error;
 mod extractor;
 mod middleware;
+mod job;
+mod push;
 
 use crate::app::build_app;
+use crate::push::Push;
 use crate::state::AppState;
 use crate::utils::CONFIG;
 use aws_sdk_s3 as s3;
@@ -53,7 +56,11 @@ pub async fn main() -> anyhow::Result<()> {
         .await;
     let s3_client = s3::Client::new(&s3_config);
 
-    let state = AppState::new(db, redis_client, s3_client);
+    let push = Push::from_config()?;
+
+    let state = AppState::new(db, redis_client, s3_client, push);
+
+    job::spawn(state.clone());
 
     let app = build_app(state);
 
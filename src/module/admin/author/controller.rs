@@ -9,6 +9,10 @@ use crate::module::admin::author::dto::PaginationParams;
 use crate::module::common::paging::PagingResponse;
 use crate::module::common::author::service;
 use crate::utils::extractors::{ValidatedJson, ValidatedPath, ValidatedQuery};
+use crate::db::translation::entity::TranslationInput;
+use crate::module::common::enums::TranslationKind;
+use crate::module::common::translation;
+use crate::module::common::translation::dto::{TranslationParam, TranslationRequest, TranslationResponse};
 
 #[utoipa::path(
     post,
@@ -20,13 +24,15 @@ use crate::utils::extractors::{ValidatedJson, ValidatedPath, ValidatedQuery};
 )]
 pub async fn create_author(
     State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
     ValidatedJson(body): ValidatedJson<AuthorCURequest>,
 ) -> Result<BaseResponse<AuthorResponse>, AppError> {
     
     let author = service::create(
         &state.db,
         body.name,
-        body.avatar_path
+        body.avatar_path,
+        lang
     ).await?;
 
     Ok(
@@ -144,4 +150,83 @@ pub async fn delete_author(
         lang
     ).await?;
     Ok(BaseResponse::success(()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/admin/author/{id}/translation",
+    security(("cookieAuth" = [])),
+    params(IdParam),
+    responses((status = 200, body = Vec<TranslationResponse>)),
+    tag = "Author"
+)]
+pub async fn get_author_translations(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>
+) -> Result<BaseResponse<Vec<TranslationResponse>>, AppError> {
+    let translations = translation::service::list(
+        &state.db,
+        TranslationKind::Author,
+        id,
+        lang
+    ).await?;
+    Ok(
+        BaseResponse::success(
+            translations.into_iter().map(translation::mapper::to_response).collect()
+        )
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/admin/author/{id}/translation/{locale}",
+    security(("cookieAuth" = [])),
+    params(TranslationParam),
+    request_body = TranslationRequest,
+    responses((status = 200, body = TranslationResponse)),
+    tag = "Author"
+)]
+pub async fn update_author_translation(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(param): ValidatedPath<TranslationParam>,
+    ValidatedJson(body): ValidatedJson<TranslationRequest>
+) -> Result<BaseResponse<TranslationResponse>, AppError> {
+    let translation = translation::service::upsert(
+        &state.db,
+        TranslationKind::Author,
+        param.id,
+        param.locale,
+        TranslationInput {
+            title: body.title,
+            description: body.description
+        },
+        lang
+    ).await?;
+    Ok(
+        BaseResponse::success(translation::mapper::to_response(translation))
+    )
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/admin/author/{id}/translation/{locale}",
+    security(("cookieAuth" = [])),
+    params(TranslationParam),
+    tag = "Author"
+)]
+pub async fn delete_author_translation(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(param): ValidatedPath<TranslationParam>
+) -> Result<BaseResponse<()>, AppError> {
+    translation::service::delete(
+        &state.db,
+        TranslationKind::Author,
+        param.id,
+        param.locale,
+        lang
+    ).await?;
+    Ok(BaseResponse::success(()))
 }
\ No newline at end of file
@@ -2,7 +2,7 @@ use crate::middleware::auth::admin_auth_middleware;
 use crate::state::AppState;
 use axum::routing::{delete, post, put};
 use axum::{middleware, routing::get, Router};
-use crate::module::admin::author::controller::{create_author, delete_author, get_author, page_author, update_author};
+use crate::module::admin::author::controller::{create_author, delete_author, get_author, page_author, update_author, get_author_translations, update_author_translation, delete_author_translation};
 
 pub fn routes() -> Router<AppState> {
     Router::new()
@@ -11,5 +11,8 @@ pub fn routes() -> Router<AppState> {
         .route("/author/{id}", get(get_author))
         .route("/author/{id}", delete(delete_author))
         .route("/author", get(page_author))
+        .route("/author/{id}/translation", get(get_author_translations))
+        .route("/author/{id}/translation/{locale}", put(update_author_translation))
+        .route("/author/{id}/translation/{locale}", delete(delete_author_translation))
         .layer(middleware::from_fn(admin_auth_middleware))
 }
\ No newline at end of file
@@ -0,0 +1,67 @@
+use crate::error::AppError;
+use crate::extractor::accept_language::AcceptLanguage;
+use crate::module::admin::file::dto::{AudioMetadataResponse, GcReportResponse, MetadataParams, OrphanedAssetResponse};
+use crate::module::admin::file::mapper;
+use crate::module::common::base::BaseResponse;
+use crate::module::common::file::service;
+use crate::state::AppState;
+use crate::utils::CONFIG;
+use crate::utils::extractors::ValidatedQuery;
+use crate::utils::jwt::Claims;
+use axum::extract::State;
+use axum::Extension;
+
+#[utoipa::path(
+    get,
+    path = "/v1/admin/file/gc",
+    security(("cookieAuth" = [])),
+    responses((status = 200, body = GcReportResponse)),
+    tag = "File"
+)]
+pub async fn gc_report(
+    State(state): State<AppState>
+) -> Result<BaseResponse<GcReportResponse>, AppError> {
+    let (assets, cutoff) = service::gc_report(&state.db).await?;
+
+    let items: Vec<OrphanedAssetResponse> = assets.into_iter()
+        .map(|asset| mapper::to_response(asset, cutoff))
+        .collect();
+    let due = items.iter().filter(|item| item.due);
+
+    Ok(
+        BaseResponse::success(
+            GcReportResponse {
+                grace_period_hours: CONFIG.gc_grace_period_hours,
+                cutoff,
+                due_count: due.clone().count() as i64,
+                due_size: due.map(|item| item.file_size).sum(),
+                items
+            }
+        )
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/admin/file/metadata",
+    security(("cookieAuth" = [])),
+    params(MetadataParams),
+    responses((status = 200, body = AudioMetadataResponse)),
+    tag = "File"
+)]
+pub async fn metadata(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>,
+    ValidatedQuery(params): ValidatedQuery<MetadataParams>
+) -> Result<BaseResponse<AudioMetadataResponse>, AppError> {
+    let metadata = service::extract_metadata(
+        &state.db,
+        &state.s3_client,
+        params.file_key,
+        claims.sub,
+        lang
+    ).await?;
+
+    Ok(BaseResponse::success(metadata))
+}
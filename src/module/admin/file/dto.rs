@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+use crate::module::common::enums::AssetStorage;
+pub use crate::module::common::file::dto::AudioMetadataResponse;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrphanedAssetResponse {
+    pub path: String,
+    pub storage: AssetStorage,
+    pub file_size: i64,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub orphaned_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    pub due: bool
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GcReportResponse {
+    pub grace_period_hours: i64,
+    #[serde(with = "time::serde::rfc3339")]
+    pub cutoff: OffsetDateTime,
+    pub due_count: i64,
+    pub due_size: i64,
+    pub items: Vec<OrphanedAssetResponse>
+}
+
+#[derive(Deserialize, IntoParams, Validate)]
+pub struct MetadataParams {
+    #[validate(length(min = 1))]
+    pub file_key: String
+}
@@ -0,0 +1,14 @@
+use time::OffsetDateTime;
+use crate::db::asset::entity::AssetEntity;
+use crate::module::admin::file::dto::OrphanedAssetResponse;
+
+pub fn to_response(entity: AssetEntity, cutoff: OffsetDateTime) -> OrphanedAssetResponse {
+    OrphanedAssetResponse {
+        due: entity.orphaned_at.is_some_and(|at| at < cutoff),
+        path: entity.path,
+        storage: entity.storage,
+        file_size: entity.file_size,
+        orphaned_at: entity.orphaned_at,
+        created_at: entity.created_at
+    }
+}
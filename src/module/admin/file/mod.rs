@@ -0,0 +1,4 @@
+pub mod routes;
+pub mod controller;
+pub mod dto;
+pub mod mapper;
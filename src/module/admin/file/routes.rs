@@ -0,0 +1,11 @@
+use axum::{Router, routing::get, middleware};
+use crate::middleware::auth::admin_auth_middleware;
+use crate::module::admin::file::controller::{gc_report, metadata};
+use crate::state::AppState;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/file/gc", get(gc_report))
+        .route("/file/metadata", get(metadata))
+        .layer(middleware::from_fn(admin_auth_middleware))
+}
@@ -7,8 +7,26 @@ use crate::state::AppState;
 use crate::utils::extractors::{ValidatedJson, ValidatedPath, ValidatedQuery};
 use axum::extract::State;
 use crate::error::AppError;
-use crate::module::admin::lesson::dto::LessonPaginationParams;
+use crate::module::admin::lesson::dto::{LessonPaginationParams, TranscriptUploadForm};
+use crate::module::common::transcript;
+use crate::module::common::transcript::dto::{TranscriptRequest, TranscriptSegmentResponse};
+use crate::db::transcript::entity::TranscriptSegmentInput;
+use axum::extract::Multipart;
 use crate::module::common::paging::PagingResponse;
+use crate::module::common::chapter;
+use crate::module::common::chapter::dto::{ChapterImportRequest, ChapterRequest, ChapterSource};
+use crate::db::chapter::entity::ChapterInput;
+use crate::string_keys::strings;
+use crate::utils::t;
+use crate::utils::jwt::Claims;
+use crate::module::common::revision;
+use crate::module::common::revision::dto::RevisionResponse;
+use crate::module::admin::lesson::dto::LessonRevisionParam;
+use axum::Extension;
+use crate::db::translation::entity::TranslationInput;
+use crate::module::common::enums::TranslationKind;
+use crate::module::common::translation;
+use crate::module::common::translation::dto::{TranslationParam, TranslationRequest, TranslationResponse};
 
 #[utoipa::path(
     post,
@@ -22,6 +40,7 @@ use crate::module::common::paging::PagingResponse;
 pub async fn create_lesson(
     State(state): State<AppState>,
     AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>,
     ValidatedJson(body): ValidatedJson<LessonCURequest>,
 ) -> Result<BaseResponse<LessonResponse>, AppError> {
     
@@ -34,6 +53,9 @@ pub async fn create_lesson(
         body.description,
         body.cover_image_path,
         body.audio_path,
+        body.publication_status,
+        body.publish_at,
+        claims.sub,
         lang
     ).await?;
 
@@ -57,6 +79,7 @@ pub async fn update_lesson(
     State(state): State<AppState>,
     AcceptLanguage(lang): AcceptLanguage,
     ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>,
     ValidatedJson(body): ValidatedJson<LessonCURequest>,
 ) -> Result<BaseResponse<LessonResponse>, AppError> {
 
@@ -64,10 +87,16 @@ pub async fn update_lesson(
         &state.db,
         &state.s3_client,
         id,
+        body.author_id,
+        body.topic_id,
         body.title,
         body.description,
         body.cover_image_path,
         body.audio_path,
+        body.publication_status,
+        body.publish_at,
+        claims.sub,
+        None,
         lang
     ).await?;
 
@@ -162,4 +191,305 @@ pub async fn delete_lesson(
         lang
     ).await?;
     Ok(BaseResponse::success(()))
-}
\ No newline at end of file
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/admin/lesson/{id}/transcript",
+    security(("cookieAuth" = [])),
+    params(IdParam),
+    responses((status = 200, body = Vec<TranscriptSegmentResponse>)),
+    tag = "Lesson"
+)]
+pub async fn get_transcript(
+    State(state): State<AppState>,
+    ValidatedPath(id): ValidatedPath<i64>
+) -> Result<BaseResponse<Vec<TranscriptSegmentResponse>>, AppError> {
+    let segments = transcript::service::get(&state.db, id, false).await?;
+    Ok(
+        BaseResponse::success(
+            segments.into_iter().map(transcript::mapper::to_response).collect()
+        )
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/admin/lesson/{id}/transcript",
+    security(("cookieAuth" = [])),
+    params(IdParam),
+    request_body = TranscriptRequest,
+    responses((status = 200, body = Vec<TranscriptSegmentResponse>)),
+    tag = "Lesson"
+)]
+pub async fn update_transcript(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    ValidatedJson(body): ValidatedJson<TranscriptRequest>
+) -> Result<BaseResponse<Vec<TranscriptSegmentResponse>>, AppError> {
+    let segments = transcript::service::replace(
+        &state.db,
+        id,
+        body.segments.into_iter()
+            .map(|segment| TranscriptSegmentInput {
+                start_ms: segment.start_ms,
+                end_ms: segment.end_ms,
+                text: segment.text
+            })
+            .collect(),
+        lang
+    ).await?;
+
+    Ok(
+        BaseResponse::success(
+            segments.into_iter().map(transcript::mapper::to_response).collect()
+        )
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/admin/lesson/{id}/transcript/file",
+    security(("cookieAuth" = [])),
+    params(IdParam),
+    request_body(content = TranscriptUploadForm, content_type = "multipart/form-data"),
+    responses((status = 200, body = Vec<TranscriptSegmentResponse>)),
+    tag = "Lesson"
+)]
+pub async fn upload_transcript(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    mut multipart: Multipart
+) -> Result<BaseResponse<Vec<TranscriptSegmentResponse>>, AppError> {
+    while let Ok(Some(field)) = multipart.next_field().await {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let data = field.bytes().await
+            .map_err(|_| AppError::FileTooLarge(lang.clone()))?;
+        let content = String::from_utf8(data.to_vec())
+            .map_err(|_| AppError::UnsupportedFileType(lang.clone()))?;
+
+        let segments = transcript::service::import(&state.db, id, &content, lang).await?;
+        return Ok(
+            BaseResponse::success(
+                segments.into_iter().map(transcript::mapper::to_response).collect()
+            )
+        );
+    }
+
+    Err(AppError::NotFound(lang))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/admin/lesson/{id}/transcript",
+    security(("cookieAuth" = [])),
+    params(IdParam),
+    tag = "Lesson"
+)]
+pub async fn delete_transcript(
+    State(state): State<AppState>,
+    ValidatedPath(id): ValidatedPath<i64>
+) -> Result<BaseResponse<()>, AppError> {
+    transcript::service::delete(&state.db, id).await?;
+    Ok(BaseResponse::success(()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/admin/lesson/{id}/chapter",
+    security(("cookieAuth" = [])),
+    params(IdParam),
+    request_body = ChapterRequest,
+    responses((status = 200, body = LessonResponse)),
+    tag = "Lesson"
+)]
+pub async fn update_chapters(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    ValidatedJson(body): ValidatedJson<ChapterRequest>
+) -> Result<BaseResponse<LessonResponse>, AppError> {
+    chapter::service::replace(
+        &state.db,
+        id,
+        body.chapters.into_iter()
+            .map(|chapter| ChapterInput {
+                title: chapter.title,
+                start_ms: chapter.start_ms,
+                description: chapter.description
+            })
+            .collect(),
+        lang.clone()
+    ).await?;
+
+    let lesson = service::get(&state.db, id, lang).await?;
+    Ok(
+        BaseResponse::success(mapper::to_response(lesson))
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/admin/lesson/{id}/chapter/import",
+    security(("cookieAuth" = [])),
+    params(IdParam),
+    request_body = ChapterImportRequest,
+    responses((status = 200, body = LessonResponse)),
+    tag = "Lesson"
+)]
+pub async fn import_chapters(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    ValidatedJson(body): ValidatedJson<ChapterImportRequest>
+) -> Result<BaseResponse<LessonResponse>, AppError> {
+    match body.source {
+        ChapterSource::Id3 => {
+            chapter::service::import_id3(&state.db, &state.s3_client, id, lang.clone()).await?
+        }
+        ChapterSource::Text => {
+            let Some(text) = body.text else {
+                return Err(AppError::BadRequest{lang: lang.clone(), message: t(&lang, strings::BAD_REQUEST)});
+            };
+            chapter::service::import_text(&state.db, id, &text, lang.clone()).await?
+        }
+    }
+
+    let lesson = service::get(&state.db, id, lang).await?;
+    Ok(
+        BaseResponse::success(mapper::to_response(lesson))
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/admin/lesson/{id}/revision",
+    security(("cookieAuth" = [])),
+    params(IdParam),
+    responses((status = 200, body = Vec<RevisionResponse>)),
+    tag = "Lesson"
+)]
+pub async fn get_revisions(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>
+) -> Result<BaseResponse<Vec<RevisionResponse>>, AppError> {
+    let revisions = revision::service::history(&state.db, id, lang).await?;
+    Ok(
+        BaseResponse::success(
+            revisions.into_iter().map(revision::mapper::to_response).collect()
+        )
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/admin/lesson/{id}/revision/{revision}/rollback",
+    security(("cookieAuth" = [])),
+    params(LessonRevisionParam),
+    responses((status = 200, body = LessonResponse)),
+    tag = "Lesson"
+)]
+pub async fn rollback_lesson(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(param): ValidatedPath<LessonRevisionParam>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<LessonResponse>, AppError> {
+    let lesson = service::rollback(
+        &state.db,
+        &state.s3_client,
+        param.id,
+        param.revision,
+        claims.sub,
+        lang
+    ).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(lesson))
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/admin/lesson/{id}/translation",
+    security(("cookieAuth" = [])),
+    params(IdParam),
+    responses((status = 200, body = Vec<TranslationResponse>)),
+    tag = "Lesson"
+)]
+pub async fn get_lesson_translations(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>
+) -> Result<BaseResponse<Vec<TranslationResponse>>, AppError> {
+    let translations = translation::service::list(
+        &state.db,
+        TranslationKind::Lesson,
+        id,
+        lang
+    ).await?;
+    Ok(
+        BaseResponse::success(
+            translations.into_iter().map(translation::mapper::to_response).collect()
+        )
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/admin/lesson/{id}/translation/{locale}",
+    security(("cookieAuth" = [])),
+    params(TranslationParam),
+    request_body = TranslationRequest,
+    responses((status = 200, body = TranslationResponse)),
+    tag = "Lesson"
+)]
+pub async fn update_lesson_translation(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(param): ValidatedPath<TranslationParam>,
+    ValidatedJson(body): ValidatedJson<TranslationRequest>
+) -> Result<BaseResponse<TranslationResponse>, AppError> {
+    let translation = translation::service::upsert(
+        &state.db,
+        TranslationKind::Lesson,
+        param.id,
+        param.locale,
+        TranslationInput {
+            title: body.title,
+            description: body.description
+        },
+        lang
+    ).await?;
+    Ok(
+        BaseResponse::success(translation::mapper::to_response(translation))
+    )
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/admin/lesson/{id}/translation/{locale}",
+    security(("cookieAuth" = [])),
+    params(TranslationParam),
+    tag = "Lesson"
+)]
+pub async fn delete_lesson_translation(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(param): ValidatedPath<TranslationParam>
+) -> Result<BaseResponse<()>, AppError> {
+    translation::service::delete(
+        &state.db,
+        TranslationKind::Lesson,
+        param.id,
+        param.locale,
+        lang
+    ).await?;
+    Ok(BaseResponse::success(()))
+}
@@ -3,13 +3,21 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
-use crate::module::common::enums::UserProgressStatus;
+use crate::module::common::enums::{PublicationStatus, UserProgressStatus};
+use time::OffsetDateTime;
 use crate::module::common::paging::QueryOrder;
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct LessonResponse {
     #[serde(flatten)]
     pub lesson: CommonLessonResponse,
+    pub next_lesson_id: Option<i64>,
+    pub previous_lesson_id: Option<i64>,
+    pub publication_status: PublicationStatus,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub publish_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub published_at: Option<OffsetDateTime>
 }
 
 #[derive(Debug, Deserialize, Validate, ToSchema)]
@@ -20,7 +28,12 @@ pub struct LessonCURequest {
     pub title: String,
     pub description: Option<String>,
     pub cover_image_path: Option<String>,
-    pub audio_path: String
+    pub audio_path: String,
+    /// Defaults to `draft` on create; left unchanged on update when omitted
+    pub publication_status: Option<PublicationStatus>,
+    /// Required for `scheduled`
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub publish_at: Option<OffsetDateTime>
 }
 
 #[derive(Deserialize, ToSchema, IntoParams)]
@@ -38,4 +51,19 @@ pub struct LessonPaginationParams {
     pub author_id: Option<i64>,
     pub topic_id: Option<i64>,
     pub favourite: Option<bool>
-}
\ No newline at end of file
+}
+
+/// Just a schema for axum native multipart
+#[derive(Deserialize, ToSchema)]
+#[allow(unused)]
+pub struct TranscriptUploadForm {
+    /// WebVTT or SRT file
+    #[schema(format = Binary, content_media_type = "text/plain")]
+    file: String,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct LessonRevisionParam {
+    pub id: i64,
+    pub revision: i32
+}
@@ -4,6 +4,11 @@ use crate::module::common::lesson::mapper::to_response as lesson_to_response;
 
 pub fn to_response(entity: LessonWithAuthorTopic) -> LessonResponse {
     LessonResponse {
-        lesson: lesson_to_response(&entity)
+        lesson: lesson_to_response(&entity),
+        next_lesson_id: entity.next_lesson_id,
+        previous_lesson_id: entity.previous_lesson_id,
+        publication_status: entity.lesson.publication_status,
+        publish_at: entity.lesson.publish_at,
+        published_at: entity.lesson.published_at
     }
 }
\ No newline at end of file
@@ -1,7 +1,7 @@
 use axum::{Router, routing::get, middleware};
 use axum::routing::{delete, post, put};
 use crate::middleware::auth::admin_auth_middleware;
-use crate::module::admin::lesson::controller::{create_lesson, delete_lesson, get_lesson, page_lesson, update_lesson};
+use crate::module::admin::lesson::controller::{create_lesson, delete_lesson, delete_transcript, get_lesson, get_transcript, get_revisions, import_chapters, page_lesson, rollback_lesson, update_chapters, update_lesson, update_transcript, upload_transcript, get_lesson_translations, update_lesson_translation, delete_lesson_translation};
 use crate::state::AppState;
 
 pub fn routes() -> Router<AppState> {
@@ -11,5 +11,16 @@ pub fn routes() -> Router<AppState> {
         .route("/lesson/{id}", get(get_lesson))
         .route("/lesson/{id}", delete(delete_lesson))
         .route("/lesson", get(page_lesson))
+        .route("/lesson/{id}/transcript", get(get_transcript))
+        .route("/lesson/{id}/transcript", put(update_transcript))
+        .route("/lesson/{id}/transcript", delete(delete_transcript))
+        .route("/lesson/{id}/transcript/file", post(upload_transcript))
+        .route("/lesson/{id}/chapter", put(update_chapters))
+        .route("/lesson/{id}/chapter/import", post(import_chapters))
+        .route("/lesson/{id}/revision", get(get_revisions))
+        .route("/lesson/{id}/revision/{revision}/rollback", post(rollback_lesson))
+        .route("/lesson/{id}/translation", get(get_lesson_translations))
+        .route("/lesson/{id}/translation/{locale}", put(update_lesson_translation))
+        .route("/lesson/{id}/translation/{locale}", delete(delete_lesson_translation))
         .layer(middleware::from_fn(admin_auth_middleware))
 }
\ No newline at end of file
@@ -1,4 +1,7 @@
 pub mod auth;
 pub mod topic;
 pub mod lesson;
-pub mod author;
\ No newline at end of file
+pub mod author;
+pub mod file;
+pub mod trash;
+pub mod review;
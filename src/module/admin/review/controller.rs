@@ -0,0 +1,82 @@
+use crate::state::AppState;
+use axum::extract::State;
+use axum::Extension;
+use crate::error::AppError;
+use crate::extractor::accept_language::AcceptLanguage;
+use crate::module::admin::review::dto::{AdminReviewResponse, ReviewPaginationParams};
+use crate::module::admin::review::mapper;
+use crate::module::common::base::{BaseResponse, IdParam};
+use crate::module::common::paging::PagingResponse;
+use crate::module::common::review::service;
+use crate::utils::extractors::{ValidatedPath, ValidatedQuery};
+use crate::utils::jwt::Claims;
+
+#[utoipa::path(
+    get,
+    path = "/v1/admin/review",
+    security(("cookieAuth" = [])),
+    params(ReviewPaginationParams),
+    responses((status = 200, body = PagingResponse<AdminReviewResponse>)),
+    tag = "Review"
+)]
+pub async fn page_review(
+    State(state): State<AppState>,
+    ValidatedQuery(params): ValidatedQuery<ReviewPaginationParams>,
+) -> Result<BaseResponse<PagingResponse<AdminReviewResponse>>, AppError> {
+    let page = params.page.unwrap_or(1);
+    let limit = params.limit.unwrap_or(20);
+
+    let items = service::page(
+        &state.db,
+        page,
+        limit,
+        params.lesson_id,
+        params.hidden
+    ).await?;
+
+    Ok(
+        BaseResponse::success(
+            PagingResponse::new(
+                items.0.into_iter().map(mapper::to_response).collect(),
+                items.1,
+                page,
+                limit
+            )
+        )
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/admin/review/{id}/hide",
+    security(("cookieAuth" = [])),
+    params(IdParam),
+    responses((status = 200, body = AdminReviewResponse)),
+    tag = "Review"
+)]
+pub async fn hide_review(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<AdminReviewResponse>, AppError> {
+    let review = service::hide(&state.db, id, claims.sub, lang).await?;
+    Ok(BaseResponse::success(mapper::to_response(review)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/admin/review/{id}/unhide",
+    security(("cookieAuth" = [])),
+    params(IdParam),
+    responses((status = 200, body = AdminReviewResponse)),
+    tag = "Review"
+)]
+pub async fn unhide_review(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>
+) -> Result<BaseResponse<AdminReviewResponse>, AppError> {
+    let review = service::unhide(&state.db, id, lang).await?;
+    Ok(BaseResponse::success(mapper::to_response(review)))
+}
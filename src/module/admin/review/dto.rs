@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AdminReviewResponse {
+    pub id: i64,
+    pub lesson_id: i64,
+    pub lesson_title: String,
+    pub user_id: i64,
+    pub user_first_name: String,
+    pub rating: i16,
+    pub text: Option<String>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub hidden_at: Option<OffsetDateTime>,
+    /// Admin who hid it
+    pub hidden_by: Option<i64>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime
+}
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ReviewPaginationParams {
+    #[param(example = json!(1))]
+    pub page: Option<u32>,
+    #[param(example = json!(20))]
+    pub limit: Option<u32>,
+    pub lesson_id: Option<i64>,
+    /// Only hidden or only visible reviews
+    pub hidden: Option<bool>
+}
@@ -0,0 +1,18 @@
+use crate::db::review::entity::ReviewWithUserEntity;
+use crate::module::admin::review::dto::AdminReviewResponse;
+
+pub fn to_response(entity: ReviewWithUserEntity) -> AdminReviewResponse {
+    AdminReviewResponse {
+        id: entity.review.id,
+        lesson_id: entity.review.lesson_id,
+        lesson_title: entity.lesson_title,
+        user_id: entity.review.user_id,
+        user_first_name: entity.user_first_name,
+        rating: entity.review.rating,
+        text: entity.review.text,
+        hidden_at: entity.review.hidden_at,
+        hidden_by: entity.review.hidden_by,
+        created_at: entity.review.created_at,
+        updated_at: entity.review.updated_at
+    }
+}
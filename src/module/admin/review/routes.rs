@@ -0,0 +1,13 @@
+use crate::middleware::auth::admin_auth_middleware;
+use crate::state::AppState;
+use axum::routing::put;
+use axum::{middleware, routing::get, Router};
+use crate::module::admin::review::controller::{hide_review, page_review, unhide_review};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/review", get(page_review))
+        .route("/review/{id}/hide", put(hide_review))
+        .route("/review/{id}/unhide", put(unhide_review))
+        .layer(middleware::from_fn(admin_auth_middleware))
+}
@@ -3,11 +3,15 @@ use axum::extract::{State};
 use crate::error::AppError;
 use crate::extractor::accept_language::AcceptLanguage;
 use crate::module::admin::topic::{mapper};
-use crate::module::admin::topic::dto::{TopicCURequest, PaginationParams, TopicResponse};
+use crate::module::admin::topic::dto::{LessonOrderRequest, TopicCURequest, PaginationParams, TopicResponse};
 use crate::module::common::base::{BaseResponse, IdParam};
 use crate::module::common::paging::PagingResponse;
 use crate::module::common::topic::service;
 use crate::utils::extractors::{ValidatedJson, ValidatedPath, ValidatedQuery};
+use crate::db::translation::entity::TranslationInput;
+use crate::module::common::enums::TranslationKind;
+use crate::module::common::translation;
+use crate::module::common::translation::dto::{TranslationParam, TranslationRequest, TranslationResponse};
 
 #[utoipa::path(
     post,
@@ -29,6 +33,8 @@ pub async fn create_topic(
         body.title,
         body.description,
         body.cover_image_path,
+        body.publication_status,
+        body.publish_at,
         lang
     ).await?;
 
@@ -61,6 +67,8 @@ pub async fn update_topic(
         body.title,
         body.description,
         body.cover_image_path,
+        body.publication_status,
+        body.publish_at,
         lang
     ).await?;
     
@@ -151,4 +159,106 @@ pub async fn delete_topic(
         lang
     ).await?;
     Ok(BaseResponse::success(()))
-}
\ No newline at end of file
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/admin/topic/{id}/lesson/order",
+    security(("cookieAuth" = [])),
+    params(IdParam),
+    request_body = LessonOrderRequest,
+    tag = "Topic"
+)]
+pub async fn reorder_lessons(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    ValidatedJson(body): ValidatedJson<LessonOrderRequest>
+) -> Result<BaseResponse<()>, AppError> {
+    service::reorder_lessons(
+        &state.db,
+        id,
+        body.lesson_ids,
+        lang
+    ).await?;
+    Ok(BaseResponse::success(()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/admin/topic/{id}/translation",
+    security(("cookieAuth" = [])),
+    params(IdParam),
+    responses((status = 200, body = Vec<TranslationResponse>)),
+    tag = "Topic"
+)]
+pub async fn get_topic_translations(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>
+) -> Result<BaseResponse<Vec<TranslationResponse>>, AppError> {
+    let translations = translation::service::list(
+        &state.db,
+        TranslationKind::Topic,
+        id,
+        lang
+    ).await?;
+    Ok(
+        BaseResponse::success(
+            translations.into_iter().map(translation::mapper::to_response).collect()
+        )
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/admin/topic/{id}/translation/{locale}",
+    security(("cookieAuth" = [])),
+    params(TranslationParam),
+    request_body = TranslationRequest,
+    responses((status = 200, body = TranslationResponse)),
+    tag = "Topic"
+)]
+pub async fn update_topic_translation(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(param): ValidatedPath<TranslationParam>,
+    ValidatedJson(body): ValidatedJson<TranslationRequest>
+) -> Result<BaseResponse<TranslationResponse>, AppError> {
+    let translation = translation::service::upsert(
+        &state.db,
+        TranslationKind::Topic,
+        param.id,
+        param.locale,
+        TranslationInput {
+            title: body.title,
+            description: body.description
+        },
+        lang
+    ).await?;
+    Ok(
+        BaseResponse::success(translation::mapper::to_response(translation))
+    )
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/admin/topic/{id}/translation/{locale}",
+    security(("cookieAuth" = [])),
+    params(TranslationParam),
+    tag = "Topic"
+)]
+pub async fn delete_topic_translation(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(param): ValidatedPath<TranslationParam>
+) -> Result<BaseResponse<()>, AppError> {
+    translation::service::delete(
+        &state.db,
+        TranslationKind::Topic,
+        param.id,
+        param.locale,
+        lang
+    ).await?;
+    Ok(BaseResponse::success(()))
+}
@@ -3,7 +3,8 @@ use serde_json::json;
 use utoipa::{IntoParams, ToSchema};
 use validator::{Validate};
 use crate::module::common::author::dto::CommonAuthorResponse;
-use crate::module::common::enums::UserProgressStatus;
+use crate::module::common::enums::{PublicationStatus, UserProgressStatus};
+use time::OffsetDateTime;
 use crate::module::common::paging::QueryOrder;
 use crate::module::common::topic::dto::{CommonTopicResponse, QuerySort};
 
@@ -11,6 +12,11 @@ use crate::module::common::topic::dto::{CommonTopicResponse, QuerySort};
 pub struct TopicResponse {
     pub topic: CommonTopicResponse,
     pub author: CommonAuthorResponse,
+    pub publication_status: PublicationStatus,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub publish_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub published_at: Option<OffsetDateTime>
 }
 
 #[derive(Debug, Deserialize, Validate, ToSchema)]
@@ -19,7 +25,12 @@ pub struct TopicCURequest {
     #[validate(length(min = 3))]
     pub title: String,
     pub description: Option<String>,
-    pub cover_image_path: Option<String>
+    pub cover_image_path: Option<String>,
+    /// Defaults to `draft` on create; left unchanged on update when omitted
+    pub publication_status: Option<PublicationStatus>,
+    /// Required for `scheduled`
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub publish_at: Option<OffsetDateTime>
 }
 
 #[derive(Deserialize, ToSchema, IntoParams)]
@@ -35,4 +46,10 @@ pub struct PaginationParams {
     pub author_id: Option<i64>,
     pub status: Option<UserProgressStatus>,
     pub sort: Option<QuerySort>
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct LessonOrderRequest {
+    /// Every lesson of the topic, first to last
+    pub lesson_ids: Vec<i64>
+}
@@ -5,6 +5,9 @@ use crate::module::common::topic::mapper::to_response as topic_to_response;
 
 pub fn to_response(entity: TopicWithAuthor) -> TopicResponse {
     let author_id = entity.topic.author_id;
+    let publication_status = entity.topic.publication_status;
+    let publish_at = entity.topic.publish_at;
+    let published_at = entity.topic.published_at;
     TopicResponse {
         topic: topic_to_response(entity.topic),
         author: CommonAuthorResponse{
@@ -13,6 +16,9 @@ pub fn to_response(entity: TopicWithAuthor) -> TopicResponse {
             avatar_path: entity.author_avatar_path,
             created_at: entity.author_created_at,
             lesson_count: entity.author_lesson_count
-        }
+        },
+        publication_status,
+        publish_at,
+        published_at
     }
 }
\ No newline at end of file
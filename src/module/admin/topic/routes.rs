@@ -1,7 +1,7 @@
 use axum::{Router, routing::get, middleware};
 use axum::routing::{delete, post, put};
 use crate::middleware::auth::admin_auth_middleware;
-use crate::module::admin::topic::controller::{create_topic, delete_topic, get_topic, page_topic, update_topic};
+use crate::module::admin::topic::controller::{create_topic, delete_topic, get_topic, page_topic, reorder_lessons, update_topic, get_topic_translations, update_topic_translation, delete_topic_translation};
 use crate::state::AppState;
 
 pub fn routes() -> Router<AppState> {
@@ -11,5 +11,9 @@ pub fn routes() -> Router<AppState> {
         .route("/topic/{id}", get(get_topic))
         .route("/topic/{id}", delete(delete_topic))
         .route("/topic", get(page_topic))
+        .route("/topic/{id}/lesson/order", put(reorder_lessons))
+        .route("/topic/{id}/translation", get(get_topic_translations))
+        .route("/topic/{id}/translation/{locale}", put(update_topic_translation))
+        .route("/topic/{id}/translation/{locale}", delete(delete_topic_translation))
         .layer(middleware::from_fn(admin_auth_middleware))
 }
\ No newline at end of file
@@ -0,0 +1,65 @@
+use crate::state::AppState;
+use axum::extract::State;
+use crate::error::AppError;
+use crate::extractor::accept_language::AcceptLanguage;
+use crate::module::admin::trash::dto::{TrashItemParam, TrashItemResponse, TrashPaginationParams};
+use crate::module::admin::trash::mapper;
+use crate::module::common::base::BaseResponse;
+use crate::module::common::paging::PagingResponse;
+use crate::module::common::trash::service;
+use crate::utils::extractors::{ValidatedPath, ValidatedQuery};
+
+#[utoipa::path(
+    get,
+    path = "/v1/admin/trash",
+    security(("cookieAuth" = [])),
+    params(TrashPaginationParams),
+    responses((status = 200, body = PagingResponse<TrashItemResponse>)),
+    tag = "Trash"
+)]
+pub async fn page_trash(
+    State(state): State<AppState>,
+    ValidatedQuery(params): ValidatedQuery<TrashPaginationParams>,
+) -> Result<BaseResponse<PagingResponse<TrashItemResponse>>, AppError> {
+    let page = params.page.unwrap_or(1);
+    let limit = params.limit.unwrap_or(20);
+
+    let items = service::page(
+        &state.db,
+        page,
+        limit,
+        params.kind
+    ).await?;
+
+    Ok(
+        BaseResponse::success(
+            PagingResponse::new(
+                items.0.into_iter().map(mapper::to_response).collect(),
+                items.1,
+                page,
+                limit
+            )
+        )
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/admin/trash/{kind}/{id}/restore",
+    security(("cookieAuth" = [])),
+    params(TrashItemParam),
+    tag = "Trash"
+)]
+pub async fn restore_trash_item(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(param): ValidatedPath<TrashItemParam>
+) -> Result<BaseResponse<()>, AppError> {
+    service::restore(
+        &state.db,
+        param.kind,
+        param.id,
+        lang
+    ).await?;
+    Ok(BaseResponse::success(()))
+}
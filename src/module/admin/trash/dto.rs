@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::{IntoParams, ToSchema};
+use crate::module::common::enums::TrashKind;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrashItemResponse {
+    pub kind: TrashKind,
+    pub id: i64,
+    /// Author name, topic title or lesson title
+    pub title: String,
+    pub image_path: Option<String>,
+    pub author_id: i64,
+    pub topic_id: Option<i64>,
+    /// Its author or topic is trashed too and has to be restored first
+    pub parent_deleted: bool,
+    #[serde(with = "time::serde::rfc3339")]
+    pub deleted_at: OffsetDateTime,
+    /// When the item is permanently deleted
+    #[serde(with = "time::serde::rfc3339")]
+    pub purge_at: OffsetDateTime
+}
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TrashPaginationParams {
+    #[param(example = json!(1))]
+    pub page: Option<u32>,
+    #[param(example = json!(20))]
+    pub limit: Option<u32>,
+    pub kind: Option<TrashKind>
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct TrashItemParam {
+    pub kind: TrashKind,
+    pub id: i64
+}
@@ -0,0 +1,22 @@
+use crate::db::trash::entity::TrashItemEntity;
+use crate::module::admin::trash::dto::TrashItemResponse;
+use crate::module::common::enums::TrashKind;
+use crate::module::common::trash;
+
+pub fn to_response(entity: TrashItemEntity) -> TrashItemResponse {
+    TrashItemResponse {
+        kind: match entity.kind.as_str() {
+            "author" => TrashKind::Author,
+            "topic" => TrashKind::Topic,
+            _ => TrashKind::Lesson
+        },
+        id: entity.id,
+        title: entity.title,
+        image_path: entity.image_path,
+        author_id: entity.author_id,
+        topic_id: entity.topic_id,
+        parent_deleted: entity.parent_deleted,
+        purge_at: trash::service::purge_at(entity.deleted_at),
+        deleted_at: entity.deleted_at
+    }
+}
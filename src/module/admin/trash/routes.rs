@@ -0,0 +1,12 @@
+use crate::middleware::auth::admin_auth_middleware;
+use crate::state::AppState;
+use axum::routing::post;
+use axum::{middleware, routing::get, Router};
+use crate::module::admin::trash::controller::{page_trash, restore_trash_item};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/trash", get(page_trash))
+        .route("/trash/{kind}/{id}/restore", post(restore_trash_item))
+        .layer(middleware::from_fn(admin_auth_middleware))
+}
@@ -0,0 +1,126 @@
+use crate::db;
+use crate::error::AppError;
+use crate::module::common::enums::Achievement;
+use crate::string_keys::strings;
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Metric {
+    LessonsCompleted,
+    TopicsCompleted,
+    /// Longest streak of active days
+    StreakDays,
+    SnipsCreated,
+    AuthorsListened,
+    ListenedHours
+}
+
+pub struct AchievementRule {
+    pub achievement: Achievement,
+    pub metric: Metric,
+    pub target: i64,
+    /// Fluent keys of the badge texts
+    pub title: &'static str,
+    pub description: &'static str
+}
+
+pub const RULES: &[AchievementRule] = &[
+    AchievementRule {
+        achievement: Achievement::FirstLesson,
+        metric: Metric::LessonsCompleted,
+        target: 1,
+        title: strings::ACHIEVEMENT_FIRST_LESSON_TITLE,
+        description: strings::ACHIEVEMENT_FIRST_LESSON_DESCRIPTION
+    },
+    AchievementRule {
+        achievement: Achievement::FirstTopic,
+        metric: Metric::TopicsCompleted,
+        target: 1,
+        title: strings::ACHIEVEMENT_FIRST_TOPIC_TITLE,
+        description: strings::ACHIEVEMENT_FIRST_TOPIC_DESCRIPTION
+    },
+    AchievementRule {
+        achievement: Achievement::WeekStreak,
+        metric: Metric::StreakDays,
+        target: 7,
+        title: strings::ACHIEVEMENT_WEEK_STREAK_TITLE,
+        description: strings::ACHIEVEMENT_WEEK_STREAK_DESCRIPTION
+    },
+    AchievementRule {
+        achievement: Achievement::MonthStreak,
+        metric: Metric::StreakDays,
+        target: 30,
+        title: strings::ACHIEVEMENT_MONTH_STREAK_TITLE,
+        description: strings::ACHIEVEMENT_MONTH_STREAK_DESCRIPTION
+    },
+    AchievementRule {
+        achievement: Achievement::TenSnips,
+        metric: Metric::SnipsCreated,
+        target: 10,
+        title: strings::ACHIEVEMENT_TEN_SNIPS_TITLE,
+        description: strings::ACHIEVEMENT_TEN_SNIPS_DESCRIPTION
+    },
+    AchievementRule {
+        achievement: Achievement::HundredSnips,
+        metric: Metric::SnipsCreated,
+        target: 100,
+        title: strings::ACHIEVEMENT_HUNDRED_SNIPS_TITLE,
+        description: strings::ACHIEVEMENT_HUNDRED_SNIPS_DESCRIPTION
+    },
+    AchievementRule {
+        achievement: Achievement::TenAuthors,
+        metric: Metric::AuthorsListened,
+        target: 10,
+        title: strings::ACHIEVEMENT_TEN_AUTHORS_TITLE,
+        description: strings::ACHIEVEMENT_TEN_AUTHORS_DESCRIPTION
+    },
+    AchievementRule {
+        achievement: Achievement::TenHours,
+        metric: Metric::ListenedHours,
+        target: 10,
+        title: strings::ACHIEVEMENT_TEN_HOURS_TITLE,
+        description: strings::ACHIEVEMENT_TEN_HOURS_DESCRIPTION
+    }
+];
+
+pub struct AchievementStatus {
+    pub rule: &'static AchievementRule,
+    /// Current value of the rule's metric, capped at its target
+    pub progress: i64,
+    pub earned_at: Option<OffsetDateTime>
+}
+
+/// Checks every rule against the user's rollup and awards the ones reached.
+/// Earned achievements are kept even if the metric later drops, e.g. after
+/// a snip is deleted.
+pub async fn evaluate(db: &PgPool, user_id: i64) -> Result<Vec<AchievementStatus>, AppError> {
+    let summary = db::stat::repo::summary(db, user_id).await?;
+    let streaks = db::stat::repo::streaks(db, user_id, summary.today).await?;
+    let topics_completed = db::stat::repo::topics_completed(db, user_id).await?;
+    let authors_listened = db::achievement::repo::authors_listened(db, user_id).await?;
+    let earned = db::achievement::repo::earned(db, user_id).await?;
+
+    let value = |metric: Metric| match metric {
+        Metric::LessonsCompleted => summary.lessons_completed,
+        Metric::TopicsCompleted => topics_completed,
+        Metric::StreakDays => streaks.longest,
+        Metric::SnipsCreated => summary.snips_created,
+        Metric::AuthorsListened => authors_listened,
+        Metric::ListenedHours => summary.listened_ms / 3_600_000
+    };
+
+    let mut result = Vec::with_capacity(RULES.len());
+    for rule in RULES {
+        let progress = value(rule.metric);
+        let mut earned_at = earned.iter()
+            .find(|e| e.achievement == rule.achievement)
+            .map(|e| e.earned_at);
+        if earned_at.is_none() && progress >= rule.target
+            && let Some(entity) = db::achievement::repo::award(db, user_id, rule.achievement).await? {
+            earned_at = Some(entity.earned_at);
+        }
+        result.push(AchievementStatus { rule, progress: progress.min(rule.target), earned_at });
+    }
+    Ok(result)
+}
@@ -1,6 +1,7 @@
 use crate::db::author::entity::{AuthorEntity, AuthorInput};
 use crate::error::author::AuthorError;
 use crate::error::AppError;
+use crate::module::common::file;
 use crate::{db, utils};
 use fluent_templates::LanguageIdentifier;
 use sqlx::PgPool;
@@ -11,7 +12,12 @@ pub async fn create(
     db: &PgPool,
     name: String,
     avatar_path: Option<String>,
+    lang: LanguageIdentifier
 ) -> Result<AuthorEntity, AppError> {
+    if let Some(avatar_path) = &avatar_path {
+        file::service::ensure_completed(db, avatar_path, lang).await?;
+    }
+
     let author = AuthorInput {
         name,
         avatar_path
@@ -31,6 +37,13 @@ pub async fn update(
     avatar_path: Option<String>,
     lang: LanguageIdentifier
 ) -> Result<AuthorEntity, AppError> {
+    let old_author = db::author::repo::get_by_id(db, id).await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
+    if let Some(avatar_path) = &avatar_path
+        && old_author.avatar_path.as_ref() != Some(avatar_path) {
+        file::service::ensure_completed(db, avatar_path, lang.clone()).await?;
+    }
+
     let author = AuthorInput {
         name,
         avatar_path
@@ -121,7 +134,8 @@ pub async fn delete(
     ).await?;
 
     if let Some(author) = author {
-        if author.lesson_count != 0 {
+        // `lesson_count` only counts published lessons, drafts block deletion too
+        if db::lesson::repo::exists_by_author(&mut tx, author.id).await? {
             return Err(AuthorError::AuthorHasLesson(lang).into())
         }
 
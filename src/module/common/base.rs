@@ -4,6 +4,8 @@ use axum::response::{IntoResponse, Response};
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+use crate::module::common::enums::UploadStatus;
 
 
 #[derive(Serialize)]
@@ -32,6 +34,11 @@ pub struct ClientSnipIdParam {
     pub client_snip_id: String
 }
 
+#[derive(Deserialize, IntoParams)]
+pub struct ShareTokenParam {
+    pub token: String
+}
+
 #[derive(Deserialize, IntoParams)]
 pub struct TopicIdParam {
     pub topic_id: i64
@@ -46,7 +53,9 @@ pub struct LessonIdParam {
 pub struct UploadUrlParam {
     pub file_name: String,
     pub file_length: i64,
-    pub mime_type: String
+    pub mime_type: String,
+    /// Base64 encoded SHA-256 of the file, enforced by the bucket on upload
+    pub checksum: Option<String>
 }
 
 #[derive(Serialize, ToSchema)]
@@ -55,6 +64,23 @@ pub struct UploadUrlResponse {
     pub file_key: String
 }
 
+#[derive(Deserialize, ToSchema, Validate)]
+pub struct UploadCompleteRequest {
+    #[validate(length(min = 1))]
+    pub file_key: String
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct UploadSessionResponse {
+    pub file_key: String,
+    pub file_size: i64,
+    pub mime_type: Option<String>,
+    pub status: UploadStatus,
+    pub uploaded_by: Option<i64>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub completed_at: Option<OffsetDateTime>
+}
+
 #[derive(Deserialize, ToSchema, IntoParams)]
 #[into_params(parameter_in = Query)]
 pub struct DeletedParams {
@@ -69,6 +95,13 @@ pub struct DeletedResponse {
     pub deleted_at: OffsetDateTime
 }
 
+#[derive(Serialize, ToSchema)]
+pub struct PublishedResponse {
+    pub id: i64,
+    #[serde(with = "time::serde::rfc3339")]
+    pub published_at: OffsetDateTime
+}
+
 impl<T> IntoResponse for BaseResponse<T>
 where T: Serialize + Send,
 {
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ChapterItemRequest {
+    #[validate(length(min = 1, max = 200))]
+    pub title: String,
+    #[validate(range(min = 0))]
+    pub start_ms: i64,
+    #[validate(length(max = 2000))]
+    pub description: Option<String>
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ChapterRequest {
+    #[validate(nested)]
+    pub chapters: Vec<ChapterItemRequest>
+}
+
+#[derive(Debug, Deserialize, PartialEq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChapterSource {
+    /// CHAP frames of the lesson audio
+    Id3,
+    /// One chapter per line, e.g. `00:00 Intro` or `1:02:30 Summary`
+    Text
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ChapterImportRequest {
+    pub source: ChapterSource,
+    /// Required when `source` is `text`
+    #[validate(length(min = 1))]
+    pub text: Option<String>
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ChapterResponse {
+    pub title: String,
+    pub start_ms: i64,
+    /// Start of the next chapter, or the lesson duration for the last one
+    pub end_ms: i64,
+    pub description: Option<String>
+}
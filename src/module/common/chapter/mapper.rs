@@ -0,0 +1,16 @@
+use crate::db::chapter::entity::ChapterEntity;
+use crate::module::common::chapter::dto::ChapterResponse;
+
+pub fn to_responses(chapters: &[ChapterEntity], duration: i64) -> Vec<ChapterResponse> {
+    chapters.iter()
+        .enumerate()
+        .map(|(index, chapter)| ChapterResponse {
+            title: chapter.title.to_owned(),
+            start_ms: chapter.start_ms,
+            end_ms: chapters.get(index + 1)
+                .map(|next| next.start_ms)
+                .unwrap_or(duration),
+            description: chapter.description.to_owned()
+        })
+        .collect()
+}
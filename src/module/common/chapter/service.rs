@@ -0,0 +1,195 @@
+use crate::db;
+use crate::db::chapter::entity::ChapterInput;
+use crate::db::lesson::entity::LessonWithAuthorTopic;
+use crate::db::snip::entity::SnipEntityWithLesson;
+use crate::error::lesson::LessonError;
+use crate::error::AppError;
+use crate::module::common::file;
+use crate::string_keys::strings;
+use crate::utils::t_args;
+use aws_sdk_s3 as s3;
+use fluent_templates::LanguageIdentifier;
+use fluent_templates::fluent_bundle::FluentValue;
+use sqlx::PgPool;
+use std::collections::HashMap;
+
+/// Parses `mm:ss` or `hh:mm:ss`, optionally with a `.mmm` fraction. A bare
+/// number is not a timestamp, so description lines starting with one are kept.
+fn parse_timestamp(value: &str) -> Option<i64> {
+    let (clock, millis) = match value.split_once('.') {
+        Some((clock, fraction)) if !fraction.is_empty() && fraction.len() <= 3
+            && fraction.chars().all(|c| c.is_ascii_digit()) => {
+            (clock, format!("{fraction:0<3}").parse::<i64>().ok()?)
+        }
+        Some(_) => return None,
+        None => (value, 0)
+    };
+
+    let parts: Vec<&str> = clock.split(':').collect();
+    if !(2..=3).contains(&parts.len())
+        || parts.iter().any(|part| part.is_empty() || !part.chars().all(|c| c.is_ascii_digit())) {
+        return None;
+    }
+    let seconds = parts.iter()
+        .try_fold(0i64, |total, part| total.checked_mul(60)?.checked_add(part.parse::<i64>().ok()?))?;
+    seconds.checked_mul(1000)?.checked_add(millis)
+}
+
+/// Parses a pasted chapter list: one `00:00 Title` line per chapter, where the
+/// timestamp may be wrapped in brackets and followed by `-`, `–` or `|`.
+/// Other non-empty lines become the description of the chapter above. Returns
+/// the 1-based number of the offending line on error.
+fn parse_text(content: &str) -> Result<Vec<ChapterInput>, usize> {
+    let mut chapters: Vec<ChapterInput> = vec![];
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let (head, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let timestamp = head.trim_matches(|c| matches!(c, '[' | ']' | '(' | ')'));
+        match parse_timestamp(timestamp) {
+            Some(start_ms) => chapters.push(ChapterInput {
+                title: rest.trim_start_matches(|c: char| c.is_whitespace() || matches!(c, '-' | '–' | '—' | '|'))
+                    .trim()
+                    .to_string(),
+                start_ms,
+                description: None
+            }),
+            None => match chapters.last_mut() {
+                Some(chapter) => {
+                    let description = chapter.description.get_or_insert_with(String::new);
+                    if !description.is_empty() {
+                        description.push('\n');
+                    }
+                    description.push_str(line);
+                }
+                None => return Err(index + 1)
+            }
+        }
+    }
+
+    Ok(chapters)
+}
+
+/// Replaces the chapters of a lesson. Chapters need a title and must start
+/// inside the lesson, each strictly after the previous one.
+pub async fn replace(
+    db: &PgPool,
+    lesson_id: i64,
+    chapters: Vec<ChapterInput>,
+    lang: LanguageIdentifier
+) -> Result<(), AppError> {
+    let lesson = db::lesson::repo::get_by_id(db, lesson_id)
+        .await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
+
+    let mut previous_start = None;
+    for (index, chapter) in chapters.iter().enumerate() {
+        let valid = !chapter.title.trim().is_empty()
+            && chapter.start_ms >= 0
+            && previous_start.is_none_or(|start| chapter.start_ms > start)
+            && (lesson.duration == 0 || chapter.start_ms < lesson.duration);
+        if !valid {
+            return Err(LessonError::InvalidChapter(lang, index + 1).into());
+        }
+        previous_start = Some(chapter.start_ms);
+    }
+
+    let mut tx = db.begin().await?;
+    db::chapter::repo::delete_by_lesson_id(&mut tx, lesson_id).await?;
+    db::chapter::repo::insert_all(&mut tx, lesson_id, chapters).await?;
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Replaces the chapters of a lesson with a pasted `00:00 Title` list.
+pub async fn import_text(
+    db: &PgPool,
+    lesson_id: i64,
+    content: &str,
+    lang: LanguageIdentifier
+) -> Result<(), AppError> {
+    let chapters = parse_text(content)
+        .map_err(|line| LessonError::InvalidChapter(lang.clone(), line))?;
+    replace(db, lesson_id, chapters, lang).await
+}
+
+/// Replaces the chapters of a lesson with the ID3 CHAP frames of its audio.
+/// Untitled frames are numbered, in `lang`.
+pub async fn import_id3(
+    db: &PgPool,
+    s3_client: &s3::Client,
+    lesson_id: i64,
+    lang: LanguageIdentifier
+) -> Result<(), AppError> {
+    let lesson = db::lesson::repo::get_by_id(db, lesson_id)
+        .await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
+
+    let mut frames = file::service::read_chapters(s3_client, &lesson.audio_path, lang.clone()).await?;
+    frames.sort_by_key(|frame| frame.start_ms);
+
+    let chapters = frames.into_iter()
+        .enumerate()
+        .map(|(index, frame)| ChapterInput {
+            title: frame.title
+                .filter(|title| !title.trim().is_empty())
+                .unwrap_or_else(|| t_args(
+                    &lang,
+                    strings::CHAPTER_DEFAULT_TITLE,
+                    vec![("number", FluentValue::from(index + 1))]
+                )),
+            start_ms: frame.start_ms,
+            description: None
+        })
+        .collect();
+
+    replace(db, lesson_id, chapters, lang).await
+}
+
+/// Loads the chapters of all `lessons` with a single query.
+pub async fn attach(
+    db: &PgPool,
+    lessons: &mut [LessonWithAuthorTopic]
+) -> Result<(), AppError> {
+    if lessons.is_empty() {
+        return Ok(());
+    }
+
+    let ids: Vec<i64> = lessons.iter().map(|lesson| lesson.lesson.id).collect();
+    let mut chapters: HashMap<i64, Vec<_>> = HashMap::new();
+    for chapter in db::chapter::repo::get_by_lesson_ids(db, &ids).await? {
+        chapters.entry(chapter.lesson_id).or_default().push(chapter);
+    }
+
+    for lesson in lessons.iter_mut() {
+        lesson.chapters = chapters.remove(&lesson.lesson.id).unwrap_or_default();
+    }
+    Ok(())
+}
+
+/// Loads the chapters of the lessons of all `snips` with a single query.
+pub async fn attach_snips(
+    db: &PgPool,
+    snips: &mut [&mut SnipEntityWithLesson]
+) -> Result<(), AppError> {
+    if snips.is_empty() {
+        return Ok(());
+    }
+
+    let ids: Vec<i64> = snips.iter().map(|snip| snip.snip.lesson_id).collect();
+    let mut chapters: HashMap<i64, Vec<_>> = HashMap::new();
+    for chapter in db::chapter::repo::get_by_lesson_ids(db, &ids).await? {
+        chapters.entry(chapter.lesson_id).or_default().push(chapter);
+    }
+
+    for snip in snips.iter_mut() {
+        // several snips may share a lesson
+        snip.chapters = chapters.get(&snip.snip.lesson_id).cloned().unwrap_or_default();
+    }
+    Ok(())
+}
@@ -0,0 +1,177 @@
+use crate::db::asset::entity::AssetInput;
+use crate::db::snip::entity::SnipEntityWithLesson;
+use crate::error::AppError;
+use crate::module::common::enums::{AssetStorage, UploadStatus};
+use crate::module::common::file;
+use crate::string_keys::strings;
+use crate::utils::{t, CONFIG};
+use anyhow::Result;
+use aws_sdk_s3 as s3;
+use aws_sdk_s3::presigning::PresigningConfig;
+use aws_sdk_s3::primitives::ByteStream;
+use ffmpeg_light::command::{FfmpegBinaryPaths, FfmpegCommand};
+use fluent_templates::LanguageIdentifier;
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use std::path::Path;
+use std::time::Duration;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+const CLIP_FADE_SECS: f64 = 0.5;
+
+/// Renders `[start_ms, end_ms)` of the lesson audio to an MP3 with fades. With
+/// `watermark` the configured spoken intro is prepended and the cover image is
+/// embedded as artwork.
+async fn render(
+    s3_client: &s3::Client,
+    entity: &SnipEntityWithLesson,
+    start_ms: i64,
+    end_ms: i64,
+    watermark: bool,
+) -> Result<Vec<u8>> {
+    let expires_in = PresigningConfig::expires_in(Duration::from_mins(10))?;
+    let source = s3_client
+        .get_object()
+        .bucket(CONFIG.r2_bucket_name.clone())
+        .key(entity.lesson_audio_path.clone())
+        .presigned(expires_in)
+        .await?
+        .uri()
+        .to_string();
+
+    let duration = (end_ms - start_ms) as f64 / 1000.0;
+    let fade = CLIP_FADE_SECS.min(duration / 2.0);
+    let intro = CONFIG.clip_intro_path.clone().filter(|_| watermark);
+    let cover = entity.lesson_cover_image_path.clone()
+        .or(entity.topic_cover_image_path.clone())
+        .map(|path| format!("uploads/{}", path))
+        .filter(|path| watermark && Path::new(path).exists());
+    let output = std::env::temp_dir().join(format!("{}.mp3", Uuid::new_v4()));
+
+    let paths = FfmpegBinaryPaths::auto()?;
+    let mut command = FfmpegCommand::new(paths.ffmpeg());
+    command
+        .args(&["-y", "-hide_banner", "-loglevel", "error"])
+        .args(&["-ss", &format!("{:.3}", start_ms as f64 / 1000.0), "-t", &format!("{:.3}", duration)])
+        .args(&["-i", &source]);
+    if let Some(intro) = &intro {
+        command.args(&["-i", intro]);
+    }
+    if let Some(cover) = &cover {
+        command.args(&["-i", cover]);
+    }
+
+    let clip_label = if intro.is_some() { "[clip]" } else { "[out]" };
+    let mut filter = format!(
+        "[0:a]afade=t=in:d={fade:.3},afade=t=out:st={:.3}:d={fade:.3},aresample=44100,aformat=channel_layouts=stereo{clip_label}",
+        duration - fade
+    );
+    if intro.is_some() {
+        filter.push_str(";[1:a]aresample=44100,aformat=channel_layouts=stereo[intro];[intro][clip]concat=n=2:v=0:a=1[out]");
+    }
+    command.args(&["-filter_complex", &filter, "-map", "[out]"]);
+    if cover.is_some() {
+        let cover_input = if intro.is_some() { 2 } else { 1 };
+        command.args(&["-map", &format!("{}:v", cover_input), "-c:v", "copy", "-disposition:v", "attached_pic"]);
+    }
+
+    command
+        .args(&["-c:a", "libmp3lame", "-b:a", "128k", "-id3v2_version", "3"])
+        .args(&["-metadata", &format!("title={}", entity.lesson_title)])
+        .args(&["-metadata", &format!("artist={}", entity.author_name)]);
+    if let Some(album) = &entity.topic_title {
+        command.args(&["-metadata", &format!("album={}", album)]);
+    }
+    command.arg(&output);
+
+    let result = tokio::task::spawn_blocking(move || command.run()).await?;
+    let bytes = match result {
+        Ok(()) => tokio::fs::read(&output).await,
+        Err(err) => {
+            let _ = tokio::fs::remove_file(&output).await;
+            return Err(err.into())
+        }
+    };
+    let _ = tokio::fs::remove_file(&output).await;
+
+    Ok(bytes?)
+}
+
+/// Renders the snip range (clamped to the lesson duration) as a standalone MP3.
+/// Clips are cached in the bucket under
+/// `clip/<sha256(audio:start:end:watermark)>.mp3` and tracked as assets, so an
+/// unused clip is collected by the GC. Returns a presigned URL and its expiry.
+pub async fn export(
+    db: &PgPool,
+    s3_client: &s3::Client,
+    entity: &SnipEntityWithLesson,
+    watermark: bool,
+    uploaded_by: Option<i64>,
+    lang: LanguageIdentifier
+) -> std::result::Result<(String, OffsetDateTime), AppError> {
+    let start_ms = entity.snip.start_ms;
+    let end_ms = if entity.lesson_duration > 0 {
+        entity.snip.end_ms.min(entity.lesson_duration)
+    } else {
+        entity.snip.end_ms
+    };
+    if end_ms <= start_ms {
+        return Err(AppError::BadRequest{lang: lang.clone(), message: t(&lang, strings::BAD_REQUEST)});
+    }
+
+    let mut hasher = Sha256::new();
+    hasher.update(format!("{}:{}:{}:{}", entity.lesson_audio_path, start_ms, end_ms, watermark));
+    let clip_path = format!("clip/{:x}.mp3", hasher.finalize());
+
+    let cached = s3_client
+        .head_object()
+        .bucket(CONFIG.r2_bucket_name.clone())
+        .key(clip_path.clone())
+        .send()
+        .await
+        .ok()
+        .and_then(|head| head.content_length());
+    let file_size = match cached {
+        Some(size) => size,
+        None => {
+            let bytes = render(s3_client, entity, start_ms, end_ms, watermark).await?;
+            let size = bytes.len() as i64;
+            s3_client
+                .put_object()
+                .bucket(CONFIG.r2_bucket_name.clone())
+                .key(clip_path.clone())
+                .content_type("audio/mpeg")
+                .body(ByteStream::from(bytes))
+                .send()
+                .await
+                .map_err(anyhow::Error::from)?;
+            size
+        }
+    };
+
+    // re-recording resets orphaned_at, so a clip that keeps being exported stays cached
+    file::service::record_upload(
+        db,
+        AssetInput {
+            path: clip_path.clone(),
+            storage: AssetStorage::Bucket,
+            file_size,
+            mime_type: Some("audio/mpeg".to_string()),
+            checksum: None,
+            status: UploadStatus::Completed,
+            uploaded_by
+        }
+    ).await?;
+
+    let ttl = Duration::from_mins(CONFIG.clip_url_ttl_minutes);
+    let presigned_request = s3_client
+        .get_object()
+        .bucket(CONFIG.r2_bucket_name.clone())
+        .key(clip_path)
+        .presigned(PresigningConfig::expires_in(ttl).map_err(anyhow::Error::from)?)
+        .await
+        .map_err(anyhow::Error::from)?;
+
+    Ok((presigned_request.uri().to_string(), OffsetDateTime::now_utc() + ttl))
+}
@@ -8,4 +8,98 @@ pub enum UserProgressStatus {
     NotStarted,
     InProgress,
     Completed
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "asset_storage", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AssetStorage {
+    Bucket,
+    Local
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "upload_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum UploadStatus {
+    Pending,
+    Completed
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchKind {
+    Author,
+    Topic,
+    Lesson
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "publication_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PublicationStatus {
+    Draft,
+    /// Goes live at `publish_at`
+    Scheduled,
+    Published,
+    /// Live, but left out of lists, search and feeds
+    Unlisted
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TrashKind {
+    Author,
+    Topic,
+    Lesson
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TranslationKind {
+    Author,
+    Topic,
+    Lesson
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "achievement", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum Achievement {
+    FirstLesson,
+    FirstTopic,
+    /// Seven days in a row
+    WeekStreak,
+    /// Thirty days in a row
+    MonthStreak,
+    TenSnips,
+    HundredSnips,
+    /// Counted listens of ten different authors
+    TenAuthors,
+    TenHours
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "notification_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    /// A lesson was published in a subscribed topic
+    NewLesson,
+    /// Nothing was listened to today after an active yesterday
+    StreakAtRisk,
+    /// Listening totals of the past week
+    WeeklySummary
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "device_platform", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DevicePlatform {
+    /// Delivered through FCM
+    Android,
+    /// Delivered through APNs
+    Ios,
+    /// Delivered through FCM
+    Web
+}
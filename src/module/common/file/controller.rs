@@ -1,14 +1,15 @@
 use crate::error::AppError;
 use crate::extractor::accept_language::AcceptLanguage;
-use crate::module::common::base::{BaseResponse, UploadUrlParam, UploadUrlResponse};
+use crate::module::common::base::{BaseResponse, UploadCompleteRequest, UploadSessionResponse, UploadUrlParam, UploadUrlResponse};
 use crate::state::AppState;
-use crate::utils::extractors::{ValidatedPath, ValidatedQuery};
+use crate::utils::extractors::{ValidatedJson, ValidatedPath, ValidatedQuery};
+use crate::utils::jwt::Claims;
 use crate::utils::CONFIG;
 use aws_sdk_s3::presigning::PresigningConfig;
 use axum::extract::{Multipart, State};
+use axum::Extension;
 use axum::response::{IntoResponse, Redirect, Response};
 use serde::Deserialize;
-use sha2::{Digest, Sha256};
 use std::fs;
 use std::path::Path;
 use std::time::Duration;
@@ -16,6 +17,9 @@ use axum::http::{header, HeaderValue};
 use utoipa::ToSchema;
 use uuid::Uuid;
 use crate::db;
+use crate::db::asset::entity::AssetInput;
+use crate::module::common::enums::{AssetStorage, UploadStatus};
+use crate::module::common::file::service;
 
 /// Just a schema for axum native multipart
 #[derive(Deserialize, ToSchema)]
@@ -26,21 +30,6 @@ struct UploadForm {
     file: String,
 }
 
-fn detect_file_kind(bytes: &[u8]) -> Option<(&str, &str)> {
-    let file = infer::get(bytes)?;
-
-    let category = match file.mime_type() {
-        m if m.starts_with("image/") => "image",
-        m if m.starts_with("audio/") => "audio",
-        _ => return None,
-    };
-
-    Some((
-        category,
-        file.extension()
-    ))
-}
-
 #[utoipa::path(
     post,
     path = "/v1/file",
@@ -50,7 +39,9 @@ fn detect_file_kind(bytes: &[u8]) -> Option<(&str, &str)> {
     tag = "File"
 )]
 pub async fn upload(
+    State(state): State<AppState>,
     AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>,
     mut multipart: Multipart,
 ) -> Result<BaseResponse<String>, AppError> {
     let mut audio_path = None;
@@ -58,31 +49,9 @@ pub async fn upload(
     if let Ok(field) = multipart.next_field().await {
         if let Some(field) = field && let Some(name) = field.name() && name == "file" {
             let data = field.bytes().await.unwrap();
-            let file_kind = detect_file_kind(&data).ok_or(
-                AppError::UnsupportedFileType(lang.clone())
-            )?;
-
-            if file_kind.0 == "audio" {
-                return Err(AppError::UnsupportedFileType(lang))
-            }
-
-            let mut hasher = Sha256::new();
-            hasher.update(&data);
-
-            let hash = format!("{:x}", hasher.finalize());
-            let filename = format!("{}.{}", hash, file_kind.1);
-            audio_path = Some(format!("{}/{}", file_kind.0, filename));
-
-            let path_str = format!("uploads/{}", audio_path.as_ref().unwrap());
-            let path = Path::new(&path_str);
-
-            if let Some(parent) = path.parent() && let Err(_) = fs::create_dir_all(parent) {
-                return Err(AppError::Internal(lang))
-            }
-
-            if let Err(_) = fs::write(&path, &data) {
-                return Err(AppError::Internal(lang))
-            }
+            audio_path = Some(
+                service::save_image(&state.db, &data, Some(claims.sub), lang.clone()).await?
+            );
         }
     }
     
@@ -104,6 +73,7 @@ pub async fn upload(
 pub async fn upload_url(
     State(state): State<AppState>,
     AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>,
     ValidatedQuery(params): ValidatedQuery<UploadUrlParam>
 ) -> Result<BaseResponse<UploadUrlResponse>, AppError> {
 
@@ -140,11 +110,25 @@ pub async fn upload_url(
         .bucket(CONFIG.r2_bucket_name.clone())
         .key(audio_path.clone())
         .content_length(params.file_length)
-        .content_type(params.mime_type)
+        .content_type(params.mime_type.clone())
+        .set_checksum_sha256(params.checksum.clone())
         .presigned(expires_in)
         .await
         .map_err(|_| { AppError::Internal(lang.clone()) })?;
 
+    service::record_upload(
+        &state.db,
+        AssetInput {
+            path: audio_path.clone(),
+            storage: AssetStorage::Bucket,
+            file_size: params.file_length,
+            mime_type: Some(params.mime_type),
+            checksum: params.checksum,
+            status: UploadStatus::Pending,
+            uploaded_by: Some(claims.sub)
+        }
+    ).await?;
+
     Ok(
         BaseResponse::success(
             UploadUrlResponse{
@@ -155,6 +139,40 @@ pub async fn upload_url(
     )
 }
 
+#[utoipa::path(
+    post,
+    path = "/v1/file/upload-complete",
+    security(("bearerAuth" = [])),
+    request_body = UploadCompleteRequest,
+    responses((status = 200, body = UploadSessionResponse)),
+    tag = "File"
+)]
+pub async fn upload_complete(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedJson(body): ValidatedJson<UploadCompleteRequest>
+) -> Result<BaseResponse<UploadSessionResponse>, AppError> {
+    let asset = service::complete_upload(
+        &state.db,
+        &state.s3_client,
+        body.file_key,
+        lang
+    ).await?;
+
+    Ok(
+        BaseResponse::success(
+            UploadSessionResponse {
+                file_key: asset.path,
+                file_size: asset.file_size,
+                mime_type: asset.mime_type,
+                status: asset.status,
+                uploaded_by: asset.uploaded_by,
+                completed_at: asset.completed_at
+            }
+        )
+    )
+}
+
 #[utoipa::path(
     get,
     path = "/v1/file/{file_path}",
@@ -0,0 +1,23 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AudioChapterResponse {
+    pub title: Option<String>,
+    pub start_ms: i64,
+    pub end_ms: i64
+}
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct AudioMetadataResponse {
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    /// Author whose name matches `artist`
+    pub author_id: Option<i64>,
+    /// Topic whose title matches `album`
+    pub topic_id: Option<i64>,
+    /// Embedded artwork saved as a local image
+    pub cover_image_path: Option<String>,
+    pub chapters: Vec<AudioChapterResponse>
+}
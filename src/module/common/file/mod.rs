@@ -1,2 +1,4 @@
 pub mod controller;
-pub mod routes;
\ No newline at end of file
+pub mod dto;
+pub mod routes;
+pub mod service;
@@ -2,7 +2,7 @@ use axum::{Router, routing::get, middleware};
 use axum::extract::DefaultBodyLimit;
 use axum::routing::{post};
 use crate::middleware::auth::{admin_auth_middleware, common_auth_middleware, origin_middleware};
-use crate::module::common::file::controller::{download_file, download_image, upload, upload_url};
+use crate::module::common::file::controller::{download_file, download_image, upload, upload_complete, upload_url};
 use crate::state::AppState;
 
 pub fn routes() -> Router<AppState> {
@@ -15,6 +15,7 @@ pub fn routes() -> Router<AppState> {
         .route("/", post(upload))
         .layer(DefaultBodyLimit::max(1 * 1024 * 1024))
         .route("/upload-url", get(upload_url))
+        .route("/upload-complete", post(upload_complete))
         .layer(middleware::from_fn(origin_middleware))
         .layer(middleware::from_fn(admin_auth_middleware));
 
@@ -0,0 +1,328 @@
+use crate::db;
+use crate::db::asset::entity::{AssetEntity, AssetInput};
+use crate::module::common::file::dto::{AudioChapterResponse, AudioMetadataResponse};
+use crate::error::file::FileError;
+use crate::error::AppError;
+use crate::module::common::enums::{AssetStorage, UploadStatus};
+use crate::utils::CONFIG;
+use anyhow::Result;
+use aws_sdk_s3 as s3;
+use aws_sdk_s3::types::ChecksumMode;
+use fluent_templates::LanguageIdentifier;
+use id3::frame::PictureType;
+use id3::{Tag, TagLike};
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use std::io::{Cursor, ErrorKind};
+use std::path::Path;
+use time::{Duration, OffsetDateTime};
+
+const MAX_ID3_TAG_SIZE: u64 = 16 * 1024 * 1024;
+
+pub fn detect_file_kind(bytes: &[u8]) -> Option<(&str, &str)> {
+    let file = infer::get(bytes)?;
+
+    let category = match file.mime_type() {
+        m if m.starts_with("image/") => "image",
+        m if m.starts_with("audio/") => "audio",
+        _ => return None,
+    };
+
+    Some((
+        category,
+        file.extension()
+    ))
+}
+
+pub async fn record_upload(db: &PgPool, asset: AssetInput) -> Result<AssetEntity> {
+    let asset = db::asset::repo::insert(db, asset).await?;
+    Ok(asset)
+}
+
+/// Stores an image under `uploads/image/<sha256>.<ext>` and records it as a
+/// completed upload. Returns the asset path.
+pub async fn save_image(
+    db: &PgPool,
+    data: &[u8],
+    uploaded_by: Option<i64>,
+    lang: LanguageIdentifier,
+) -> std::result::Result<String, AppError> {
+    let file_kind = detect_file_kind(data).ok_or(
+        AppError::UnsupportedFileType(lang.clone())
+    )?;
+
+    if file_kind.0 != "image" {
+        return Err(AppError::UnsupportedFileType(lang))
+    }
+
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+
+    let hash = format!("{:x}", hasher.finalize());
+    let image_path = format!("{}/{}.{}", file_kind.0, hash, file_kind.1);
+
+    let path_str = format!("uploads/{}", image_path);
+    let path = Path::new(&path_str);
+
+    if let Some(parent) = path.parent() && tokio::fs::create_dir_all(parent).await.is_err() {
+        return Err(AppError::Internal(lang))
+    }
+
+    if tokio::fs::write(path, data).await.is_err() {
+        return Err(AppError::Internal(lang))
+    }
+
+    record_upload(
+        db,
+        AssetInput {
+            path: image_path.clone(),
+            storage: AssetStorage::Local,
+            file_size: data.len() as i64,
+            mime_type: infer::get(data).map(|t| t.mime_type().to_string()),
+            checksum: None,
+            status: UploadStatus::Completed,
+            uploaded_by
+        }
+    ).await?;
+
+    Ok(image_path)
+}
+
+/// Verifies a direct-to-bucket upload against its session (size, content type,
+/// checksum and magic bytes) and marks the session completed.
+pub async fn complete_upload(
+    db: &PgPool,
+    s3_client: &s3::Client,
+    path: String,
+    lang: LanguageIdentifier,
+) -> Result<AssetEntity> {
+    let asset = db::asset::repo::get_by_path(db, &path)
+        .await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
+
+    if asset.status == UploadStatus::Completed {
+        return Ok(asset);
+    }
+
+    let head = s3_client
+        .head_object()
+        .bucket(CONFIG.r2_bucket_name.clone())
+        .key(path.clone())
+        .checksum_mode(ChecksumMode::Enabled)
+        .send()
+        .await
+        .map_err(|_| FileError::UploadNotCompleted(lang.clone()))?;
+
+    let verified = head.content_length() == Some(asset.file_size)
+        && head.content_type() == asset.mime_type.as_deref()
+        && (asset.checksum.is_none() || head.checksum_sha256() == asset.checksum.as_deref());
+    if !verified {
+        return Err(FileError::UploadVerificationFailed(lang).into());
+    }
+
+    let object = s3_client
+        .get_object()
+        .bucket(CONFIG.r2_bucket_name.clone())
+        .key(path.clone())
+        .range("bytes=0-8191")
+        .send()
+        .await?;
+    let bytes = object.body.collect().await?.into_bytes();
+    let extension = path.rsplit('.').next();
+    match detect_file_kind(&bytes) {
+        Some(("audio", kind)) if extension.is_some_and(|ext| ext.eq_ignore_ascii_case(kind)) => {}
+        _ => return Err(FileError::UploadVerificationFailed(lang).into()),
+    }
+
+    Ok(db::asset::repo::complete(db, asset.id).await?)
+}
+
+/// Fails unless `path` belongs to a completed upload session.
+pub async fn ensure_completed(
+    db: &PgPool,
+    path: &str,
+    lang: LanguageIdentifier,
+) -> std::result::Result<(), AppError> {
+    match db::asset::repo::get_by_path(db, path).await? {
+        Some(asset) if asset.status == UploadStatus::Completed => Ok(()),
+        _ => Err(FileError::UploadNotCompleted(lang).into()),
+    }
+}
+
+async fn read_range(s3_client: &s3::Client, path: &str, start: u64, end: u64) -> Result<Vec<u8>> {
+    let object = s3_client
+        .get_object()
+        .bucket(CONFIG.r2_bucket_name.clone())
+        .key(path)
+        .range(format!("bytes={}-{}", start, end))
+        .send()
+        .await?;
+    Ok(object.body.collect().await?.into_bytes().to_vec())
+}
+
+/// Reads the ID3v2 tag at the start of a bucket object. Only the tag bytes are
+/// downloaded; returns `None` when the file carries no tag.
+async fn read_id3_tag(s3_client: &s3::Client, path: &str) -> Result<Option<Tag>> {
+    let header = read_range(s3_client, path, 0, 9).await?;
+    if header.len() < 10 || &header[..3] != b"ID3" {
+        return Ok(None);
+    }
+
+    // tag size is a 28-bit syncsafe integer, excluding the header and the optional footer
+    let size = header[6..10]
+        .iter()
+        .fold(0u64, |size, byte| (size << 7) | (*byte & 0x7f) as u64);
+    let footer = if header[5] & 0x10 != 0 { 10 } else { 0 };
+    let total = (10 + size + footer).min(MAX_ID3_TAG_SIZE);
+
+    let bytes = read_range(s3_client, path, 0, total - 1).await?;
+    match Tag::read_from2(Cursor::new(bytes)) {
+        Ok(tag) => Ok(Some(tag)),
+        Err(err) if matches!(err.kind, id3::ErrorKind::NoTag) => Ok(None),
+        Err(err) => match err.partial_tag {
+            Some(tag) => Ok(Some(tag)),
+            None => Err(err.into())
+        }
+    }
+}
+
+fn chapters_of(tag: &Tag) -> Vec<AudioChapterResponse> {
+    let mut chapters: Vec<&id3::frame::Chapter> = tag.chapters()
+        .filter(|chapter| chapter.end_time > chapter.start_time)
+        .collect();
+
+    // follow the top level table of contents when it is ordered, otherwise order by time
+    let order = tag.tables_of_contents()
+        .find(|toc| toc.top_level && toc.ordered)
+        .map(|toc| toc.elements.clone());
+    match order {
+        Some(order) => chapters.sort_by_key(|chapter| {
+            order.iter().position(|id| *id == chapter.element_id).unwrap_or(usize::MAX)
+        }),
+        None => chapters.sort_by_key(|chapter| chapter.start_time),
+    }
+
+    chapters.into_iter()
+        .map(|chapter| AudioChapterResponse {
+            title: chapter.title().map(str::to_string),
+            start_ms: chapter.start_time as i64,
+            end_ms: chapter.end_time as i64
+        })
+        .collect()
+}
+
+/// Chapters from the ID3 tag of a bucket audio file, empty when it has none.
+pub async fn read_chapters(
+    s3_client: &s3::Client,
+    path: &str,
+    lang: LanguageIdentifier,
+) -> std::result::Result<Vec<AudioChapterResponse>, AppError> {
+    let tag = read_id3_tag(s3_client, path)
+        .await
+        .map_err(|_| FileError::UploadVerificationFailed(lang))?;
+    Ok(tag.as_ref().map(chapters_of).unwrap_or_default())
+}
+
+/// Extracts title, artist, album, chapters and embedded cover art from a
+/// completed audio upload. The cover is saved as a local image and artist and
+/// album are matched against existing authors and topics.
+pub async fn extract_metadata(
+    db: &PgPool,
+    s3_client: &s3::Client,
+    path: String,
+    uploaded_by: i64,
+    lang: LanguageIdentifier,
+) -> std::result::Result<AudioMetadataResponse, AppError> {
+    ensure_completed(db, &path, lang.clone()).await?;
+
+    let tag = read_id3_tag(s3_client, &path)
+        .await
+        .map_err(|_| FileError::UploadVerificationFailed(lang.clone()))?;
+    let Some(tag) = tag else {
+        return Ok(AudioMetadataResponse::default());
+    };
+
+    let cover = tag.pictures()
+        .find(|picture| picture.picture_type == PictureType::CoverFront)
+        .or_else(|| tag.pictures().next());
+    let cover_image_path = match cover {
+        Some(picture) => save_image(db, &picture.data, Some(uploaded_by), lang).await.ok(),
+        None => None
+    };
+
+    let title = tag.title().map(str::to_string);
+    let artist = tag.artist().map(str::to_string);
+    let album = tag.album().map(str::to_string);
+
+    let author = match &artist {
+        Some(artist) => db::author::repo::get_by_name(db, artist).await?,
+        None => None
+    };
+    let topic = match &album {
+        Some(album) => db::topic::repo::get_by_title(db, album, author.as_ref().map(|a| a.id)).await?,
+        None => None
+    };
+
+    Ok(
+        AudioMetadataResponse {
+            chapters: chapters_of(&tag),
+            title,
+            artist,
+            album,
+            author_id: author.map(|author| author.id).or(topic.as_ref().map(|topic| topic.author_id)),
+            topic_id: topic.map(|topic| topic.id),
+            cover_image_path
+        }
+    )
+}
+
+fn grace_cutoff() -> OffsetDateTime {
+    OffsetDateTime::now_utc() - Duration::hours(CONFIG.gc_grace_period_hours)
+}
+
+/// Lists every unreferenced asset without touching storage. Assets orphaned
+/// before the returned cutoff will be removed by the next GC run.
+pub async fn gc_report(db: &PgPool) -> Result<(Vec<AssetEntity>, OffsetDateTime)> {
+    let items = db::asset::repo::unreferenced(db).await?;
+    Ok((items, grace_cutoff()))
+}
+
+async fn delete_stored(s3_client: &s3::Client, asset: &AssetEntity) -> Result<()> {
+    match asset.storage {
+        AssetStorage::Bucket => {
+            s3_client
+                .delete_object()
+                .bucket(CONFIG.r2_bucket_name.clone())
+                .key(asset.path.clone())
+                .send()
+                .await?;
+        }
+        AssetStorage::Local => {
+            match tokio::fs::remove_file(format!("uploads/{}", asset.path)).await {
+                Err(err) if err.kind() != ErrorKind::NotFound => return Err(err.into()),
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Deletes assets that have been unreferenced for longer than the grace period.
+/// Returns `(deleted, failed)`.
+pub async fn collect_garbage(db: &PgPool, s3_client: &s3::Client) -> Result<(u64, u64)> {
+    db::asset::repo::mark_orphans(db).await?;
+
+    let mut deleted = 0;
+    let mut failed = 0;
+    for asset in db::asset::repo::orphaned_before(db, grace_cutoff()).await? {
+        if let Err(err) = delete_stored(s3_client, &asset).await {
+            eprintln!("GC failed to delete {}: {:?}", asset.path, err);
+            failed += 1;
+            continue;
+        }
+        db::asset::repo::delete(db, asset.id).await?;
+        deleted += 1;
+    }
+
+    Ok((deleted, failed))
+}
@@ -1,5 +1,6 @@
 use crate::module::common::author::dto::CommonAuthorResponse;
 use crate::module::common::base::FileResponse;
+use crate::module::common::chapter::dto::ChapterResponse;
 use crate::module::common::topic::dto::CommonTopicResponse;
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
@@ -14,8 +15,15 @@ pub struct CommonLessonResponse {
     pub author: CommonAuthorResponse,
     pub topic: Option<CommonTopicResponse>,
     pub audio: FileResponse,
+    /// Not loaded for the lesson of a snip
+    pub chapters: Vec<ChapterResponse>,
     pub listen_count: i64,
     pub snip_count: i64,
+    /// Mean of the visible 1–5 star ratings, 0 without any
+    pub rating_average: f64,
+    pub rating_count: i64,
+    /// Position within the topic
+    pub position: i32,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime
 }
@@ -24,5 +32,9 @@ pub struct CommonLessonResponse {
 #[serde(rename_all = "snake_case")]
 pub enum QuerySort {
     SnipCount,
-    CreatedAt
+    CreatedAt,
+    /// Average rating
+    Rating,
+    /// Order within the topic, ascending unless `order` says otherwise
+    Position
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 use crate::db::lesson::entity::LessonWithAuthorTopic;
 use crate::module::common::author::dto::CommonAuthorResponse;
 use crate::module::common::base::FileResponse;
+use crate::module::common::chapter::mapper::to_responses as chapters_to_responses;
 use crate::module::common::lesson::dto::CommonLessonResponse;
 use crate::module::common::topic::dto::CommonTopicResponse;
 
@@ -26,7 +27,9 @@ pub fn to_response(entity: &LessonWithAuthorTopic) -> CommonLessonResponse {
                     cover_image_path: entity.topic_cover_image_path.to_owned(),
                     created_at: entity.topic_created_at.unwrap(),
                     lesson_count: entity.topic_lesson_count.unwrap(),
-                    total_duration: entity.topic_total_duration.unwrap()
+                    total_duration: entity.topic_total_duration.unwrap(),
+                    rating_average: entity.topic_rating_average.unwrap_or_default(),
+                    rating_count: entity.topic_rating_count.unwrap_or_default()
                 }
             )
         } else { None },
@@ -35,8 +38,12 @@ pub fn to_response(entity: &LessonWithAuthorTopic) -> CommonLessonResponse {
             size: entity.lesson.file_size,
             duration: entity.lesson.duration
         },
+        chapters: chapters_to_responses(&entity.chapters, entity.lesson.duration),
         listen_count: entity.lesson.listen_count,
         snip_count: entity.lesson.snip_count,
+        rating_average: entity.lesson.rating_average,
+        rating_count: entity.lesson.rating_count,
+        position: entity.lesson.position,
         created_at: entity.lesson.created_at
     }
 }
\ No newline at end of file
@@ -1,11 +1,13 @@
 use crate::db::lesson::entity::{LessonInput, LessonProgressEntity, LessonWithAuthorTopic};
 use crate::error::lesson::LessonError;
 use crate::error::AppError;
-use crate::module::common::enums::UserProgressStatus;
+use crate::module::common::enums::{PublicationStatus, UserProgressStatus};
+use crate::module::common::{achievement, chapter, file, revision};
 use crate::module::common::lesson::dto::QuerySort;
 use crate::module::common::paging::QueryOrder;
 use crate::module::user::lesson::dto::LessonCursor;
-use crate::utils::CONFIG;
+use crate::string_keys::strings;
+use crate::utils::{t, CONFIG};
 use crate::{db, utils};
 use anyhow::Result;
 use aws_sdk_s3 as s3;
@@ -16,6 +18,18 @@ use sqlx::PgPool;
 use std::time::Duration;
 use time::OffsetDateTime;
 
+/// A scheduled item needs the time it goes live.
+pub fn check_schedule(
+    status: PublicationStatus,
+    publish_at: Option<OffsetDateTime>,
+    lang: &LanguageIdentifier,
+) -> std::result::Result<(), AppError> {
+    if status == PublicationStatus::Scheduled && publish_at.is_none() {
+        return Err(AppError::BadRequest{lang: lang.clone(), message: t(lang, strings::PUBLISH_AT_REQUIRED)});
+    }
+    Ok(())
+}
+
 async fn get_info(s3_client: &s3::Client, path: &str) -> Result<(i64, i64)> {
     let head = s3_client
         .head_object()
@@ -54,8 +68,18 @@ pub async fn create(
     description: Option<String>,
     cover_image_path: Option<String>,
     audio_path: String,
+    publication_status: Option<PublicationStatus>,
+    publish_at: Option<OffsetDateTime>,
+    admin_id: i64,
     lang: LanguageIdentifier,
 ) -> Result<LessonWithAuthorTopic, AppError> {
+    let publication_status = publication_status.unwrap_or(PublicationStatus::Draft);
+    check_schedule(publication_status, publish_at, &lang)?;
+    file::service::ensure_completed(db, &audio_path, lang.clone()).await?;
+    if let Some(cover_image_path) = &cover_image_path {
+        file::service::ensure_completed(db, cover_image_path, lang.clone()).await?;
+    }
+
     let info = get_info(s3_client, audio_path.as_str()).await?;
 
     let lesson = LessonInput {
@@ -67,6 +91,8 @@ pub async fn create(
         audio_path,
         duration: info.1,
         file_size: info.0,
+        publication_status,
+        publish_at,
     };
 
     if let Some(topic_id) = topic_id {
@@ -76,16 +102,24 @@ pub async fn create(
         }
     }
 
+    let mut revision = revision::service::input(0, None, &lesson, admin_id, None);
+
     let mut tx = db.begin().await?;
     let lesson_id = db::lesson::repo::insert(&mut tx, lesson).await?;
-    if let Some(topic_id) = topic_id {
-        db::topic::repo::update_stats(&mut tx, topic_id, 1, info.1).await?;
+    revision.lesson_id = lesson_id;
+    db::revision::repo::insert(&mut tx, revision).await?;
+    // only published lessons count towards the topic and author totals
+    if publication_status == PublicationStatus::Published {
+        if let Some(topic_id) = topic_id {
+            db::topic::repo::update_stats(&mut tx, topic_id, 1, info.1).await?;
+        }
+        db::author::repo::update_stats(&mut tx, author_id, 1).await?;
     }
-    db::author::repo::update_stats(&mut tx, author_id, 1).await?;
     tx.commit().await?;
-    let lesson = db::lesson::repo::get_with_author_topic_by_id(db, lesson_id)
+    let mut lesson = db::lesson::repo::get_with_author_topic_by_id(db, lesson_id)
         .await?
         .ok_or(AppError::NotFound(lang))?;
+    chapter::service::attach(db, std::slice::from_mut(&mut lesson)).await?;
 
     Ok(lesson)
 }
@@ -94,55 +128,185 @@ pub async fn update(
     db: &PgPool,
     s3_client: &s3::Client,
     id: i64,
+    author_id: i64,
+    topic_id: Option<i64>,
     title: String,
     description: Option<String>,
     cover_image_path: Option<String>,
     audio_path: String,
+    publication_status: Option<PublicationStatus>,
+    publish_at: Option<OffsetDateTime>,
+    admin_id: i64,
+    restored_from: Option<i32>,
     lang: LanguageIdentifier,
 ) -> Result<LessonWithAuthorTopic> {
-    let old_lesson = db::lesson::repo::get_by_id(db, id)
+    // files are checked and probed before the transaction, the lesson is read
+    // again under lock below and the counters follow that row
+    let current = db::lesson::repo::get_by_id(db, id)
         .await?.ok_or(AppError::NotFound(lang.clone()))?;
 
-    let audio_updated = old_lesson.audio_path != audio_path;
+    if current.audio_path != audio_path {
+        file::service::ensure_completed(db, &audio_path, lang.clone()).await?;
+    }
+    if let Some(cover_image_path) = &cover_image_path
+        && current.cover_image_path.as_ref() != Some(cover_image_path) {
+        file::service::ensure_completed(db, cover_image_path, lang.clone()).await?;
+    }
 
-    let info = if audio_updated {
-        get_info(s3_client, audio_path.as_str()).await?
+    let probed = if current.audio_path != audio_path {
+        Some(get_info(s3_client, audio_path.as_str()).await?)
     } else {
-        (old_lesson.file_size, old_lesson.duration)
+        None
+    };
+
+    let mut tx = db.begin().await?;
+    let old_lesson = db::lesson::repo::lock(&mut tx, id)
+        .await?.ok_or(AppError::NotFound(lang.clone()))?;
+
+    // without a status the publication state is left as it is
+    let (publication_status, publish_at) = match publication_status {
+        Some(status) => (status, publish_at),
+        None => (old_lesson.publication_status, old_lesson.publish_at)
+    };
+    check_schedule(publication_status, publish_at, &lang)?;
+
+    let author_changed = old_lesson.author_id != author_id;
+    let topic_changed = old_lesson.topic_id != topic_id;
+    let was_published = old_lesson.publication_status == PublicationStatus::Published;
+    let is_published = publication_status == PublicationStatus::Published;
+
+    let audio_updated = old_lesson.audio_path != audio_path;
+
+    let info = match probed {
+        _ if !audio_updated => (old_lesson.file_size, old_lesson.duration),
+        Some(info) => info,
+        // the audio was replaced by a concurrent update since the first read
+        None => get_info(s3_client, audio_path.as_str()).await?,
     };
 
     let lesson = LessonInput {
-        author_id: 1,
-        topic_id: None,
+        author_id,
+        topic_id,
         title,
         description,
         cover_image_path,
         audio_path,
         duration: info.1,
         file_size: info.0,
+        publication_status,
+        publish_at,
     };
 
-    let mut tx = db.begin().await?;
+    let revision = revision::service::input(id, Some(&old_lesson), &lesson, admin_id, restored_from);
+
+    // checked in the transaction so the author or topic cannot be trashed meanwhile
+    if author_changed && !db::author::repo::lock_active(&mut tx, author_id).await? {
+        return Err(AppError::NotFound(lang).into())
+    }
+    if let Some(topic_id) = topic_id
+        && db::topic::repo::lock_active(&mut tx, topic_id).await? != Some(author_id) {
+        return Err(AppError::NotFound(lang).into())
+    }
     let lesson_id = db::lesson::repo::update(&mut tx, id, lesson)
         .await?
         .ok_or(AppError::NotFound(lang.clone()))?;
-    let entity = db::lesson::repo::get_with_author_topic_by_id(db, lesson_id)
-        .await?
-        .ok_or(AppError::NotFound(lang))?;
-    if let Some(topic_id) = entity.lesson.topic_id && audio_updated{
-        db::topic::repo::update_stats(
-            &mut tx,
-            topic_id,
-            0,
-            entity.lesson.duration - old_lesson.duration,
-        )
-        .await?
+    db::revision::repo::insert(&mut tx, revision).await?;
+
+    // only published lessons count towards the topic and author totals, so the
+    // old contribution is taken back and the new one added
+    if was_published != is_published || author_changed || topic_changed || audio_updated {
+        if was_published {
+            if let Some(old_topic_id) = old_lesson.topic_id {
+                db::topic::repo::update_stats(&mut tx, old_topic_id, -1, -old_lesson.duration).await?;
+            }
+            db::author::repo::update_stats(&mut tx, old_lesson.author_id, -1).await?;
+        }
+        if is_published {
+            if let Some(topic_id) = topic_id {
+                db::topic::repo::update_stats(&mut tx, topic_id, 1, info.1).await?;
+            }
+            db::author::repo::update_stats(&mut tx, author_id, 1).await?;
+        }
+    }
+
+    if topic_changed {
+        // the lesson takes its snips and ratings along to the new topic
+        if let Some(old_topic_id) = old_lesson.topic_id {
+            db::topic::repo::update_snip_count(&mut tx, old_topic_id, -old_lesson.snip_count).await?;
+            db::topic::repo::recount_rating(&mut tx, old_topic_id).await?;
+        }
+        if let Some(topic_id) = topic_id {
+            db::topic::repo::update_snip_count(&mut tx, topic_id, old_lesson.snip_count).await?;
+            db::topic::repo::recount_rating(&mut tx, topic_id).await?;
+        }
+    }
+
+    if author_changed || topic_changed {
+        db::lesson::repo::move_related(&mut tx, lesson_id, author_id, topic_id).await?;
+    }
+    // new audio may end before chapters, cues and snips of the old one
+    if audio_updated && info.1 > 0
+        && db::lesson::repo::fit_to_duration(&mut tx, lesson_id, info.1).await? {
+        db::lesson::repo::recount_snip_count(&mut tx, lesson_id).await?;
+        if let Some(topic_id) = topic_id {
+            db::topic::repo::recount_stats(&mut tx, topic_id).await?;
+        }
+    }
+    // completed counts only cover published lessons
+    if topic_changed || was_published != is_published {
+        if let Some(old_topic_id) = old_lesson.topic_id
+            && old_lesson.topic_id != topic_id {
+            db::topic::repo::refresh_progress_of_lesson_users(&mut tx, lesson_id, old_lesson.author_id, old_topic_id).await?;
+        }
+        if let Some(topic_id) = topic_id {
+            db::topic::repo::refresh_progress_of_lesson_users(&mut tx, lesson_id, author_id, topic_id).await?;
+        }
     }
     tx.commit().await?;
 
+    let mut entity = db::lesson::repo::get_with_author_topic_by_id(db, lesson_id)
+        .await?
+        .ok_or(AppError::NotFound(lang))?;
+    chapter::service::attach(db, std::slice::from_mut(&mut entity)).await?;
+
     Ok(entity)
 }
 
+/// Restores the title, description, cover and audio of `revision` as a new
+/// revision. The author, topic and publication state are left as they are.
+pub async fn rollback(
+    db: &PgPool,
+    s3_client: &s3::Client,
+    id: i64,
+    revision: i32,
+    admin_id: i64,
+    lang: LanguageIdentifier,
+) -> Result<LessonWithAuthorTopic> {
+    let lesson = db::lesson::repo::get_by_id(db, id)
+        .await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
+    let target = db::revision::repo::get(db, id, revision)
+        .await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
+
+    update(
+        db,
+        s3_client,
+        id,
+        lesson.author_id,
+        lesson.topic_id,
+        target.title,
+        target.description,
+        target.cover_image_path,
+        target.audio_path,
+        None,
+        None,
+        admin_id,
+        Some(revision),
+        lang,
+    ).await
+}
+
 pub async fn page(
     db: &PgPool,
     page: u32,
@@ -158,10 +322,11 @@ pub async fn page(
 ) -> Result<(Vec<LessonWithAuthorTopic>, u64)> {
     let offset = (page - 1) * limit;
 
-    let items = db::lesson::repo::page(
+    let mut items = db::lesson::repo::page(
         db, limit, offset, author_id, topic_id, &search, user_id, &status, favourite, sort, order,
     )
     .await?;
+    chapter::service::attach(db, &mut items).await?;
 
     let total = db::lesson::repo::count(
         db, author_id, topic_id, &search, user_id, &status, favourite,
@@ -205,44 +370,44 @@ pub async fn page_cursor(
         utils::cursor::encode(LessonCursor {
             id: last.lesson.id,
             snip_count: last.lesson.snip_count,
+            position: last.lesson.position,
+            rating_average: last.lesson.rating_average,
             created_at: last.lesson.created_at,
         })
     } else {
         None
     };
+    chapter::service::attach(db, &mut items).await?;
 
     Ok((items, next_cursor))
 }
 
 pub async fn get(db: &PgPool, id: i64, lang: LanguageIdentifier) -> Result<LessonWithAuthorTopic> {
-    let lesson = db::lesson::repo::get_with_author_topic_by_id(db, id)
+    let mut lesson = db::lesson::repo::get_with_author_topic_by_id(db, id)
         .await?
         .ok_or(AppError::NotFound(lang))?;
+    chapter::service::attach(db, std::slice::from_mut(&mut lesson)).await?;
     Ok(lesson)
 }
 
-pub async fn increase_listen_count(
+/// Starts a listen session. It is counted in `listen_count` by the heartbeats
+/// once enough of the lesson is heard.
+pub async fn create_listen_session(
     db: &PgPool,
     session_id: String,
     user_id: i64,
     lesson_id: i64,
     created_at: OffsetDateTime,
+    device: Option<String>,
 ) -> Result<i64> {
-    let mut tx = db.begin().await?;
-    let result = db::lesson::repo::create_listen_session(
-        &mut tx,
+    db::lesson::repo::create_listen_session(
+        &mut *db.acquire().await?,
         session_id,
         user_id,
         lesson_id,
-        created_at
+        created_at,
+        device
     ).await?;
-    if result.1 {
-        db::lesson::repo::increase_listen_count(
-            &mut tx,
-            lesson_id
-        ).await?;
-    }
-    tx.commit().await?;
     Ok(
         db::lesson::repo::get_listen_count(db, lesson_id).await?
     )
@@ -256,6 +421,7 @@ pub async fn update_progress(
     last_position_ms: i64,
     status: Option<UserProgressStatus>,
     completed_at: Option<OffsetDateTime>,
+    session_id: Option<String>,
     lang: LanguageIdentifier,
 ) -> Result<LessonProgressEntity> {
     let author = db::author::repo::get_by_lesson_id(db, lesson_id)
@@ -278,23 +444,40 @@ pub async fn update_progress(
     if let Some(topic) = topic {
         db::topic::repo::update_progress(&mut tx, user_id, author.id, topic.id).await?;
     }
+    if let Some(completed_at) = entity.completed_at {
+        db::stat::repo::refresh(&mut tx, user_id, Some((completed_at, completed_at))).await?;
+    }
+    if let Some(session_id) = session_id {
+        db::lesson::repo::update_listen_session(
+            &mut tx,
+            session_id,
+            user_id,
+            lesson_id,
+            last_position_ms
+        ).await?;
+    }
     tx.commit().await?;
+    if entity.completed_at.is_some() {
+        achievement::service::evaluate(db, user_id).await?;
+    }
 
     Ok(entity)
 }
 
 pub async fn delete(db: &PgPool, id: i64, lang: LanguageIdentifier) -> Result<()> {
     let mut tx = db.begin().await?;
-    let entity = db::lesson::repo::delete(&mut tx, id).await?;
+    // a lesson already in the trash must not take its counters down twice
+    let lesson = db::lesson::repo::delete(&mut tx, id)
+        .await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
 
-    if let Some(entity) = &entity
-        && entity.listen_count >= 50
-    {
+    if lesson.listen_count >= 50 {
         return Err(LessonError::LessonDeleteTooManyListens(lang).into());
     }
 
-    if let Some(lesson) = entity {
-        if let Some(topic_id) = lesson.topic_id {
+    let published = lesson.publication_status == PublicationStatus::Published;
+    if let Some(topic_id) = lesson.topic_id {
+        if published {
             db::topic::repo::update_stats(
                 &mut tx,
                 topic_id,
@@ -302,7 +485,11 @@ pub async fn delete(db: &PgPool, id: i64, lang: LanguageIdentifier) -> Result<()
                 -lesson.duration,
             )
             .await?;
+            db::topic::repo::refresh_progress_of_lesson_users(&mut tx, lesson.id, lesson.author_id, topic_id).await?;
         }
+        db::topic::repo::recount_rating(&mut tx, topic_id).await?;
+    }
+    if published {
         db::author::repo::update_stats(&mut tx, lesson.author_id, -1).await?;
     }
     tx.commit().await?;
@@ -318,6 +505,27 @@ pub async fn deleted(
     Ok(deleted_since)
 }
 
+pub async fn published(
+    db: &PgPool,
+    since: OffsetDateTime
+) -> std::result::Result<Vec<(i64, OffsetDateTime)>, AppError> {
+    Ok(db::lesson::repo::published(db, since).await?)
+}
+
+pub async fn publish_due(db: &PgPool) -> Result<u64> {
+    let mut tx = db.begin().await?;
+    let lessons = db::lesson::repo::publish_due(&mut tx).await?;
+    for lesson in &lessons {
+        if let Some(topic_id) = lesson.topic_id {
+            db::topic::repo::update_stats(&mut tx, topic_id, 1, lesson.duration).await?;
+            db::topic::repo::refresh_progress_of_lesson_users(&mut tx, lesson.id, lesson.author_id, topic_id).await?;
+        }
+        db::author::repo::update_stats(&mut tx, lesson.author_id, 1).await?;
+    }
+    tx.commit().await?;
+    Ok(lessons.len() as u64)
+}
+
 pub async fn set_favourite(db: &PgPool, user_id: i64, lesson_id: i64) -> Result<()> {
     db::lesson::repo::set_favourite(db, user_id, lesson_id).await?;
     Ok(())
@@ -6,6 +6,15 @@ pub mod topic;
 pub mod lesson;
 pub mod author;
 pub mod file;
+pub mod clip;
+pub mod transcript;
+pub mod chapter;
+pub mod trash;
+pub mod revision;
+pub mod translation;
+pub mod achievement;
+pub mod notification;
+pub mod review;
 pub mod enums;
 pub mod paging;
 pub mod base;
\ No newline at end of file
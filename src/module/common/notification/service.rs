@@ -0,0 +1,169 @@
+use crate::db;
+use crate::db::notification::entity::NotificationEntity;
+use crate::error::AppError;
+use crate::module::common::enums::NotificationKind;
+use crate::module::common::translation;
+use crate::push::{Push, PushError, PushMessage};
+use crate::string_keys::strings;
+use crate::utils::{t, t_args, CONFIG};
+use fluent_templates::LanguageIdentifier;
+use fluent_templates::fluent_bundle::FluentValue;
+use sqlx::PgPool;
+use std::slice;
+use time::{Duration, OffsetDateTime};
+
+/// Notifications older than this are not pushed any more, e.g. after an outage.
+const PUSH_WINDOW: Duration = Duration::days(1);
+const PUSH_BATCH: i64 = 500;
+/// A push that failed on every device, or a Telegram message that failed, is
+/// tried again on the next runs, up to this many times in total.
+const MAX_PUSH_ATTEMPTS: i32 = 5;
+
+/// Title and body in `lang`, with the lesson and topic titles as stored on
+/// the entity.
+pub fn render(entity: &NotificationEntity, lang: &LanguageIdentifier) -> (String, String) {
+    match entity.kind {
+        NotificationKind::NewLesson => {
+            let lesson = entity.lesson_title.clone().unwrap_or_default();
+            let topic = entity.topic_title.clone().unwrap_or_default();
+            (
+                t_args(lang, strings::NOTIFICATION_NEW_LESSON_TITLE, vec![("topic", FluentValue::from(topic))]),
+                t_args(lang, strings::NOTIFICATION_NEW_LESSON_BODY, vec![("lesson", FluentValue::from(lesson))])
+            )
+        }
+        NotificationKind::StreakAtRisk => (
+            t(lang, strings::NOTIFICATION_STREAK_AT_RISK_TITLE),
+            t_args(
+                lang,
+                strings::NOTIFICATION_STREAK_AT_RISK_BODY,
+                vec![("days", FluentValue::from(entity.streak_days.unwrap_or_default()))]
+            )
+        ),
+        NotificationKind::WeeklySummary => (
+            t(lang, strings::NOTIFICATION_WEEKLY_SUMMARY_TITLE),
+            t_args(
+                lang,
+                strings::NOTIFICATION_WEEKLY_SUMMARY_BODY,
+                vec![
+                    ("minutes", FluentValue::from(entity.listened_ms.unwrap_or_default() / 60_000)),
+                    ("lessons", FluentValue::from(entity.lessons_completed.unwrap_or_default()))
+                ]
+            )
+        )
+    }
+}
+
+/// Creates the notifications for lessons published in subscribed topics, for
+/// streaks at risk and the weekly summaries. All are deduplicated, so a rerun
+/// creates nothing new.
+pub async fn create_due(db: &PgPool) -> Result<u64, AppError> {
+    let since = OffsetDateTime::now_utc() - PUSH_WINDOW;
+    let mut created = db::notification::repo::insert_new_lessons(db, since).await?;
+
+    for user in db::notification::repo::streaks_at_risk(db, CONFIG.streak_reminder_hour).await? {
+        let streaks = db::stat::repo::streaks(db, user.user_id, user.today).await?;
+        if streaks.current > 0 {
+            created += db::notification::repo::insert_streak_at_risk(db, user.user_id, user.today, streaks.current).await?;
+        }
+    }
+    created += db::notification::repo::insert_weekly_summaries(db, CONFIG.weekly_summary_hour).await?;
+    Ok(created)
+}
+
+/// Pushes new notifications to every device of their user, in the device's
+/// language. Tokens the provider rejects are dropped. A notification that
+/// reached no device because of provider errors is released for the next run.
+pub async fn deliver(db: &PgPool, push: &Push) -> Result<u64, AppError> {
+    let mut sent = 0;
+    let mut failed = Vec::new();
+    loop {
+        let since = OffsetDateTime::now_utc() - PUSH_WINDOW;
+        let notifications = db::notification::repo::claim_unpushed(db, since, PUSH_BATCH, MAX_PUSH_ATTEMPTS).await?;
+        let claimed = notifications.len() as i64;
+
+        for notification in notifications {
+            let (mut delivered, mut errored) = (false, false);
+            for device in db::notification::repo::devices(db, notification.user_id).await? {
+                let lang: LanguageIdentifier = device.language.parse().unwrap_or_default();
+                let mut localized = notification.clone();
+                translation::service::localize_notifications(db, slice::from_mut(&mut localized), &lang).await?;
+                let (title, body) = render(&localized, &lang);
+                let message = PushMessage { title, body, data: data(&notification) };
+
+                match push.sender(device.platform).send(&device.token, &message).await {
+                    Ok(()) => {
+                        sent += 1;
+                        delivered = true;
+                    }
+                    Err(PushError::InvalidToken) => db::notification::repo::delete_token(db, &device.token).await?,
+                    Err(PushError::Other(err)) => {
+                        eprintln!("Push {} failed: {:?}", notification.id, err);
+                        errored = true;
+                    }
+                }
+            }
+            if errored && !delivered {
+                failed.push(notification.id);
+            }
+        }
+
+        if claimed < PUSH_BATCH {
+            // released only now, so this run does not claim them again
+            db::notification::repo::release_unpushed(db, &failed).await?;
+            return Ok(sent);
+        }
+    }
+}
+
+/// Messages new notifications to the Telegram chat of users who opted in, in
+/// the language they opted in with. Users who blocked the bot are opted out.
+/// A message that failed otherwise is released for the next run.
+pub async fn deliver_telegram(db: &PgPool, push: &Push) -> Result<u64, AppError> {
+    let mut sent = 0;
+    let mut failed = Vec::new();
+    loop {
+        let since = OffsetDateTime::now_utc() - PUSH_WINDOW;
+        let notifications = db::notification::repo::claim_untelegrammed(db, since, PUSH_BATCH, MAX_PUSH_ATTEMPTS).await?;
+        let claimed = notifications.len() as i64;
+
+        for mut entity in notifications {
+            let lang: LanguageIdentifier = entity.telegram_language.parse().unwrap_or_default();
+            translation::service::localize_notifications(db, slice::from_mut(&mut entity.notification), &lang).await?;
+            let (title, body) = render(&entity.notification, &lang);
+            let message = PushMessage { title, body, data: data(&entity.notification) };
+
+            match push.telegram().send(&entity.telegram_id.to_string(), &message).await {
+                Ok(()) => sent += 1,
+                Err(PushError::InvalidToken) => {
+                    db::notification::repo::disable_telegram(db, entity.notification.user_id).await?
+                }
+                Err(PushError::Other(err)) => {
+                    eprintln!("Telegram message {} failed: {:?}", entity.notification.id, err);
+                    failed.push(entity.notification.id);
+                }
+            }
+        }
+
+        if claimed < PUSH_BATCH {
+            // released only now, so this run does not claim them again
+            db::notification::repo::release_untelegrammed(db, &failed).await?;
+            return Ok(sent);
+        }
+    }
+}
+
+fn data(notification: &NotificationEntity) -> Vec<(&'static str, String)> {
+    let kind = match notification.kind {
+        NotificationKind::NewLesson => "new_lesson",
+        NotificationKind::StreakAtRisk => "streak_at_risk",
+        NotificationKind::WeeklySummary => "weekly_summary"
+    };
+    let mut data = vec![("notification_id", notification.id.to_string()), ("kind", kind.to_string())];
+    if let Some(lesson_id) = notification.lesson_id {
+        data.push(("lesson_id", lesson_id.to_string()));
+    }
+    if let Some(topic_id) = notification.topic_id {
+        data.push(("topic_id", topic_id.to_string()));
+    }
+    data
+}
@@ -0,0 +1,63 @@
+use crate::db;
+use crate::db::review::entity::ReviewWithUserEntity;
+use crate::error::AppError;
+use fluent_templates::LanguageIdentifier;
+use sqlx::{PgConnection, PgPool};
+
+/// Recomputes the rating aggregates of the lesson and its topic after one of
+/// its reviews changed.
+pub async fn recount(connection: &mut PgConnection, lesson_id: i64) -> Result<(), AppError> {
+    if let Some(topic_id) = db::lesson::repo::recount_rating(connection, lesson_id).await? {
+        db::topic::repo::recount_rating(connection, topic_id).await?;
+    }
+    Ok(())
+}
+
+pub async fn page(
+    db: &PgPool,
+    page: u32,
+    limit: u32,
+    lesson_id: Option<i64>,
+    hidden: Option<bool>
+) -> Result<(Vec<ReviewWithUserEntity>, u64), AppError> {
+    let offset = (page - 1) * limit;
+
+    let items = db::review::repo::page_all(db, limit, offset, lesson_id, hidden).await?;
+    let total = db::review::repo::count_all(db, lesson_id, hidden).await?;
+
+    Ok(
+        (items, total as u64)
+    )
+}
+
+/// Leaves the review out of the lesson's reviews and ratings.
+pub async fn hide(
+    db: &PgPool,
+    id: i64,
+    admin_id: i64,
+    lang: LanguageIdentifier
+) -> Result<ReviewWithUserEntity, AppError> {
+    let mut tx = db.begin().await?;
+    let lesson_id = db::review::repo::hide(&mut tx, id, admin_id)
+        .await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
+    recount(&mut tx, lesson_id).await?;
+    tx.commit().await?;
+
+    db::review::repo::get(db, id).await?.ok_or(AppError::NotFound(lang))
+}
+
+pub async fn unhide(
+    db: &PgPool,
+    id: i64,
+    lang: LanguageIdentifier
+) -> Result<ReviewWithUserEntity, AppError> {
+    let mut tx = db.begin().await?;
+    let lesson_id = db::review::repo::unhide(&mut tx, id)
+        .await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
+    recount(&mut tx, lesson_id).await?;
+    tx.commit().await?;
+
+    db::review::repo::get(db, id).await?.ok_or(AppError::NotFound(lang))
+}
@@ -0,0 +1,30 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+use crate::module::common::base::FileResponse;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RevisionChangeResponse {
+    /// `title`, `description`, `cover_image_path`, `audio_path`, `duration` or `file_size`
+    pub field: String,
+    pub from: Option<String>,
+    pub to: Option<String>
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RevisionResponse {
+    pub lesson_id: i64,
+    pub revision: i32,
+    /// Admin who made the change, `null` for history recorded before revisions existed
+    pub admin_id: Option<i64>,
+    pub title: String,
+    pub description: Option<String>,
+    pub cover_image_path: Option<String>,
+    pub audio: FileResponse,
+    /// Fields that differ from the previous revision
+    pub changes: Vec<RevisionChangeResponse>,
+    /// Revision this one rolled back to
+    pub restored_from: Option<i32>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime
+}
@@ -0,0 +1,28 @@
+use crate::db::revision::entity::RevisionEntity;
+use crate::module::common::base::FileResponse;
+use crate::module::common::revision::dto::{RevisionChangeResponse, RevisionResponse};
+
+pub fn to_response(entity: RevisionEntity) -> RevisionResponse {
+    RevisionResponse {
+        lesson_id: entity.lesson_id,
+        revision: entity.revision,
+        admin_id: entity.admin_id,
+        title: entity.title,
+        description: entity.description,
+        cover_image_path: entity.cover_image_path,
+        audio: FileResponse {
+            path: entity.audio_path,
+            size: entity.file_size,
+            duration: entity.duration
+        },
+        changes: entity.changes.0.into_iter()
+            .map(|change| RevisionChangeResponse {
+                field: change.field,
+                from: change.from,
+                to: change.to
+            })
+            .collect(),
+        restored_from: entity.restored_from,
+        created_at: entity.created_at
+    }
+}
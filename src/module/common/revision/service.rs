@@ -0,0 +1,64 @@
+use crate::db;
+use crate::db::lesson::entity::{LessonEntity, LessonInput};
+use crate::db::revision::entity::{RevisionChange, RevisionEntity, RevisionInput};
+use crate::error::AppError;
+use fluent_templates::LanguageIdentifier;
+use sqlx::PgPool;
+
+fn change<T: ToString + PartialEq>(
+    changes: &mut Vec<RevisionChange>,
+    field: &str,
+    from: Option<&T>,
+    to: Option<&T>
+) {
+    if from != to {
+        changes.push(RevisionChange {
+            field: field.to_string(),
+            from: from.map(T::to_string),
+            to: to.map(T::to_string)
+        });
+    }
+}
+
+/// Snapshot of `lesson` with the fields that differ from `old`, or all set
+/// fields for a new lesson.
+pub fn input(
+    lesson_id: i64,
+    old: Option<&LessonEntity>,
+    lesson: &LessonInput,
+    admin_id: i64,
+    restored_from: Option<i32>
+) -> RevisionInput {
+    let mut changes = vec![];
+    change(&mut changes, "title", old.map(|o| &o.title), Some(&lesson.title));
+    change(&mut changes, "description", old.and_then(|o| o.description.as_ref()), lesson.description.as_ref());
+    change(&mut changes, "cover_image_path", old.and_then(|o| o.cover_image_path.as_ref()), lesson.cover_image_path.as_ref());
+    change(&mut changes, "audio_path", old.map(|o| &o.audio_path), Some(&lesson.audio_path));
+    change(&mut changes, "duration", old.map(|o| &o.duration), Some(&lesson.duration));
+    change(&mut changes, "file_size", old.map(|o| &o.file_size), Some(&lesson.file_size));
+
+    RevisionInput {
+        lesson_id,
+        admin_id,
+        title: lesson.title.clone(),
+        description: lesson.description.clone(),
+        cover_image_path: lesson.cover_image_path.clone(),
+        audio_path: lesson.audio_path.clone(),
+        duration: lesson.duration,
+        file_size: lesson.file_size,
+        changes,
+        restored_from
+    }
+}
+
+pub async fn history(
+    db: &PgPool,
+    lesson_id: i64,
+    lang: LanguageIdentifier
+) -> Result<Vec<RevisionEntity>, AppError> {
+    db::lesson::repo::get_by_id(db, lesson_id)
+        .await?
+        .ok_or(AppError::NotFound(lang))?;
+
+    Ok(db::revision::repo::get_by_lesson_id(db, lesson_id).await?)
+}
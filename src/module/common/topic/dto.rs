@@ -11,12 +11,17 @@ pub struct CommonTopicResponse {
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
     pub lesson_count: i64,
-    pub total_duration: i64
+    pub total_duration: i64,
+    /// Mean of the visible ratings of its lessons, 0 without any
+    pub rating_average: f64,
+    pub rating_count: i64
 }
 
 #[derive(Deserialize, PartialEq, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QuerySort {
     SnipCount,
-    CreatedAt
+    CreatedAt,
+    /// Average rating
+    Rating
 }
\ No newline at end of file
@@ -9,6 +9,8 @@ pub fn to_response(entity: TopicEntity) -> CommonTopicResponse {
         cover_image_path: entity.cover_image_path,
         created_at: entity.created_at,
         lesson_count: entity.lesson_count,
-        total_duration: entity.total_duration
+        total_duration: entity.total_duration,
+        rating_average: entity.rating_average,
+        rating_count: entity.rating_count
     }
 }
\ No newline at end of file
@@ -1,7 +1,8 @@
 use crate::db::topic::entity::{TopicInput, TopicWithAuthor};
 use crate::error::topic::TopicError;
 use crate::error::AppError;
-use crate::module::common::enums::UserProgressStatus;
+use crate::module::common::enums::{PublicationStatus, UserProgressStatus};
+use crate::module::common::{file, lesson};
 use crate::module::common::paging::QueryOrder;
 use crate::module::common::topic::dto::QuerySort;
 use crate::module::user::topic::dto::TopicCursor;
@@ -17,13 +18,23 @@ pub async fn create(
     title: String,
     description: Option<String>,
     cover_image_path: Option<String>,
+    publication_status: Option<PublicationStatus>,
+    publish_at: Option<OffsetDateTime>,
     lang: LanguageIdentifier
 ) -> Result<TopicWithAuthor> {
+    let publication_status = publication_status.unwrap_or(PublicationStatus::Draft);
+    lesson::service::check_schedule(publication_status, publish_at, &lang)?;
+    if let Some(cover_image_path) = &cover_image_path {
+        file::service::ensure_completed(db, cover_image_path, lang.clone()).await?;
+    }
+
     let topic = TopicInput {
         author_id,
         title,
         description,
-        cover_image_path
+        cover_image_path,
+        publication_status,
+        publish_at
     };
     let entity = db::topic::repo::insert(
         db,
@@ -42,13 +53,31 @@ pub async fn update(
     title: String,
     description: Option<String>,
     cover_image_path: Option<String>,
+    publication_status: Option<PublicationStatus>,
+    publish_at: Option<OffsetDateTime>,
     lang: LanguageIdentifier
 ) -> Result<TopicWithAuthor> {
+    let old_topic = db::topic::repo::get_by_id(db, id).await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
+
+    // without a status the publication state is left as it is
+    let (publication_status, publish_at) = match publication_status {
+        Some(status) => (status, publish_at),
+        None => (old_topic.topic.publication_status, old_topic.topic.publish_at)
+    };
+    lesson::service::check_schedule(publication_status, publish_at, &lang)?;
+    if let Some(cover_image_path) = &cover_image_path
+        && old_topic.topic.cover_image_path.as_ref() != Some(cover_image_path) {
+        file::service::ensure_completed(db, cover_image_path, lang.clone()).await?;
+    }
+
     let topic = TopicInput {
         author_id: 1,
         title,
         description,
-        cover_image_path
+        cover_image_path,
+        publication_status,
+        publish_at
     };
     db::topic::repo::update(
         db,
@@ -125,6 +154,7 @@ pub async fn page_cursor(
             TopicCursor {
                 id: last.topic.id,
                 snip_count: last.topic.snip_count,
+                rating_average: last.topic.rating_average,
                 created_at: last.topic.created_at
             }
         )
@@ -156,13 +186,40 @@ pub async fn delete(
         &mut tx, id
     ).await?;
     
-    if let Some(topic) = topic && topic.lesson_count != 0{ 
+    // `lesson_count` only counts published lessons, drafts block deletion too
+    if topic.is_some() && db::lesson::repo::exists_in_topic(&mut tx, id).await? {
         return Err(TopicError::TopicHasLesson(lang).into())
     }
     tx.commit().await?;
     Ok(())
 }
 
+/// Reorders all lessons of a topic. `lesson_ids` must contain every active
+/// lesson of the topic exactly once.
+pub async fn reorder_lessons(
+    db: &PgPool,
+    id: i64,
+    lesson_ids: Vec<i64>,
+    lang: LanguageIdentifier
+) -> Result<()>{
+    db::topic::repo::get_by_id(db, id)
+        .await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
+
+    let mut tx = db.begin().await?;
+    let mut current = db::lesson::repo::lock_topic_lesson_ids(&mut tx, id).await?;
+    let mut requested = lesson_ids.clone();
+    current.sort_unstable();
+    requested.sort_unstable();
+    if current != requested {
+        return Err(TopicError::LessonOrderMismatch(lang).into())
+    }
+
+    db::lesson::repo::update_positions(&mut tx, id, &lesson_ids).await?;
+    tx.commit().await?;
+    Ok(())
+}
+
 pub async fn deleted(
     db: &PgPool,
     since: OffsetDateTime
@@ -171,3 +228,13 @@ pub async fn deleted(
     Ok(deleted_since)
 }
 
+pub async fn published(
+    db: &PgPool,
+    since: OffsetDateTime
+) -> std::result::Result<Vec<(i64, OffsetDateTime)>, AppError> {
+    Ok(db::topic::repo::published(db, since).await?)
+}
+
+pub async fn publish_due(db: &PgPool) -> Result<u64> {
+    Ok(db::topic::repo::publish_due(db).await?)
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct TranscriptSegmentRequest {
+    #[validate(range(min = 0))]
+    pub start_ms: i64,
+    #[validate(range(min = 0))]
+    pub end_ms: i64,
+    #[validate(length(min = 1, max = 2000))]
+    pub text: String
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct TranscriptRequest {
+    #[validate(nested)]
+    pub segments: Vec<TranscriptSegmentRequest>
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TranscriptSegmentResponse {
+    pub position: i32,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub text: String
+}
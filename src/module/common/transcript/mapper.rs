@@ -0,0 +1,11 @@
+use crate::db::transcript::entity::TranscriptSegmentEntity;
+use crate::module::common::transcript::dto::TranscriptSegmentResponse;
+
+pub fn to_response(entity: TranscriptSegmentEntity) -> TranscriptSegmentResponse {
+    TranscriptSegmentResponse {
+        position: entity.position,
+        start_ms: entity.start_ms,
+        end_ms: entity.end_ms,
+        text: entity.text
+    }
+}
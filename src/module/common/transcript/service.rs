@@ -0,0 +1,114 @@
+use crate::db::transcript::entity::{TranscriptHitEntity, TranscriptSegmentEntity, TranscriptSegmentInput};
+use crate::error::lesson::LessonError;
+use crate::error::AppError;
+use crate::module::user::lesson::dto::TranscriptCursor;
+use crate::utils::subtitle;
+use crate::{db, utils};
+use fluent_templates::LanguageIdentifier;
+use sqlx::PgPool;
+
+/// Replaces the transcript of a lesson. Segments must be non-empty, ordered by
+/// start time and fall inside the lesson duration.
+pub async fn replace(
+    db: &PgPool,
+    lesson_id: i64,
+    segments: Vec<TranscriptSegmentInput>,
+    lang: LanguageIdentifier
+) -> Result<Vec<TranscriptSegmentEntity>, AppError> {
+    let lesson = db::lesson::repo::get_by_id(db, lesson_id)
+        .await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
+
+    let mut previous_start = 0;
+    for (index, segment) in segments.iter().enumerate() {
+        let valid = segment.start_ms >= previous_start
+            && segment.end_ms > segment.start_ms
+            && (lesson.duration == 0 || segment.end_ms <= lesson.duration)
+            && !segment.text.trim().is_empty();
+        if !valid {
+            return Err(LessonError::InvalidTranscript(lang, index + 1).into());
+        }
+        previous_start = segment.start_ms;
+    }
+
+    let mut tx = db.begin().await?;
+    db::transcript::repo::delete_by_lesson_id(&mut tx, lesson_id).await?;
+    db::transcript::repo::insert_all(&mut tx, lesson_id, segments).await?;
+    tx.commit().await?;
+
+    Ok(db::transcript::repo::get_by_lesson_id(db, lesson_id, false).await?)
+}
+
+/// Parses a WebVTT or SRT file and replaces the lesson transcript with its cues.
+pub async fn import(
+    db: &PgPool,
+    lesson_id: i64,
+    content: &str,
+    lang: LanguageIdentifier
+) -> Result<Vec<TranscriptSegmentEntity>, AppError> {
+    let cues = subtitle::parse(content)
+        .map_err(|block| LessonError::InvalidTranscript(lang.clone(), block))?;
+
+    let segments = cues.into_iter()
+        .map(|cue| TranscriptSegmentInput {
+            start_ms: cue.start_ms,
+            end_ms: cue.end_ms,
+            text: cue.text
+        })
+        .collect();
+
+    replace(db, lesson_id, segments, lang).await
+}
+
+pub async fn get(
+    db: &PgPool,
+    lesson_id: i64,
+    live_only: bool
+) -> Result<Vec<TranscriptSegmentEntity>, AppError> {
+    Ok(db::transcript::repo::get_by_lesson_id(db, lesson_id, live_only).await?)
+}
+
+pub async fn delete(
+    db: &PgPool,
+    lesson_id: i64
+) -> Result<u64, AppError> {
+    let mut tx = db.begin().await?;
+    let deleted = db::transcript::repo::delete_by_lesson_id(&mut tx, lesson_id).await?;
+    tx.commit().await?;
+    Ok(deleted)
+}
+
+pub async fn search(
+    db: &PgPool,
+    search: String,
+    lesson_id: Option<i64>,
+    limit: u32,
+    cursor: Option<String>
+) -> Result<(Vec<TranscriptHitEntity>, Option<String>), AppError> {
+    let Some(tsquery) = utils::search::to_tsquery(&search) else {
+        return Ok((vec![], None));
+    };
+
+    let mut items = db::transcript::repo::search(
+        db,
+        tsquery,
+        lesson_id,
+        limit + 1,
+        utils::cursor::decode(cursor)
+    ).await?;
+
+    let next_cursor = if items.len() == (limit + 1) as usize {
+        items.remove(limit as usize);
+        let last = items.last().unwrap();
+        utils::cursor::encode(
+            TranscriptCursor {
+                rank: last.rank,
+                id: last.id
+            }
+        )
+    } else { None };
+
+    Ok(
+        (items, next_cursor)
+    )
+}
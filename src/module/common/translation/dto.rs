@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Deserialize, IntoParams)]
+pub struct TranslationParam {
+    pub id: i64,
+    /// Language tag, stored by its language subtag (`uz-Latn` is saved as `uz`)
+    pub locale: String
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct TranslationRequest {
+    /// Author name, or topic and lesson title
+    #[validate(length(min = 3))]
+    pub title: String,
+    /// Ignored for authors
+    pub description: Option<String>
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TranslationResponse {
+    pub locale: String,
+    pub title: String,
+    pub description: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime
+}
@@ -0,0 +1,11 @@
+use crate::db::translation::entity::TranslationEntity;
+use crate::module::common::translation::dto::TranslationResponse;
+
+pub fn to_response(entity: TranslationEntity) -> TranslationResponse {
+    TranslationResponse {
+        locale: entity.locale,
+        title: entity.title,
+        description: entity.description,
+        updated_at: entity.updated_at
+    }
+}
@@ -0,0 +1,3 @@
+pub mod service;
+pub mod dto;
+pub mod mapper;
\ No newline at end of file
@@ -0,0 +1,197 @@
+use crate::db;
+use crate::db::author::entity::AuthorEntity;
+use crate::db::lesson::entity::LessonWithAuthorTopic;
+use crate::db::notification::entity::NotificationEntity;
+use crate::db::snip::entity::SnipEntityWithLesson;
+use crate::db::topic::entity::TopicWithAuthor;
+use crate::db::translation::entity::{TranslationEntity, TranslationInput};
+use crate::error::AppError;
+use crate::module::common::enums::TranslationKind;
+use crate::string_keys::strings;
+use crate::utils::t;
+use fluent_templates::LanguageIdentifier;
+use sqlx::PgPool;
+use std::collections::HashMap;
+
+/// Translations are stored and looked up by language subtag only.
+pub fn locale(lang: &LanguageIdentifier) -> String {
+    lang.language.as_str().to_string()
+}
+
+fn parse_locale(locale: &str, lang: &LanguageIdentifier) -> Result<String, AppError> {
+    locale.parse::<LanguageIdentifier>()
+        .ok()
+        .filter(|l| !l.language.is_empty())
+        .map(|l| self::locale(&l))
+        .ok_or_else(|| AppError::BadRequest { lang: lang.clone(), message: t(lang, strings::BAD_REQUEST) })
+}
+
+async fn translations(
+    db: &PgPool,
+    kind: TranslationKind,
+    owner_ids: Vec<i64>,
+    lang: &LanguageIdentifier
+) -> Result<HashMap<i64, TranslationEntity>, AppError> {
+    if owner_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    Ok(
+        db::translation::repo::get_by_owner_ids(db, kind, &owner_ids, &locale(lang))
+            .await?
+            .into_iter()
+            .map(|translation| (translation.owner_id, translation))
+            .collect()
+    )
+}
+
+/// Replaces `title` and `description` with the translation of `id`, keeping
+/// the base values for anything that is not translated.
+fn apply(
+    translations: &HashMap<i64, TranslationEntity>,
+    id: Option<i64>,
+    title: &mut String,
+    description: Option<&mut Option<String>>
+) {
+    let Some(translation) = id.and_then(|id| translations.get(&id)) else {
+        return;
+    };
+    title.clone_from(&translation.title);
+    if let (Some(description), Some(translated)) = (description, &translation.description) {
+        *description = Some(translated.clone());
+    }
+}
+
+pub async fn localize_authors(
+    db: &PgPool,
+    authors: &mut [AuthorEntity],
+    lang: &LanguageIdentifier
+) -> Result<(), AppError> {
+    let names = translations(db, TranslationKind::Author, authors.iter().map(|a| a.id).collect(), lang).await?;
+    for author in authors.iter_mut() {
+        apply(&names, Some(author.id), &mut author.name, None);
+    }
+    Ok(())
+}
+
+pub async fn localize_topics(
+    db: &PgPool,
+    topics: &mut [TopicWithAuthor],
+    lang: &LanguageIdentifier
+) -> Result<(), AppError> {
+    let titles = translations(db, TranslationKind::Topic, topics.iter().map(|t| t.topic.id).collect(), lang).await?;
+    let names = translations(db, TranslationKind::Author, topics.iter().map(|t| t.topic.author_id).collect(), lang).await?;
+    for topic in topics.iter_mut() {
+        apply(&titles, Some(topic.topic.id), &mut topic.topic.title, Some(&mut topic.topic.description));
+        apply(&names, Some(topic.topic.author_id), &mut topic.author_name, None);
+    }
+    Ok(())
+}
+
+pub async fn localize_lessons(
+    db: &PgPool,
+    lessons: &mut [LessonWithAuthorTopic],
+    lang: &LanguageIdentifier
+) -> Result<(), AppError> {
+    let titles = translations(db, TranslationKind::Lesson, lessons.iter().map(|l| l.lesson.id).collect(), lang).await?;
+    let topics = translations(db, TranslationKind::Topic, lessons.iter().filter_map(|l| l.lesson.topic_id).collect(), lang).await?;
+    let names = translations(db, TranslationKind::Author, lessons.iter().map(|l| l.lesson.author_id).collect(), lang).await?;
+    for lesson in lessons.iter_mut() {
+        apply(&titles, Some(lesson.lesson.id), &mut lesson.lesson.title, Some(&mut lesson.lesson.description));
+        if let Some(topic_title) = lesson.topic_title.as_mut() {
+            apply(&topics, lesson.lesson.topic_id, topic_title, Some(&mut lesson.topic_description));
+        }
+        apply(&names, Some(lesson.lesson.author_id), &mut lesson.author_name, None);
+    }
+    Ok(())
+}
+
+pub async fn localize_snips(
+    db: &PgPool,
+    snips: &mut [&mut SnipEntityWithLesson],
+    lang: &LanguageIdentifier
+) -> Result<(), AppError> {
+    let titles = translations(db, TranslationKind::Lesson, snips.iter().map(|s| s.snip.lesson_id).collect(), lang).await?;
+    let topics = translations(db, TranslationKind::Topic, snips.iter().filter_map(|s| s.snip.topic_id).collect(), lang).await?;
+    let names = translations(db, TranslationKind::Author, snips.iter().map(|s| s.snip.author_id).collect(), lang).await?;
+    for snip in snips.iter_mut() {
+        apply(&titles, Some(snip.snip.lesson_id), &mut snip.lesson_title, Some(&mut snip.lesson_description));
+        if let Some(topic_title) = snip.topic_title.as_mut() {
+            apply(&topics, snip.snip.topic_id, topic_title, Some(&mut snip.topic_description));
+        }
+        apply(&names, Some(snip.snip.author_id), &mut snip.author_name, None);
+    }
+    Ok(())
+}
+
+pub async fn localize_notifications(
+    db: &PgPool,
+    notifications: &mut [NotificationEntity],
+    lang: &LanguageIdentifier
+) -> Result<(), AppError> {
+    let titles = translations(db, TranslationKind::Lesson, notifications.iter().filter_map(|n| n.lesson_id).collect(), lang).await?;
+    let topics = translations(db, TranslationKind::Topic, notifications.iter().filter_map(|n| n.topic_id).collect(), lang).await?;
+    for notification in notifications.iter_mut() {
+        if let Some(lesson_title) = notification.lesson_title.as_mut() {
+            apply(&titles, notification.lesson_id, lesson_title, None);
+        }
+        if let Some(topic_title) = notification.topic_title.as_mut() {
+            apply(&topics, notification.topic_id, topic_title, None);
+        }
+    }
+    Ok(())
+}
+
+async fn check_owner(
+    db: &PgPool,
+    kind: TranslationKind,
+    owner_id: i64,
+    lang: &LanguageIdentifier
+) -> Result<(), AppError> {
+    let exists = match kind {
+        TranslationKind::Author => db::author::repo::get_by_id(db, owner_id).await?.is_some(),
+        TranslationKind::Topic => db::topic::repo::get_by_id(db, owner_id).await?.is_some(),
+        TranslationKind::Lesson => db::lesson::repo::get_by_id(db, owner_id).await?.is_some()
+    };
+    if !exists {
+        return Err(AppError::NotFound(lang.clone()));
+    }
+    Ok(())
+}
+
+pub async fn list(
+    db: &PgPool,
+    kind: TranslationKind,
+    owner_id: i64,
+    lang: LanguageIdentifier
+) -> Result<Vec<TranslationEntity>, AppError> {
+    check_owner(db, kind, owner_id, &lang).await?;
+    Ok(db::translation::repo::get_by_owner_id(db, kind, owner_id).await?)
+}
+
+pub async fn upsert(
+    db: &PgPool,
+    kind: TranslationKind,
+    owner_id: i64,
+    locale: String,
+    translation: TranslationInput,
+    lang: LanguageIdentifier
+) -> Result<TranslationEntity, AppError> {
+    let locale = parse_locale(&locale, &lang)?;
+    check_owner(db, kind, owner_id, &lang).await?;
+    Ok(db::translation::repo::upsert(db, kind, owner_id, &locale, translation).await?)
+}
+
+pub async fn delete(
+    db: &PgPool,
+    kind: TranslationKind,
+    owner_id: i64,
+    locale: String,
+    lang: LanguageIdentifier
+) -> Result<(), AppError> {
+    let locale = parse_locale(&locale, &lang)?;
+    if db::translation::repo::delete(db, kind, owner_id, &locale).await? == 0 {
+        return Err(AppError::NotFound(lang));
+    }
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use crate::db;
+use crate::db::trash::entity::TrashItemEntity;
+use crate::error::trash::TrashError;
+use crate::error::AppError;
+use crate::module::common::enums::TrashKind;
+use crate::utils::CONFIG;
+use fluent_templates::LanguageIdentifier;
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+/// When an item trashed at `deleted_at` is permanently deleted.
+pub fn purge_at(deleted_at: OffsetDateTime) -> OffsetDateTime {
+    deleted_at + time::Duration::days(CONFIG.trash_retention_days)
+}
+
+/// A live item took the name of the trashed one in the meantime.
+fn restore_error(error: sqlx::Error, lang: LanguageIdentifier) -> AppError {
+    match &error {
+        sqlx::Error::Database(db_error) if db_error.is_unique_violation() => {
+            TrashError::TrashRestoreConflict(lang).into()
+        }
+        _ => error.into()
+    }
+}
+
+pub async fn page(
+    db: &PgPool,
+    page: u32,
+    limit: u32,
+    kind: Option<TrashKind>
+) -> Result<(Vec<TrashItemEntity>, u64), AppError> {
+    let offset = (page - 1) * limit;
+
+    let items = db::trash::repo::page(db, limit, offset, kind).await?;
+    let total = db::trash::repo::count(db, kind).await?;
+
+    Ok(
+        (items, total as u64)
+    )
+}
+
+/// Restores a trashed author, lesson or topic and recomputes the counters
+/// its deletion changed. An author brings back the topics deleted with it.
+pub async fn restore(
+    db: &PgPool,
+    kind: TrashKind,
+    id: i64,
+    lang: LanguageIdentifier
+) -> Result<(), AppError> {
+    let item = db::trash::repo::get(db, kind, id)
+        .await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
+    if item.parent_deleted {
+        return Err(TrashError::TrashParentDeleted(lang).into());
+    }
+
+    let mut tx = db.begin().await?;
+    match kind {
+        TrashKind::Author => {
+            let topic_ids = db::topic::repo::restore_by_author_id(&mut tx, id)
+                .await
+                .map_err(|e| restore_error(e, lang.clone()))?;
+            db::author::repo::restore(&mut tx, id)
+                .await
+                .map_err(|e| restore_error(e, lang.clone()))?
+                .ok_or(AppError::NotFound(lang))?;
+
+            for topic_id in topic_ids {
+                db::topic::repo::recount_stats(&mut tx, topic_id).await?;
+            }
+            db::author::repo::recount_stats(&mut tx, id).await?;
+        }
+        TrashKind::Topic => {
+            db::topic::repo::restore(&mut tx, id)
+                .await
+                .map_err(|e| restore_error(e, lang.clone()))?
+                .ok_or(AppError::NotFound(lang))?;
+
+            db::topic::repo::recount_stats(&mut tx, id).await?;
+        }
+        TrashKind::Lesson => {
+            let lesson = db::lesson::repo::restore(&mut tx, id)
+                .await
+                .map_err(|e| restore_error(e, lang.clone()))?
+                .ok_or(AppError::NotFound(lang))?;
+
+            db::lesson::repo::recount_snip_count(&mut tx, id).await?;
+            if let Some(topic_id) = lesson.topic_id {
+                db::topic::repo::recount_stats(&mut tx, topic_id).await?;
+            }
+            db::author::repo::recount_stats(&mut tx, lesson.author_id).await?;
+        }
+    }
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Permanently deletes everything trashed longer than the retention period,
+/// children before parents. Their assets are left for the GC. Returns the
+/// number of purged snips, lessons, topics and authors.
+pub async fn purge(db: &PgPool) -> Result<(u64, u64, u64, u64), AppError> {
+    let before = OffsetDateTime::now_utc() - time::Duration::days(CONFIG.trash_retention_days);
+
+    let mut tx = db.begin().await?;
+    let snips = db::trash::repo::purge_snips(&mut tx, before).await?;
+    let lessons = db::trash::repo::purge_lessons(&mut tx, before).await?;
+
+    let mut topic_ids: Vec<i64> = lessons.iter().filter_map(|lesson| lesson.1).collect();
+    topic_ids.sort_unstable();
+    topic_ids.dedup();
+    // snips of purged lessons were still counted by their topic
+    for topic_id in topic_ids {
+        db::topic::repo::recount_stats(&mut tx, topic_id).await?;
+    }
+
+    let topics = db::trash::repo::purge_topics(&mut tx, before).await?;
+    let authors = db::trash::repo::purge_authors(&mut tx, before).await?;
+    tx.commit().await?;
+
+    Ok((snips, lessons.len() as u64, topics, authors))
+}
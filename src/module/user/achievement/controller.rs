@@ -0,0 +1,31 @@
+use crate::error::AppError;
+use crate::extractor::accept_language::AcceptLanguage;
+use crate::module::common::base::BaseResponse;
+use crate::module::user::achievement::dto::AchievementResponse;
+use crate::module::user::achievement::mapper;
+use crate::module::user::achievement::service;
+use crate::state::AppState;
+use crate::utils::jwt::Claims;
+use axum::extract::State;
+use axum::Extension;
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/achievement",
+    security(("bearerAuth" = [])),
+    responses((status = 200, body = Vec<AchievementResponse>)),
+    tag = "Achievement"
+)]
+pub async fn list_achievements(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<Vec<AchievementResponse>>, AppError> {
+    let achievements = service::list(&state.db, claims.sub).await?;
+
+    Ok(
+        BaseResponse::success(
+            achievements.into_iter().map(|a| mapper::to_response(a, &lang)).collect()
+        )
+    )
+}
@@ -0,0 +1,17 @@
+use crate::module::common::enums::Achievement;
+use serde::Serialize;
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct AchievementResponse {
+    pub achievement: Achievement,
+    pub title: String,
+    pub description: String,
+    pub target: i64,
+    /// Capped at `target`
+    pub progress: i64,
+    pub earned: bool,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub earned_at: Option<OffsetDateTime>
+}
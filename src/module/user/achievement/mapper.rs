@@ -0,0 +1,16 @@
+use crate::module::common::achievement::service::AchievementStatus;
+use crate::module::user::achievement::dto::AchievementResponse;
+use crate::utils::t;
+use fluent_templates::LanguageIdentifier;
+
+pub fn to_response(status: AchievementStatus, lang: &LanguageIdentifier) -> AchievementResponse {
+    AchievementResponse {
+        achievement: status.rule.achievement,
+        title: t(lang, status.rule.title),
+        description: t(lang, status.rule.description),
+        target: status.rule.target,
+        progress: status.progress,
+        earned: status.earned_at.is_some(),
+        earned_at: status.earned_at
+    }
+}
@@ -0,0 +1,11 @@
+use axum::{Router, middleware};
+use axum::routing::get;
+use crate::middleware::auth::user_auth_middleware;
+use crate::module::user::achievement::controller::list_achievements;
+use crate::state::AppState;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/achievement", get(list_achievements))
+        .layer(middleware::from_fn(user_auth_middleware))
+}
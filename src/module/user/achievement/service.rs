@@ -0,0 +1,10 @@
+use crate::error::AppError;
+use crate::module::common::achievement;
+use crate::module::common::achievement::service::AchievementStatus;
+use sqlx::PgPool;
+
+/// Every achievement with the user's progress towards it. Evaluating here
+/// too awards anything reached before the rules existed.
+pub async fn list(db: &PgPool, user_id: i64) -> Result<Vec<AchievementStatus>, AppError> {
+    achievement::service::evaluate(db, user_id).await
+}
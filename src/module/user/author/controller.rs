@@ -1,7 +1,9 @@
 use crate::error::AppError;
+use crate::extractor::accept_language::AcceptLanguage;
 use crate::module::common::author::service;
 use crate::module::common::base::{BaseResponse, DeletedParams, DeletedResponse};
 use crate::module::common::paging::CursorPagingResponse;
+use crate::module::common::translation;
 use crate::module::user::author::dto::{AuthorResponse, AuthorPaginationParams};
 use crate::module::user::author::mapper;
 use crate::state::AppState;
@@ -18,15 +20,17 @@ use axum::extract::State;
 )]
 pub async fn page_author(
     State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
     ValidatedQuery(params): ValidatedQuery<AuthorPaginationParams>
 ) -> Result<BaseResponse<CursorPagingResponse<AuthorResponse>>, AppError> {
-    let topics = service::page_cursor(
+    let mut topics = service::page_cursor(
         &state.db,
         params.limit,
         params.cursor,
         params.search
     ).await?;
-    
+    translation::service::localize_authors(&state.db, &mut topics.0, &lang).await?;
+
     Ok(
         BaseResponse::success(
             CursorPagingResponse::new(
@@ -0,0 +1,179 @@
+use crate::error::AppError;
+use crate::extractor::accept_language::AcceptLanguage;
+use crate::module::common::base::{BaseResponse, IdParam};
+use crate::module::common::paging::CursorPagingResponse;
+use crate::module::common::translation;
+use crate::module::user::follow::dto::{FollowPaginationParams, FollowedAuthorResponse, SubscribedTopicResponse};
+use crate::module::user::follow::mapper;
+use crate::module::user::follow::service;
+use crate::module::user::lesson::dto::LessonResponse;
+use crate::module::user::lesson::mapper as lesson_mapper;
+use crate::state::AppState;
+use crate::utils::extractors::{ValidatedPath, ValidatedQuery};
+use crate::utils::jwt::Claims;
+use axum::extract::State;
+use axum::Extension;
+
+#[utoipa::path(
+    post,
+    path = "/v1/user/author/{id}/follow",
+    security(("bearerAuth" = [])),
+    params(IdParam),
+    tag = "Follow"
+)]
+pub async fn follow_author(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<()>, AppError> {
+    service::follow_author(&state.db, claims.sub, id, lang).await?;
+    Ok(BaseResponse::success(()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/user/author/{id}/follow",
+    security(("bearerAuth" = [])),
+    params(IdParam),
+    tag = "Follow"
+)]
+pub async fn unfollow_author(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<()>, AppError> {
+    service::unfollow_author(&state.db, claims.sub, id, lang).await?;
+    Ok(BaseResponse::success(()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/user/topic/{id}/subscription",
+    security(("bearerAuth" = [])),
+    params(IdParam),
+    tag = "Follow"
+)]
+pub async fn subscribe_topic(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<()>, AppError> {
+    service::subscribe_topic(&state.db, claims.sub, id, lang).await?;
+    Ok(BaseResponse::success(()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/user/topic/{id}/subscription",
+    security(("bearerAuth" = [])),
+    params(IdParam),
+    tag = "Follow"
+)]
+pub async fn unsubscribe_topic(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<()>, AppError> {
+    service::unsubscribe_topic(&state.db, claims.sub, id, lang).await?;
+    Ok(BaseResponse::success(()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/following/author",
+    security(("bearerAuth" = [])),
+    params(FollowPaginationParams),
+    responses((status = 200, body = CursorPagingResponse<FollowedAuthorResponse>)),
+    tag = "Follow"
+)]
+pub async fn page_followed_authors(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedQuery(params): ValidatedQuery<FollowPaginationParams>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<CursorPagingResponse<FollowedAuthorResponse>>, AppError> {
+    let (mut authors, followed_at, next_cursor) = service::page_authors(
+        &state.db,
+        claims.sub,
+        params.limit,
+        params.cursor
+    ).await?;
+    translation::service::localize_authors(&state.db, &mut authors, &lang).await?;
+
+    Ok(
+        BaseResponse::success(
+            CursorPagingResponse::new(
+                authors.into_iter().zip(followed_at).map(|(a, at)| mapper::author_to_followed(a, at)).collect(),
+                next_cursor
+            )
+        )
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/following/topic",
+    security(("bearerAuth" = [])),
+    params(FollowPaginationParams),
+    responses((status = 200, body = CursorPagingResponse<SubscribedTopicResponse>)),
+    tag = "Follow"
+)]
+pub async fn page_subscribed_topics(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedQuery(params): ValidatedQuery<FollowPaginationParams>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<CursorPagingResponse<SubscribedTopicResponse>>, AppError> {
+    let (mut topics, subscribed_at, next_cursor) = service::page_topics(
+        &state.db,
+        claims.sub,
+        params.limit,
+        params.cursor
+    ).await?;
+    translation::service::localize_topics(&state.db, &mut topics, &lang).await?;
+
+    Ok(
+        BaseResponse::success(
+            CursorPagingResponse::new(
+                topics.into_iter().zip(subscribed_at).map(|(t, at)| mapper::topic_to_subscribed(t, at)).collect(),
+                next_cursor
+            )
+        )
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/following/feed",
+    security(("bearerAuth" = [])),
+    params(FollowPaginationParams),
+    responses((status = 200, body = CursorPagingResponse<LessonResponse>)),
+    tag = "Follow"
+)]
+pub async fn page_feed(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedQuery(params): ValidatedQuery<FollowPaginationParams>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<CursorPagingResponse<LessonResponse>>, AppError> {
+    let (mut lessons, next_cursor) = service::feed(
+        &state.db,
+        claims.sub,
+        params.limit,
+        params.cursor
+    ).await?;
+    translation::service::localize_lessons(&state.db, &mut lessons, &lang).await?;
+
+    Ok(
+        BaseResponse::success(
+            CursorPagingResponse::new(
+                lessons.into_iter().map(lesson_mapper::to_response).collect(),
+                next_cursor
+            )
+        )
+    )
+}
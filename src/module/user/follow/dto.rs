@@ -0,0 +1,43 @@
+use crate::module::common::author::dto::CommonAuthorResponse;
+use crate::module::user::topic::dto::TopicResponse;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct FollowPaginationParams {
+    #[param(example = json!(20))]
+    pub limit: u32,
+    pub cursor: Option<String>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FollowCursor {
+    pub id: i64,
+    #[serde(with = "time::serde::rfc3339")]
+    pub followed_at: OffsetDateTime
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FeedCursor {
+    pub id: i64,
+    #[serde(with = "time::serde::rfc3339")]
+    pub published_at: OffsetDateTime
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct FollowedAuthorResponse {
+    #[serde(flatten)]
+    pub author: CommonAuthorResponse,
+    #[serde(with = "time::serde::rfc3339")]
+    pub followed_at: OffsetDateTime
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct SubscribedTopicResponse {
+    #[serde(flatten)]
+    pub topic: TopicResponse,
+    #[serde(with = "time::serde::rfc3339")]
+    pub subscribed_at: OffsetDateTime
+}
@@ -0,0 +1,20 @@
+use crate::db::author::entity::AuthorEntity;
+use crate::db::topic::entity::TopicWithAuthor;
+use crate::module::common::author::mapper::to_response as author_to_response;
+use crate::module::user::follow::dto::{FollowedAuthorResponse, SubscribedTopicResponse};
+use crate::module::user::topic::mapper::to_response as topic_to_response;
+use time::OffsetDateTime;
+
+pub fn author_to_followed(entity: AuthorEntity, followed_at: OffsetDateTime) -> FollowedAuthorResponse {
+    FollowedAuthorResponse {
+        author: author_to_response(entity),
+        followed_at
+    }
+}
+
+pub fn topic_to_subscribed(entity: TopicWithAuthor, subscribed_at: OffsetDateTime) -> SubscribedTopicResponse {
+    SubscribedTopicResponse {
+        topic: topic_to_response(entity),
+        subscribed_at
+    }
+}
@@ -0,0 +1,20 @@
+use axum::{Router, middleware};
+use axum::routing::{delete, get, post};
+use crate::middleware::auth::user_auth_middleware;
+use crate::module::user::follow::controller::{
+    follow_author, page_feed, page_followed_authors, page_subscribed_topics, subscribe_topic,
+    unfollow_author, unsubscribe_topic
+};
+use crate::state::AppState;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/author/{id}/follow", post(follow_author))
+        .route("/author/{id}/follow", delete(unfollow_author))
+        .route("/topic/{id}/subscription", post(subscribe_topic))
+        .route("/topic/{id}/subscription", delete(unsubscribe_topic))
+        .route("/following/author", get(page_followed_authors))
+        .route("/following/topic", get(page_subscribed_topics))
+        .route("/following/feed", get(page_feed))
+        .layer(middleware::from_fn(user_auth_middleware))
+}
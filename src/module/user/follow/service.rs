@@ -0,0 +1,147 @@
+use crate::db;
+use crate::db::author::entity::AuthorEntity;
+use crate::db::lesson::entity::LessonWithAuthorTopic;
+use crate::db::topic::entity::TopicWithAuthor;
+use crate::error::AppError;
+use crate::module::common::chapter;
+use crate::module::user::follow::dto::{FeedCursor, FollowCursor};
+use crate::utils;
+use fluent_templates::LanguageIdentifier;
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+pub async fn follow_author(
+    db: &PgPool,
+    user_id: i64,
+    author_id: i64,
+    lang: LanguageIdentifier
+) -> Result<(), AppError> {
+    if !db::follow::repo::is_author_visible(db, author_id).await? {
+        return Err(AppError::NotFound(lang));
+    }
+    db::follow::repo::follow_author(db, user_id, author_id).await?;
+    Ok(())
+}
+
+pub async fn unfollow_author(
+    db: &PgPool,
+    user_id: i64,
+    author_id: i64,
+    lang: LanguageIdentifier
+) -> Result<(), AppError> {
+    if db::follow::repo::unfollow_author(db, user_id, author_id).await? == 0 {
+        return Err(AppError::NotFound(lang));
+    }
+    Ok(())
+}
+
+pub async fn subscribe_topic(
+    db: &PgPool,
+    user_id: i64,
+    topic_id: i64,
+    lang: LanguageIdentifier
+) -> Result<(), AppError> {
+    if !db::follow::repo::is_topic_visible(db, topic_id).await? {
+        return Err(AppError::NotFound(lang));
+    }
+    db::follow::repo::subscribe_topic(db, user_id, topic_id).await?;
+    Ok(())
+}
+
+pub async fn unsubscribe_topic(
+    db: &PgPool,
+    user_id: i64,
+    topic_id: i64,
+    lang: LanguageIdentifier
+) -> Result<(), AppError> {
+    if db::follow::repo::unsubscribe_topic(db, user_id, topic_id).await? == 0 {
+        return Err(AppError::NotFound(lang));
+    }
+    Ok(())
+}
+
+pub async fn page_authors(
+    db: &PgPool,
+    user_id: i64,
+    limit: u32,
+    cursor: Option<String>
+) -> Result<(Vec<AuthorEntity>, Vec<OffsetDateTime>, Option<String>), AppError> {
+    let mut items = db::follow::repo::page_authors(
+        db,
+        user_id,
+        limit + 1,
+        utils::cursor::decode(cursor)
+    ).await?;
+
+    let next_cursor = if items.len() == (limit + 1) as usize {
+        items.remove(limit as usize);
+        let last = items.last().unwrap();
+        utils::cursor::encode(FollowCursor {
+            id: last.follow_id,
+            followed_at: last.followed_at
+        })
+    } else { None };
+
+    let (authors, followed_at) = items
+        .into_iter()
+        .map(|item| (item.author, item.followed_at))
+        .unzip();
+    Ok((authors, followed_at, next_cursor))
+}
+
+pub async fn page_topics(
+    db: &PgPool,
+    user_id: i64,
+    limit: u32,
+    cursor: Option<String>
+) -> Result<(Vec<TopicWithAuthor>, Vec<OffsetDateTime>, Option<String>), AppError> {
+    let mut items = db::follow::repo::page_topics(
+        db,
+        user_id,
+        limit + 1,
+        utils::cursor::decode(cursor)
+    ).await?;
+
+    let next_cursor = if items.len() == (limit + 1) as usize {
+        items.remove(limit as usize);
+        let last = items.last().unwrap();
+        utils::cursor::encode(FollowCursor {
+            id: last.follow_id,
+            followed_at: last.followed_at
+        })
+    } else { None };
+
+    let (topics, subscribed_at) = items
+        .into_iter()
+        .map(|item| (item.topic, item.followed_at))
+        .unzip();
+    Ok((topics, subscribed_at, next_cursor))
+}
+
+/// New lessons from the followed authors and subscribed topics. A lesson
+/// matching both is listed once.
+pub async fn feed(
+    db: &PgPool,
+    user_id: i64,
+    limit: u32,
+    cursor: Option<String>
+) -> Result<(Vec<LessonWithAuthorTopic>, Option<String>), AppError> {
+    let mut lessons = db::follow::repo::feed(
+        db,
+        user_id,
+        limit + 1,
+        utils::cursor::decode(cursor)
+    ).await?;
+
+    let next_cursor = if lessons.len() == (limit + 1) as usize {
+        lessons.remove(limit as usize);
+        let last = &lessons.last().unwrap().lesson;
+        last.published_at.and_then(|published_at| utils::cursor::encode(FeedCursor {
+            id: last.id,
+            published_at
+        }))
+    } else { None };
+    chapter::service::attach(db, &mut lessons).await?;
+
+    Ok((lessons, next_cursor))
+}
@@ -0,0 +1,78 @@
+use crate::error::AppError;
+use crate::extractor::accept_language::AcceptLanguage;
+use crate::module::common::base::{BaseResponse, IdParam};
+use crate::module::common::paging::CursorPagingResponse;
+use crate::module::common::translation;
+use crate::module::user::history::dto::{HistoryDayResponse, HistoryPaginationParams};
+use crate::module::user::history::mapper;
+use crate::module::user::history::service;
+use crate::state::AppState;
+use crate::utils::extractors::{ValidatedPath, ValidatedQuery};
+use crate::utils::jwt::Claims;
+use axum::extract::State;
+use axum::Extension;
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/history",
+    security(("bearerAuth" = [])),
+    params(HistoryPaginationParams),
+    responses((status = 200, body = CursorPagingResponse<HistoryDayResponse>)),
+    tag = "History"
+)]
+pub async fn page_history(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedQuery(params): ValidatedQuery<HistoryPaginationParams>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<CursorPagingResponse<HistoryDayResponse>>, AppError> {
+    let (entries, mut lessons, next_cursor) = service::page(
+        &state.db,
+        claims.sub,
+        params.timezone,
+        params.limit,
+        params.cursor,
+        lang.clone()
+    ).await?;
+    translation::service::localize_lessons(&state.db, &mut lessons, &lang).await?;
+
+    Ok(
+        BaseResponse::success(
+            CursorPagingResponse::new(
+                mapper::to_day_responses(entries, lessons),
+                next_cursor
+            )
+        )
+    )
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/user/history/{id}",
+    security(("bearerAuth" = [])),
+    params(IdParam),
+    tag = "History"
+)]
+pub async fn delete_history_entry(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<()>, AppError> {
+    service::delete(&state.db, id, claims.sub, lang).await?;
+    Ok(BaseResponse::success(()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/user/history",
+    security(("bearerAuth" = [])),
+    tag = "History"
+)]
+pub async fn clear_history(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<()>, AppError> {
+    service::clear(&state.db, claims.sub).await?;
+    Ok(BaseResponse::success(()))
+}
@@ -0,0 +1,46 @@
+use crate::module::user::lesson::dto::LessonResponse;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct HistoryPaginationParams {
+    #[param(example = json!(20))]
+    pub limit: u32,
+    pub cursor: Option<String>,
+    /// IANA timezone the entries are grouped by. Defaults to the user's
+    /// timezone setting, which is `UTC` unless changed
+    #[param(example = json!("Asia/Tashkent"))]
+    pub timezone: Option<String>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct HistoryCursor {
+    pub id: i64,
+    #[serde(with = "time::serde::rfc3339")]
+    pub started_at: OffsetDateTime
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct HistoryEntryResponse {
+    pub id: i64,
+    pub lesson: LessonResponse,
+    pub device: Option<String>,
+    /// Where the session stopped
+    pub last_position_ms: i64,
+    /// Time actually listened in the session
+    pub listened_ms: i64,
+    #[serde(with = "time::serde::rfc3339")]
+    pub started_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct HistoryDayResponse {
+    /// `YYYY-MM-DD` in the requested timezone
+    pub day: String,
+    /// Latest first
+    pub items: Vec<HistoryEntryResponse>
+}
@@ -0,0 +1,34 @@
+use crate::db::history::entity::HistoryEntity;
+use crate::db::lesson::entity::LessonWithAuthorTopic;
+use crate::module::user::history::dto::{HistoryDayResponse, HistoryEntryResponse};
+use crate::module::user::lesson::mapper as lesson_mapper;
+
+pub fn to_response(entity: HistoryEntity, lesson: LessonWithAuthorTopic) -> HistoryEntryResponse {
+    HistoryEntryResponse {
+        id: entity.history_id,
+        lesson: lesson_mapper::to_response(lesson),
+        device: entity.history_device,
+        last_position_ms: entity.history_position_ms,
+        listened_ms: entity.history_listened_ms,
+        started_at: entity.history_started_at,
+        updated_at: entity.history_updated_at
+    }
+}
+
+/// Groups consecutive entries of the same day. A day cut by the page limit
+/// continues in the first group of the next page.
+pub fn to_day_responses(
+    entities: Vec<HistoryEntity>,
+    lessons: Vec<LessonWithAuthorTopic>
+) -> Vec<HistoryDayResponse> {
+    let mut days: Vec<HistoryDayResponse> = Vec::new();
+    for (entity, lesson) in entities.into_iter().zip(lessons) {
+        let day = entity.history_day.clone();
+        let entry = to_response(entity, lesson);
+        match days.last_mut() {
+            Some(last) if last.day == day => last.items.push(entry),
+            _ => days.push(HistoryDayResponse { day, items: vec![entry] })
+        }
+    }
+    days
+}
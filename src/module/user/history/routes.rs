@@ -0,0 +1,13 @@
+use axum::{Router, middleware};
+use axum::routing::{delete, get};
+use crate::middleware::auth::user_auth_middleware;
+use crate::module::user::history::controller::{clear_history, delete_history_entry, page_history};
+use crate::state::AppState;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/history", get(page_history))
+        .route("/history", delete(clear_history))
+        .route("/history/{id}", delete(delete_history_entry))
+        .layer(middleware::from_fn(user_auth_middleware))
+}
@@ -0,0 +1,72 @@
+use crate::db;
+use crate::db::history::entity::HistoryEntity;
+use crate::db::lesson::entity::LessonWithAuthorTopic;
+use crate::error::AppError;
+use crate::module::common::chapter;
+use crate::module::user::history::dto::HistoryCursor;
+use crate::string_keys::strings;
+use crate::utils;
+use crate::utils::t;
+use fluent_templates::LanguageIdentifier;
+use sqlx::PgPool;
+
+pub async fn page(
+    db: &PgPool,
+    user_id: i64,
+    timezone: Option<String>,
+    limit: u32,
+    cursor: Option<String>,
+    lang: LanguageIdentifier
+) -> Result<(Vec<HistoryEntity>, Vec<LessonWithAuthorTopic>, Option<String>), AppError> {
+    let timezone = match timezone {
+        Some(timezone) => timezone,
+        None => db::user::repo::get_settings(db, user_id)
+            .await?
+            .map_or_else(|| "UTC".to_string(), |settings| settings.timezone)
+    };
+    if !db::user::repo::is_timezone(db, &timezone).await? {
+        return Err(AppError::BadRequest { lang: lang.clone(), message: t(&lang, strings::BAD_REQUEST) });
+    }
+
+    let mut items = db::history::repo::page(
+        db,
+        user_id,
+        &timezone,
+        limit + 1,
+        utils::cursor::decode(cursor)
+    ).await?;
+
+    let next_cursor = if items.len() == (limit + 1) as usize {
+        items.remove(limit as usize);
+        let last = &items.last().unwrap().history;
+        utils::cursor::encode(HistoryCursor {
+            id: last.history_id,
+            started_at: last.history_started_at
+        })
+    } else { None };
+
+    let (entries, mut lessons): (Vec<HistoryEntity>, Vec<LessonWithAuthorTopic>) = items
+        .into_iter()
+        .map(|item| (item.history, item.lesson))
+        .unzip();
+    chapter::service::attach(db, &mut lessons).await?;
+
+    Ok((entries, lessons, next_cursor))
+}
+
+pub async fn delete(
+    db: &PgPool,
+    id: i64,
+    user_id: i64,
+    lang: LanguageIdentifier
+) -> Result<(), AppError> {
+    if db::history::repo::hide(db, id, user_id).await? == 0 {
+        return Err(AppError::NotFound(lang));
+    }
+    Ok(())
+}
+
+pub async fn clear(db: &PgPool, user_id: i64) -> Result<(), AppError> {
+    db::history::repo::hide_all(db, user_id).await?;
+    Ok(())
+}
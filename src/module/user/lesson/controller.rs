@@ -3,7 +3,7 @@ use crate::state::AppState;
 use axum::extract::State;
 use crate::error::AppError;
 use crate::extractor::accept_language::AcceptLanguage;
-use crate::module::common::base::{BaseResponse, DeletedParams, DeletedResponse, IdParam};
+use crate::module::common::base::{BaseResponse, DeletedParams, DeletedResponse, PublishedResponse, IdParam};
 use crate::module::user::lesson::dto::{LessonPaginationParams, ListenSessionCreateRequest, ListenSessionCreateResponse};
 use crate::module::common::lesson::service;
 use crate::module::common::paging::{CursorPagingResponse, PagingResponse};
@@ -11,6 +11,10 @@ use crate::module::user::lesson::dto::{LessonProgressResponse, LessonProgressUpd
 use crate::module::user::lesson::mapper;
 use crate::utils::extractors::{ValidatedJson, ValidatedPath, ValidatedQuery};
 use crate::utils::jwt::Claims;
+use crate::module::common::transcript;
+use crate::module::common::translation;
+use crate::module::common::transcript::dto::TranscriptSegmentResponse;
+use crate::module::user::lesson::dto::{TranscriptHitResponse, TranscriptSearchParams};
 
 #[utoipa::path(
     get,
@@ -22,10 +26,11 @@ use crate::utils::jwt::Claims;
 )]
 pub async fn page_lesson(
     State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
     ValidatedQuery(params): ValidatedQuery<LessonPaginationParams>,
     Extension(claims): Extension<Claims>
 ) -> Result<BaseResponse<CursorPagingResponse<LessonResponse>>, AppError> {
-    let lessons = service::page_cursor(
+    let mut lessons = service::page_cursor(
         &state.db,
         params.limit,
         params.cursor,
@@ -38,6 +43,7 @@ pub async fn page_lesson(
         params.sort,
         params.order
     ).await?;
+    translation::service::localize_lessons(&state.db, &mut lessons.0, &lang).await?;
 
     Ok(
         BaseResponse::success(
@@ -129,12 +135,13 @@ pub async fn increase_listen_count(
     Extension(claims): Extension<Claims>,
     ValidatedJson(body): ValidatedJson<ListenSessionCreateRequest>
 ) -> Result<BaseResponse<ListenSessionCreateResponse>, AppError> {
-    let listen_count = service::increase_listen_count(
+    let listen_count = service::create_listen_session(
         &state.db,
         body.session_id,
         claims.sub,
         id,
-        body.created_at
+        body.created_at,
+        body.device
     ).await?;
 
     Ok(BaseResponse::success(
@@ -166,6 +173,7 @@ pub async fn update_lesson_progress(
         body.last_position_ms,
         body.status,
         body.completed_at,
+        body.session_id,
         lang
     ).await?;
 
@@ -174,4 +182,76 @@ pub async fn update_lesson_progress(
             mapper::progress_to_response(entity)
         )
     )
-}
\ No newline at end of file
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/lesson/{id}/transcript",
+    security(("bearerAuth" = [])),
+    params(IdParam),
+    responses((status = 200, body = Vec<TranscriptSegmentResponse>)),
+    tag = "Lesson"
+)]
+pub async fn get_transcript(
+    State(state): State<AppState>,
+    ValidatedPath(id): ValidatedPath<i64>
+) -> Result<BaseResponse<Vec<TranscriptSegmentResponse>>, AppError> {
+    let segments = transcript::service::get(&state.db, id, true).await?;
+    Ok(
+        BaseResponse::success(
+            segments.into_iter().map(transcript::mapper::to_response).collect()
+        )
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/lesson/transcript/search",
+    security(("bearerAuth" = [])),
+    params(TranscriptSearchParams),
+    responses((status = 200, body = CursorPagingResponse<TranscriptHitResponse>)),
+    tag = "Lesson"
+)]
+pub async fn search_transcript(
+    State(state): State<AppState>,
+    ValidatedQuery(params): ValidatedQuery<TranscriptSearchParams>
+) -> Result<BaseResponse<CursorPagingResponse<TranscriptHitResponse>>, AppError> {
+    let hits = transcript::service::search(
+        &state.db,
+        params.q,
+        params.lesson_id,
+        params.limit,
+        params.cursor
+    ).await?;
+
+    Ok(
+        BaseResponse::success(
+            CursorPagingResponse::new(
+                hits.0.into_iter().map(mapper::to_transcript_hit_response).collect(),
+                hits.1
+            )
+        )
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/lesson/published",
+    security(("bearerAuth" = [])),
+    params(DeletedParams),
+    responses((status = 200, body = Vec<PublishedResponse>)),
+    tag = "Lesson"
+)]
+pub async fn published_lessons(
+    State(state): State<AppState>,
+    ValidatedQuery(params): ValidatedQuery<DeletedParams>
+) -> Result<BaseResponse<Vec<PublishedResponse>>, AppError> {
+    Ok(
+        BaseResponse::success(
+            service::published(&state.db, params.since).await?
+                .iter().map(|pair| {
+                PublishedResponse{ id: pair.0, published_at: pair.1 }
+            }).collect()
+        )
+    )
+}
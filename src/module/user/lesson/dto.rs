@@ -12,7 +12,10 @@ pub struct LessonResponse {
     #[serde(flatten)]
     pub lesson: CommonLessonResponse,
     pub is_favourite: bool,
-    pub lesson_progress: Option<LessonProgressResponse>
+    pub lesson_progress: Option<LessonProgressResponse>,
+    /// Next lesson of the topic, for auto-play
+    pub next_lesson_id: Option<i64>,
+    pub previous_lesson_id: Option<i64>
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -36,14 +39,20 @@ pub struct LessonProgressUpdateRequest {
     pub last_position_ms: i64,
     pub status: Option<UserProgressStatus>,
     #[serde(with = "time::serde::rfc3339::option", default)]
-    pub completed_at: Option<OffsetDateTime>
+    pub completed_at: Option<OffsetDateTime>,
+    /// Listen session the update belongs to, recorded in the history.
+    /// Listening time is computed from the heartbeats, not sent here
+    pub session_id: Option<String>
 }
 
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct ListenSessionCreateRequest {
     pub session_id: String,
     #[serde(with = "time::serde::rfc3339")]
-    pub created_at: OffsetDateTime
+    pub created_at: OffsetDateTime,
+    /// Name of the device, shown in the history
+    #[validate(length(max = 64))]
+    pub device: Option<String>
 }
 
 #[derive(Debug, Serialize, Validate, ToSchema)]
@@ -72,6 +81,39 @@ pub struct LessonPaginationParams {
 pub struct LessonCursor {
     pub id: i64,
     pub snip_count: i64,
+    pub position: i32,
+    pub rating_average: f64,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
-}
\ No newline at end of file
+}
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TranscriptSearchParams {
+    pub q: String,
+    pub lesson_id: Option<i64>,
+    pub limit: u32,
+    pub cursor: Option<String>
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TranscriptHitResponse {
+    pub lesson_id: i64,
+    pub lesson_title: String,
+    pub lesson_cover_image_path: Option<String>,
+    pub author_id: i64,
+    pub author_name: String,
+    /// Position to seek to
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub text: String,
+    /// HTML-escaped text with matches wrapped in `<b>`, the only markup
+    pub highlight: String,
+    pub rank: f32
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TranscriptCursor {
+    pub rank: f32,
+    pub id: i64
+}
@@ -1,8 +1,10 @@
 use time::OffsetDateTime;
 use crate::db::lesson::entity::{LessonProgressEntity, LessonWithAuthorTopic};
 use crate::module::common::enums::UserProgressStatus;
-use crate::module::user::lesson::dto::{LessonProgressResponse, LessonResponse};
+use crate::db::transcript::entity::TranscriptHitEntity;
+use crate::module::user::lesson::dto::{LessonProgressResponse, LessonResponse, TranscriptHitResponse};
 use crate::module::common::lesson::mapper::to_response as lesson_to_response;
+use crate::utils::search::highlight_to_html;
 
 pub fn to_response(entity: LessonWithAuthorTopic) -> LessonResponse {
     LessonResponse {
@@ -19,7 +21,9 @@ pub fn to_response(entity: LessonWithAuthorTopic) -> LessonResponse {
                 status: entity.status.unwrap_or(UserProgressStatus::InProgress),
                 completed_at: entity.completed_at
             })
-        } else { None }
+        } else { None },
+        next_lesson_id: entity.next_lesson_id,
+        previous_lesson_id: entity.previous_lesson_id
     }
 }
 
@@ -34,4 +38,19 @@ pub fn progress_to_response(entity: LessonProgressEntity) -> LessonProgressRespo
         status: entity.status,
         completed_at: entity.completed_at
     }
-}
\ No newline at end of file
+}
+
+pub fn to_transcript_hit_response(entity: TranscriptHitEntity) -> TranscriptHitResponse {
+    TranscriptHitResponse {
+        lesson_id: entity.lesson_id,
+        lesson_title: entity.lesson_title,
+        lesson_cover_image_path: entity.lesson_cover_image_path,
+        author_id: entity.author_id,
+        author_name: entity.author_name,
+        start_ms: entity.start_ms,
+        end_ms: entity.end_ms,
+        text: entity.text,
+        highlight: highlight_to_html(&entity.highlight),
+        rank: entity.rank
+    }
+}
@@ -0,0 +1,38 @@
+use crate::error::AppError;
+use crate::module::common::base::BaseResponse;
+use crate::module::user::listen::dto::{HeartbeatRequest, HeartbeatResponse};
+use crate::module::user::listen::mapper;
+use crate::module::user::listen::service;
+use crate::state::AppState;
+use crate::utils::extractors::ValidatedJson;
+use crate::utils::jwt::Claims;
+use axum::extract::State;
+use axum::Extension;
+
+#[utoipa::path(
+    post,
+    path = "/v1/user/listen/heartbeat",
+    security(("bearerAuth" = [])),
+    request_body = HeartbeatRequest,
+    responses((status = 200, body = HeartbeatResponse)),
+    tag = "Listen"
+)]
+pub async fn heartbeat(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(body): ValidatedJson<HeartbeatRequest>
+) -> Result<BaseResponse<HeartbeatResponse>, AppError> {
+    let sessions = service::heartbeat(
+        &state.db,
+        claims.sub,
+        body.sessions.into_iter().map(mapper::to_input).collect()
+    ).await?;
+
+    Ok(
+        BaseResponse::success(HeartbeatResponse {
+            sessions: sessions.into_iter()
+                .map(|(session, listen_count)| mapper::to_response(session, listen_count))
+                .collect()
+        })
+    )
+}
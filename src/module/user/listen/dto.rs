@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+pub struct HeartbeatRequest {
+    #[validate(length(min = 1, max = 50), nested)]
+    pub sessions: Vec<HeartbeatSessionRequest>
+}
+
+#[derive(Serialize, Deserialize, Debug, Validate, ToSchema)]
+pub struct HeartbeatSessionRequest {
+    #[validate(length(min = 1, max = 128))]
+    pub session_id: String,
+    pub lesson_id: i64,
+    /// Used when the session is created by its first heartbeat
+    #[validate(length(max = 64))]
+    pub device: Option<String>,
+    /// In any order; events already received are skipped
+    #[validate(length(min = 1, max = 1000), nested)]
+    pub events: Vec<HeartbeatEventRequest>
+}
+
+#[derive(Serialize, Deserialize, Debug, Validate, ToSchema)]
+pub struct HeartbeatEventRequest {
+    #[validate(range(min = 0))]
+    pub position_ms: i64,
+    #[validate(range(min = 0.25, max = 4.0))]
+    pub playback_rate: f32,
+    /// With the device's UTC offset, which decides the day the time is counted on
+    #[serde(with = "time::serde::rfc3339")]
+    pub occurred_at: OffsetDateTime
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct HeartbeatSessionResponse {
+    pub session_id: String,
+    pub lesson_id: i64,
+    /// Wall-clock time spent listening
+    pub listened_ms: i64,
+    /// Lesson audio covered at least once; replays do not add to it
+    pub heard_ms: i64,
+    /// Whether the session has been counted in `listen_count`
+    pub counted: bool,
+    pub listen_count: i64
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct HeartbeatResponse {
+    /// Sessions whose lesson is gone or that belong to someone else are left out
+    pub sessions: Vec<HeartbeatSessionResponse>
+}
@@ -0,0 +1,29 @@
+use crate::db::listen::entity::{ListenEventInput, ListenSessionEntity, ListenSessionInput};
+use crate::module::user::listen::dto::{HeartbeatSessionRequest, HeartbeatSessionResponse};
+
+pub fn to_input(request: HeartbeatSessionRequest) -> ListenSessionInput {
+    ListenSessionInput {
+        session_id: request.session_id,
+        lesson_id: request.lesson_id,
+        device: request.device,
+        events: request.events.into_iter()
+            .map(|event| ListenEventInput {
+                position_ms: event.position_ms,
+                playback_rate: event.playback_rate,
+                occurred_at: event.occurred_at,
+                local_day: event.occurred_at.date()
+            })
+            .collect()
+    }
+}
+
+pub fn to_response(entity: ListenSessionEntity, listen_count: i64) -> HeartbeatSessionResponse {
+    HeartbeatSessionResponse {
+        session_id: entity.session_id,
+        lesson_id: entity.lesson_id,
+        listened_ms: entity.listened_ms,
+        heard_ms: entity.heard_ms,
+        counted: entity.counted_at.is_some(),
+        listen_count
+    }
+}
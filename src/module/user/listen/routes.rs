@@ -0,0 +1,11 @@
+use axum::{Router, middleware};
+use axum::routing::post;
+use crate::middleware::auth::user_auth_middleware;
+use crate::module::user::listen::controller::heartbeat;
+use crate::state::AppState;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/listen/heartbeat", post(heartbeat))
+        .layer(middleware::from_fn(user_auth_middleware))
+}
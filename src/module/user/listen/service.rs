@@ -0,0 +1,66 @@
+use crate::db;
+use crate::db::listen::entity::{ListenSessionEntity, ListenSessionInput};
+use crate::error::AppError;
+use crate::module::common::achievement;
+use crate::utils::CONFIG;
+use sqlx::PgPool;
+use time::{Duration, OffsetDateTime};
+
+/// Stores the heartbeats of each session and recomputes its listening time.
+/// A session is counted in `listen_count` once `LISTEN_COUNT_THRESHOLD` of
+/// the lesson has been heard. Unknown sessions are created, so heartbeats
+/// queued offline can arrive before the session itself.
+pub async fn heartbeat(
+    db: &PgPool,
+    user_id: i64,
+    sessions: Vec<ListenSessionInput>
+) -> Result<Vec<(ListenSessionEntity, i64)>, AppError> {
+    let mut result = Vec::with_capacity(sessions.len());
+    for session in sessions {
+        if !db::lesson::repo::is_visible(db, session.lesson_id).await? {
+            continue;
+        }
+
+        let mut tx = db.begin().await?;
+        let mut entity = db::listen::repo::lock_session(&mut tx, &session.session_id).await?;
+        if entity.is_none() {
+            let started_at = session.events.iter()
+                .map(|event| event.occurred_at)
+                .min()
+                .unwrap_or_else(OffsetDateTime::now_utc);
+            db::lesson::repo::create_listen_session(
+                &mut tx,
+                session.session_id.clone(),
+                user_id,
+                session.lesson_id,
+                started_at,
+                session.device
+            ).await?;
+            entity = db::listen::repo::lock_session(&mut tx, &session.session_id).await?;
+        }
+        let Some(entity) = entity.filter(|e| e.user_id == user_id && e.lesson_id == session.lesson_id) else {
+            continue;
+        };
+
+        db::listen::repo::insert_events(&mut tx, entity.id, &session.events).await?;
+        let mut entity = db::listen::repo::recompute(&mut tx, entity.id).await?;
+        // a new event also changes the step to the event after it, up to a minute later
+        let first = session.events.iter().map(|event| event.occurred_at).min();
+        let last = session.events.iter().map(|event| event.occurred_at).max();
+        if let (Some(first), Some(last)) = (first, last) {
+            db::stat::repo::refresh(&mut tx, user_id, Some((first, last + Duration::minutes(1)))).await?;
+        }
+        if db::listen::repo::count(&mut tx, entity.id, CONFIG.listen_count_threshold).await? {
+            db::lesson::repo::increase_listen_count(&mut tx, session.lesson_id).await?;
+            entity.counted_at = Some(OffsetDateTime::now_utc());
+        }
+        tx.commit().await?;
+
+        let listen_count = db::lesson::repo::get_listen_count(db, session.lesson_id).await?;
+        result.push((entity, listen_count));
+    }
+    if !result.is_empty() {
+        achievement::service::evaluate(db, user_id).await?;
+    }
+    Ok(result)
+}
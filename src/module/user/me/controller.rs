@@ -0,0 +1,79 @@
+use crate::db::user::entity::UserSettingEntity;
+use crate::error::AppError;
+use crate::extractor::accept_language::AcceptLanguage;
+use crate::module::common::base::BaseResponse;
+use crate::module::user::me::dto::{StatsResponse, UserSettingsRequest, UserSettingsResponse};
+use crate::module::user::me::mapper;
+use crate::module::user::me::service;
+use crate::state::AppState;
+use crate::utils::extractors::ValidatedJson;
+use crate::utils::jwt::Claims;
+use axum::extract::State;
+use axum::Extension;
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/me/stats",
+    security(("bearerAuth" = [])),
+    responses((status = 200, body = StatsResponse)),
+    tag = "Me"
+)]
+pub async fn get_stats(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<StatsResponse>, AppError> {
+    let stats = service::stats(&state.db, claims.sub, lang).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(stats))
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/me/settings",
+    security(("bearerAuth" = [])),
+    responses((status = 200, body = UserSettingsResponse)),
+    tag = "Me"
+)]
+pub async fn get_settings(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<UserSettingsResponse>, AppError> {
+    let settings = service::get_settings(&state.db, claims.sub, lang).await?;
+
+    Ok(
+        BaseResponse::success(mapper::settings_to_response(settings))
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/user/me/settings",
+    security(("bearerAuth" = [])),
+    request_body = UserSettingsRequest,
+    responses((status = 200, body = UserSettingsResponse)),
+    tag = "Me"
+)]
+pub async fn update_settings(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(body): ValidatedJson<UserSettingsRequest>
+) -> Result<BaseResponse<UserSettingsResponse>, AppError> {
+    let settings = service::update_settings(
+        &state.db,
+        claims.sub,
+        UserSettingEntity {
+            timezone: body.timezone,
+            daily_goal_minutes: body.daily_goal_minutes
+        },
+        lang
+    ).await?;
+
+    Ok(
+        BaseResponse::success(mapper::settings_to_response(settings))
+    )
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+pub struct UserSettingsRequest {
+    /// IANA name, e.g. `Asia/Tashkent`
+    #[validate(length(min = 1, max = 64))]
+    pub timezone: String,
+    #[validate(range(min = 1, max = 720))]
+    pub daily_goal_minutes: i32
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct UserSettingsResponse {
+    pub timezone: String,
+    pub daily_goal_minutes: i32
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct DailyStatResponse {
+    /// `YYYY-MM-DD` in the user's timezone
+    pub day: String,
+    pub listened_minutes: i64,
+    pub lessons_completed: i32,
+    pub snips_created: i32
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct DailyGoalResponse {
+    pub goal_minutes: i32,
+    pub listened_minutes: i64,
+    /// Share of the goal listened today, from `0` to `1`
+    pub progress: f64,
+    pub reached: bool
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct StatsResponse {
+    pub timezone: String,
+    /// `YYYY-MM-DD` in the user's timezone
+    pub today: String,
+    pub listened_minutes_today: i64,
+    /// Since Monday
+    pub listened_minutes_week: i64,
+    pub listened_minutes_month: i64,
+    /// The last seven days, oldest first
+    pub days: Vec<DailyStatResponse>,
+    pub lessons_completed: i64,
+    pub topics_completed: i64,
+    pub snips_created: i64,
+    /// Days in a row with listening or a completed lesson, counting today
+    /// once it is active and yesterday until then
+    pub current_streak_days: i64,
+    pub longest_streak_days: i64,
+    pub daily_goal: DailyGoalResponse
+}
@@ -0,0 +1,47 @@
+use crate::db::stat::entity::{DailyStatEntity, StatsEntity};
+use crate::db::user::entity::UserSettingEntity;
+use crate::module::user::me::dto::{DailyGoalResponse, DailyStatResponse, StatsResponse, UserSettingsResponse};
+
+fn minutes(ms: i64) -> i64 {
+    ms / 60_000
+}
+
+pub fn settings_to_response(entity: UserSettingEntity) -> UserSettingsResponse {
+    UserSettingsResponse {
+        timezone: entity.timezone,
+        daily_goal_minutes: entity.daily_goal_minutes
+    }
+}
+
+pub fn daily_to_response(entity: DailyStatEntity) -> DailyStatResponse {
+    DailyStatResponse {
+        day: entity.day.to_string(),
+        listened_minutes: minutes(entity.listened_ms),
+        lessons_completed: entity.lessons_completed,
+        snips_created: entity.snips_created
+    }
+}
+
+pub fn to_response(entity: StatsEntity) -> StatsResponse {
+    let summary = entity.summary;
+    let goal_ms = entity.settings.daily_goal_minutes as i64 * 60_000;
+    StatsResponse {
+        timezone: entity.settings.timezone,
+        today: summary.today.to_string(),
+        listened_minutes_today: minutes(summary.listened_ms_today),
+        listened_minutes_week: minutes(summary.listened_ms_week),
+        listened_minutes_month: minutes(summary.listened_ms_month),
+        days: entity.days.into_iter().map(daily_to_response).collect(),
+        lessons_completed: summary.lessons_completed,
+        topics_completed: entity.topics_completed,
+        snips_created: summary.snips_created,
+        current_streak_days: entity.streaks.current,
+        longest_streak_days: entity.streaks.longest,
+        daily_goal: DailyGoalResponse {
+            goal_minutes: entity.settings.daily_goal_minutes,
+            listened_minutes: minutes(summary.listened_ms_today),
+            progress: (summary.listened_ms_today as f64 / goal_ms as f64).min(1.0),
+            reached: summary.listened_ms_today >= goal_ms
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use axum::{Router, middleware};
+use axum::routing::{get, put};
+use crate::middleware::auth::user_auth_middleware;
+use crate::module::user::me::controller::{get_settings, get_stats, update_settings};
+use crate::state::AppState;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/me/stats", get(get_stats))
+        .route("/me/settings", get(get_settings))
+        .route("/me/settings", put(update_settings))
+        .layer(middleware::from_fn(user_auth_middleware))
+}
@@ -0,0 +1,58 @@
+use crate::db;
+use crate::db::stat::entity::StatsEntity;
+use crate::db::user::entity::UserSettingEntity;
+use crate::error::AppError;
+use crate::string_keys::strings;
+use crate::utils::t;
+use fluent_templates::LanguageIdentifier;
+use sqlx::PgPool;
+use time::Duration;
+
+pub async fn get_settings(
+    db: &PgPool,
+    user_id: i64,
+    lang: LanguageIdentifier
+) -> Result<UserSettingEntity, AppError> {
+    db::user::repo::get_settings(db, user_id)
+        .await?
+        .ok_or(AppError::NotFound(lang))
+}
+
+/// Changing the timezone moves every day boundary, so the whole rollup is
+/// rebuilt.
+pub async fn update_settings(
+    db: &PgPool,
+    user_id: i64,
+    settings: UserSettingEntity,
+    lang: LanguageIdentifier
+) -> Result<UserSettingEntity, AppError> {
+    if !db::user::repo::is_timezone(db, &settings.timezone).await? {
+        return Err(AppError::BadRequest { lang: lang.clone(), message: t(&lang, strings::BAD_REQUEST) });
+    }
+
+    let timezone = settings.timezone.clone();
+    let mut tx = db.begin().await?;
+    let old = db::user::repo::update_settings(&mut tx, user_id, settings)
+        .await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
+    if old.timezone != timezone {
+        db::stat::repo::refresh(&mut tx, user_id, None).await?;
+    }
+    tx.commit().await?;
+
+    get_settings(db, user_id, lang).await
+}
+
+pub async fn stats(
+    db: &PgPool,
+    user_id: i64,
+    lang: LanguageIdentifier
+) -> Result<StatsEntity, AppError> {
+    let settings = get_settings(db, user_id, lang).await?;
+    let summary = db::stat::repo::summary(db, user_id).await?;
+    let days = db::stat::repo::daily(db, user_id, summary.today - Duration::days(6), summary.today).await?;
+    let streaks = db::stat::repo::streaks(db, user_id, summary.today).await?;
+    let topics_completed = db::stat::repo::topics_completed(db, user_id).await?;
+
+    Ok(StatsEntity { settings, summary, days, streaks, topics_completed })
+}
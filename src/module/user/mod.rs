@@ -2,4 +2,15 @@ pub mod auth;
 pub mod topic;
 pub mod lesson;
 pub mod snip;
-pub mod author;
\ No newline at end of file
+pub mod author;
+pub mod share;
+pub mod search;
+pub mod playlist;
+pub mod queue;
+pub mod history;
+pub mod listen;
+pub mod me;
+pub mod achievement;
+pub mod follow;
+pub mod notification;
+pub mod review;
\ No newline at end of file
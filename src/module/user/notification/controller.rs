@@ -0,0 +1,185 @@
+use crate::db::notification::entity::NotificationSettingEntity;
+use crate::error::AppError;
+use crate::extractor::accept_language::AcceptLanguage;
+use crate::module::common::base::{BaseResponse, IdParam};
+use crate::module::common::paging::CursorPagingResponse;
+use crate::module::common::translation;
+use crate::module::user::notification::dto::{
+    DeviceDeleteRequest, DeviceRequest, DeviceResponse, NotificationPaginationParams, NotificationResponse,
+    NotificationSettingsRequest, NotificationSettingsResponse, UnreadCountResponse
+};
+use crate::module::user::notification::mapper;
+use crate::module::user::notification::service;
+use crate::state::AppState;
+use crate::utils::extractors::{ValidatedJson, ValidatedPath, ValidatedQuery};
+use crate::utils::jwt::Claims;
+use axum::extract::State;
+use axum::Extension;
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/notification",
+    security(("bearerAuth" = [])),
+    params(NotificationPaginationParams),
+    responses((status = 200, body = CursorPagingResponse<NotificationResponse>)),
+    tag = "Notification"
+)]
+pub async fn page_notification(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedQuery(params): ValidatedQuery<NotificationPaginationParams>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<CursorPagingResponse<NotificationResponse>>, AppError> {
+    let (mut notifications, next_cursor) = service::page(
+        &state.db,
+        claims.sub,
+        params.unread.unwrap_or(false),
+        params.limit,
+        params.cursor
+    ).await?;
+    translation::service::localize_notifications(&state.db, &mut notifications, &lang).await?;
+
+    Ok(
+        BaseResponse::success(
+            CursorPagingResponse::new(
+                notifications.into_iter().map(|n| mapper::to_response(n, &lang)).collect(),
+                next_cursor
+            )
+        )
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/notification/unread-count",
+    security(("bearerAuth" = [])),
+    responses((status = 200, body = UnreadCountResponse)),
+    tag = "Notification"
+)]
+pub async fn unread_count(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<UnreadCountResponse>, AppError> {
+    let unread = service::unread_count(&state.db, claims.sub).await?;
+    Ok(BaseResponse::success(UnreadCountResponse { unread }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/user/notification/{id}/read",
+    security(("bearerAuth" = [])),
+    params(IdParam),
+    tag = "Notification"
+)]
+pub async fn mark_read(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<()>, AppError> {
+    service::mark_read(&state.db, id, claims.sub, lang).await?;
+    Ok(BaseResponse::success(()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/user/notification/read",
+    security(("bearerAuth" = [])),
+    tag = "Notification"
+)]
+pub async fn mark_all_read(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<()>, AppError> {
+    service::mark_all_read(&state.db, claims.sub).await?;
+    Ok(BaseResponse::success(()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/user/notification/device",
+    security(("bearerAuth" = [])),
+    request_body = DeviceRequest,
+    responses((status = 200, body = DeviceResponse)),
+    tag = "Notification"
+)]
+pub async fn register_device(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(body): ValidatedJson<DeviceRequest>
+) -> Result<BaseResponse<DeviceResponse>, AppError> {
+    let device = service::register_device(&state.db, claims.sub, body.platform, body.token, lang).await?;
+
+    Ok(
+        BaseResponse::success(mapper::device_to_response(device))
+    )
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/user/notification/device",
+    security(("bearerAuth" = [])),
+    request_body = DeviceDeleteRequest,
+    tag = "Notification"
+)]
+pub async fn remove_device(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(body): ValidatedJson<DeviceDeleteRequest>
+) -> Result<BaseResponse<()>, AppError> {
+    service::remove_device(&state.db, claims.sub, body.token, lang).await?;
+    Ok(BaseResponse::success(()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/notification/settings",
+    security(("bearerAuth" = [])),
+    responses((status = 200, body = NotificationSettingsResponse)),
+    tag = "Notification"
+)]
+pub async fn get_settings(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<NotificationSettingsResponse>, AppError> {
+    let settings = service::get_settings(&state.db, claims.sub, lang).await?;
+
+    Ok(
+        BaseResponse::success(mapper::settings_to_response(settings))
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/user/notification/settings",
+    security(("bearerAuth" = [])),
+    request_body = NotificationSettingsRequest,
+    responses((status = 200, body = NotificationSettingsResponse)),
+    tag = "Notification"
+)]
+pub async fn update_settings(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(body): ValidatedJson<NotificationSettingsRequest>
+) -> Result<BaseResponse<NotificationSettingsResponse>, AppError> {
+    let settings = service::update_settings(
+        &state.db,
+        claims.sub,
+        NotificationSettingEntity {
+            notify_new_lessons: body.new_lessons,
+            notify_streak: body.streak_at_risk,
+            notify_weekly_summary: body.weekly_summary,
+            push_enabled: body.push,
+            telegram_enabled: body.telegram
+        },
+        lang
+    ).await?;
+
+    Ok(
+        BaseResponse::success(mapper::settings_to_response(settings))
+    )
+}
@@ -0,0 +1,88 @@
+use crate::module::common::enums::{DevicePlatform, NotificationKind};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct NotificationPaginationParams {
+    #[param(example = json!(20))]
+    pub limit: u32,
+    pub cursor: Option<String>,
+    /// Only the unread ones
+    pub unread: Option<bool>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct NotificationCursor {
+    pub id: i64,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct NotificationResponse {
+    pub id: i64,
+    pub kind: NotificationKind,
+    pub title: String,
+    pub body: String,
+    pub lesson_id: Option<i64>,
+    pub topic_id: Option<i64>,
+    pub read: bool,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub read_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct UnreadCountResponse {
+    pub unread: i64
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+pub struct DeviceRequest {
+    pub platform: DevicePlatform,
+    /// FCM registration token or APNs device token
+    #[validate(length(min = 1, max = 4096))]
+    pub token: String
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+pub struct DeviceDeleteRequest {
+    #[validate(length(min = 1, max = 4096))]
+    pub token: String
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct DeviceResponse {
+    pub id: i64,
+    pub platform: DevicePlatform,
+    pub token: String,
+    /// Taken from `Accept-Language` when registering
+    pub language: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+pub struct NotificationSettingsRequest {
+    pub new_lessons: bool,
+    pub streak_at_risk: bool,
+    pub weekly_summary: bool,
+    /// Off keeps notifications in the list without pushing them
+    pub push: bool,
+    /// Messages from the bot, for accounts signed in with Telegram. They are
+    /// written in the `Accept-Language` of this request.
+    pub telegram: bool
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct NotificationSettingsResponse {
+    pub new_lessons: bool,
+    pub streak_at_risk: bool,
+    pub weekly_summary: bool,
+    pub push: bool,
+    pub telegram: bool
+}
@@ -0,0 +1,39 @@
+use crate::db::notification::entity::{DeviceTokenEntity, NotificationEntity, NotificationSettingEntity};
+use crate::module::common::notification::service::render;
+use crate::module::user::notification::dto::{DeviceResponse, NotificationResponse, NotificationSettingsResponse};
+use fluent_templates::LanguageIdentifier;
+
+pub fn to_response(entity: NotificationEntity, lang: &LanguageIdentifier) -> NotificationResponse {
+    let (title, body) = render(&entity, lang);
+    NotificationResponse {
+        id: entity.id,
+        kind: entity.kind,
+        title,
+        body,
+        lesson_id: entity.lesson_id,
+        topic_id: entity.topic_id,
+        read: entity.read_at.is_some(),
+        read_at: entity.read_at,
+        created_at: entity.created_at
+    }
+}
+
+pub fn device_to_response(entity: DeviceTokenEntity) -> DeviceResponse {
+    DeviceResponse {
+        id: entity.id,
+        platform: entity.platform,
+        token: entity.token,
+        language: entity.language,
+        updated_at: entity.updated_at
+    }
+}
+
+pub fn settings_to_response(entity: NotificationSettingEntity) -> NotificationSettingsResponse {
+    NotificationSettingsResponse {
+        new_lessons: entity.notify_new_lessons,
+        streak_at_risk: entity.notify_streak,
+        weekly_summary: entity.notify_weekly_summary,
+        push: entity.push_enabled,
+        telegram: entity.telegram_enabled
+    }
+}
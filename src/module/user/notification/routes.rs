@@ -0,0 +1,21 @@
+use axum::{Router, middleware};
+use axum::routing::{delete, get, post, put};
+use crate::middleware::auth::user_auth_middleware;
+use crate::module::user::notification::controller::{
+    get_settings, mark_all_read, mark_read, page_notification, register_device, remove_device, unread_count,
+    update_settings
+};
+use crate::state::AppState;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/notification", get(page_notification))
+        .route("/notification/unread-count", get(unread_count))
+        .route("/notification/read", put(mark_all_read))
+        .route("/notification/{id}/read", put(mark_read))
+        .route("/notification/device", post(register_device))
+        .route("/notification/device", delete(remove_device))
+        .route("/notification/settings", get(get_settings))
+        .route("/notification/settings", put(update_settings))
+        .layer(middleware::from_fn(user_auth_middleware))
+}
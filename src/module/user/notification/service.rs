@@ -0,0 +1,109 @@
+use crate::db;
+use crate::db::notification::entity::{DeviceTokenEntity, NotificationEntity, NotificationSettingEntity};
+use crate::error::AppError;
+use crate::module::common::enums::DevicePlatform;
+use crate::module::user::notification::dto::NotificationCursor;
+use crate::string_keys::strings;
+use crate::utils;
+use crate::utils::t;
+use fluent_templates::LanguageIdentifier;
+use sqlx::PgPool;
+
+pub async fn page(
+    db: &PgPool,
+    user_id: i64,
+    unread: bool,
+    limit: u32,
+    cursor: Option<String>
+) -> Result<(Vec<NotificationEntity>, Option<String>), AppError> {
+    let mut items = db::notification::repo::page(
+        db,
+        user_id,
+        unread,
+        limit + 1,
+        utils::cursor::decode(cursor)
+    ).await?;
+
+    let next_cursor = if items.len() == (limit + 1) as usize {
+        items.remove(limit as usize);
+        let last = items.last().unwrap();
+        utils::cursor::encode(NotificationCursor {
+            id: last.id,
+            created_at: last.created_at
+        })
+    } else { None };
+
+    Ok((items, next_cursor))
+}
+
+pub async fn unread_count(db: &PgPool, user_id: i64) -> Result<i64, AppError> {
+    Ok(db::notification::repo::unread_count(db, user_id).await?)
+}
+
+pub async fn mark_read(
+    db: &PgPool,
+    id: i64,
+    user_id: i64,
+    lang: LanguageIdentifier
+) -> Result<(), AppError> {
+    if db::notification::repo::mark_read(db, id, user_id).await? == 0 {
+        return Err(AppError::NotFound(lang));
+    }
+    Ok(())
+}
+
+pub async fn mark_all_read(db: &PgPool, user_id: i64) -> Result<(), AppError> {
+    db::notification::repo::mark_all_read(db, user_id).await?;
+    Ok(())
+}
+
+/// Pushes to the device are written in `lang`.
+pub async fn register_device(
+    db: &PgPool,
+    user_id: i64,
+    platform: DevicePlatform,
+    token: String,
+    lang: LanguageIdentifier
+) -> Result<DeviceTokenEntity, AppError> {
+    Ok(db::notification::repo::upsert_device(db, user_id, platform, &token, &lang.to_string()).await?)
+}
+
+pub async fn remove_device(
+    db: &PgPool,
+    user_id: i64,
+    token: String,
+    lang: LanguageIdentifier
+) -> Result<(), AppError> {
+    if db::notification::repo::delete_device(db, user_id, &token).await? == 0 {
+        return Err(AppError::NotFound(lang));
+    }
+    Ok(())
+}
+
+pub async fn get_settings(
+    db: &PgPool,
+    user_id: i64,
+    lang: LanguageIdentifier
+) -> Result<NotificationSettingEntity, AppError> {
+    db::notification::repo::get_settings(db, user_id)
+        .await?
+        .ok_or(AppError::NotFound(lang))
+}
+
+/// Telegram messages need a linked Telegram account and are written in `lang`.
+pub async fn update_settings(
+    db: &PgPool,
+    user_id: i64,
+    settings: NotificationSettingEntity,
+    lang: LanguageIdentifier
+) -> Result<NotificationSettingEntity, AppError> {
+    if settings.telegram_enabled && !db::notification::repo::has_telegram(db, user_id).await? {
+        return Err(AppError::BadRequest {
+            lang: lang.clone(),
+            message: t(&lang, strings::TELEGRAM_NOT_LINKED)
+        });
+    }
+    db::notification::repo::update_settings(db, user_id, settings, &lang.to_string())
+        .await?
+        .ok_or(AppError::NotFound(lang))
+}
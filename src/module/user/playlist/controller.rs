@@ -0,0 +1,354 @@
+use crate::db::playlist::entity::PlaylistInput;
+use crate::error::AppError;
+use crate::extractor::accept_language::AcceptLanguage;
+use crate::module::common::base::{BaseResponse, IdParam, ShareTokenParam};
+use crate::module::common::paging::CursorPagingResponse;
+use crate::module::common::translation;
+use crate::module::user::lesson::dto::LessonResponse;
+use crate::module::user::lesson::mapper as lesson_mapper;
+use crate::module::user::playlist::dto::{PlaylistCURequest, PlaylistItemParam, PlaylistItemRequest, PlaylistOrderRequest, PlaylistPaginationParams, PlaylistResponse};
+use crate::module::user::playlist::mapper;
+use crate::module::user::playlist::service;
+use crate::state::AppState;
+use crate::utils::extractors::{ValidatedJson, ValidatedPath, ValidatedQuery};
+use crate::utils::jwt::Claims;
+use axum::extract::State;
+use axum::Extension;
+
+#[utoipa::path(
+    post,
+    path = "/v1/user/playlist",
+    security(("bearerAuth" = [])),
+    request_body = PlaylistCURequest,
+    responses((status = 200, body = PlaylistResponse)),
+    tag = "Playlist"
+)]
+pub async fn create_playlist(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(body): ValidatedJson<PlaylistCURequest>
+) -> Result<BaseResponse<PlaylistResponse>, AppError> {
+    let playlist = service::create(
+        &state.db,
+        claims.sub,
+        PlaylistInput {
+            title: body.title,
+            description: body.description
+        },
+        lang
+    ).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(playlist))
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/playlist",
+    security(("bearerAuth" = [])),
+    params(PlaylistPaginationParams),
+    responses((status = 200, body = CursorPagingResponse<PlaylistResponse>)),
+    tag = "Playlist"
+)]
+pub async fn page_playlist(
+    State(state): State<AppState>,
+    ValidatedQuery(params): ValidatedQuery<PlaylistPaginationParams>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<CursorPagingResponse<PlaylistResponse>>, AppError> {
+    let playlists = service::page(
+        &state.db,
+        claims.sub,
+        params.limit,
+        params.cursor
+    ).await?;
+
+    Ok(
+        BaseResponse::success(
+            CursorPagingResponse::new(
+                playlists.0.into_iter().map(mapper::to_response).collect(),
+                playlists.1
+            )
+        )
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/playlist/{id}",
+    security(("bearerAuth" = [])),
+    params(IdParam),
+    responses((status = 200, body = PlaylistResponse)),
+    tag = "Playlist"
+)]
+pub async fn get_playlist(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<PlaylistResponse>, AppError> {
+    let playlist = service::get(&state.db, id, claims.sub, lang).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(playlist))
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/user/playlist/{id}",
+    security(("bearerAuth" = [])),
+    params(IdParam),
+    request_body = PlaylistCURequest,
+    responses((status = 200, body = PlaylistResponse)),
+    tag = "Playlist"
+)]
+pub async fn update_playlist(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(body): ValidatedJson<PlaylistCURequest>
+) -> Result<BaseResponse<PlaylistResponse>, AppError> {
+    let playlist = service::update(
+        &state.db,
+        id,
+        claims.sub,
+        PlaylistInput {
+            title: body.title,
+            description: body.description
+        },
+        lang
+    ).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(playlist))
+    )
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/user/playlist/{id}",
+    security(("bearerAuth" = [])),
+    params(IdParam),
+    tag = "Playlist"
+)]
+pub async fn delete_playlist(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<()>, AppError> {
+    service::delete(&state.db, id, claims.sub, lang).await?;
+    Ok(BaseResponse::success(()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/playlist/{id}/item",
+    security(("bearerAuth" = [])),
+    params(IdParam, PlaylistPaginationParams),
+    responses((status = 200, body = CursorPagingResponse<LessonResponse>)),
+    tag = "Playlist"
+)]
+pub async fn page_playlist_item(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    ValidatedQuery(params): ValidatedQuery<PlaylistPaginationParams>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<CursorPagingResponse<LessonResponse>>, AppError> {
+    let mut lessons = service::items(
+        &state.db,
+        id,
+        claims.sub,
+        params.limit,
+        params.cursor,
+        lang.clone()
+    ).await?;
+    translation::service::localize_lessons(&state.db, &mut lessons.0, &lang).await?;
+
+    Ok(
+        BaseResponse::success(
+            CursorPagingResponse::new(
+                lessons.0.into_iter().map(lesson_mapper::to_response).collect(),
+                lessons.1
+            )
+        )
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/user/playlist/{id}/item",
+    security(("bearerAuth" = [])),
+    params(IdParam),
+    request_body = PlaylistItemRequest,
+    responses((status = 200, body = PlaylistResponse)),
+    tag = "Playlist"
+)]
+pub async fn add_playlist_item(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(body): ValidatedJson<PlaylistItemRequest>
+) -> Result<BaseResponse<PlaylistResponse>, AppError> {
+    let playlist = service::add_item(
+        &state.db,
+        id,
+        claims.sub,
+        body.lesson_id,
+        lang
+    ).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(playlist))
+    )
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/user/playlist/{id}/item/{lesson_id}",
+    security(("bearerAuth" = [])),
+    params(PlaylistItemParam),
+    responses((status = 200, body = PlaylistResponse)),
+    tag = "Playlist"
+)]
+pub async fn remove_playlist_item(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(param): ValidatedPath<PlaylistItemParam>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<PlaylistResponse>, AppError> {
+    let playlist = service::remove_item(
+        &state.db,
+        param.id,
+        claims.sub,
+        param.lesson_id,
+        lang
+    ).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(playlist))
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/user/playlist/{id}/item/order",
+    security(("bearerAuth" = [])),
+    params(IdParam),
+    request_body = PlaylistOrderRequest,
+    tag = "Playlist"
+)]
+pub async fn reorder_playlist_items(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(body): ValidatedJson<PlaylistOrderRequest>
+) -> Result<BaseResponse<()>, AppError> {
+    service::reorder(
+        &state.db,
+        id,
+        claims.sub,
+        body.lesson_ids,
+        lang
+    ).await?;
+    Ok(BaseResponse::success(()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/user/playlist/{id}/share",
+    security(("bearerAuth" = [])),
+    params(IdParam),
+    responses((status = 200, body = PlaylistResponse)),
+    tag = "Playlist"
+)]
+pub async fn share_playlist(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<PlaylistResponse>, AppError> {
+    let playlist = service::share(&state.db, id, claims.sub, lang).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(playlist))
+    )
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/user/playlist/{id}/share",
+    security(("bearerAuth" = [])),
+    params(IdParam),
+    responses((status = 200, body = PlaylistResponse)),
+    tag = "Playlist"
+)]
+pub async fn unshare_playlist(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<PlaylistResponse>, AppError> {
+    let playlist = service::unshare(&state.db, id, claims.sub, lang).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(playlist))
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/playlist/shared/{token}",
+    params(ShareTokenParam),
+    responses((status = 200, body = PlaylistResponse)),
+    tag = "Playlist"
+)]
+pub async fn view_shared_playlist(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(ShareTokenParam { token }): ValidatedPath<ShareTokenParam>
+) -> Result<BaseResponse<PlaylistResponse>, AppError> {
+    let playlist = service::shared(&state.db, token, lang).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(playlist))
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/playlist/shared/{token}/item",
+    params(ShareTokenParam, PlaylistPaginationParams),
+    responses((status = 200, body = CursorPagingResponse<LessonResponse>)),
+    tag = "Playlist"
+)]
+pub async fn page_shared_playlist_item(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(ShareTokenParam { token }): ValidatedPath<ShareTokenParam>,
+    ValidatedQuery(params): ValidatedQuery<PlaylistPaginationParams>
+) -> Result<BaseResponse<CursorPagingResponse<LessonResponse>>, AppError> {
+    let mut lessons = service::shared_items(
+        &state.db,
+        token,
+        params.limit,
+        params.cursor,
+        lang.clone()
+    ).await?;
+    translation::service::localize_lessons(&state.db, &mut lessons.0, &lang).await?;
+
+    Ok(
+        BaseResponse::success(
+            CursorPagingResponse::new(
+                lessons.0.into_iter().map(lesson_mapper::to_response).collect(),
+                lessons.1
+            )
+        )
+    )
+}
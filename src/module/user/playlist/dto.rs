@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+pub struct PlaylistCURequest {
+    #[validate(length(min = 1, max = 128))]
+    pub title: String,
+    #[validate(length(max = 1024))]
+    pub description: Option<String>
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PlaylistResponse {
+    pub id: i64,
+    pub title: String,
+    pub description: Option<String>,
+    /// Items visible to users; hidden lessons stay in the playlist but are not listed
+    pub item_count: i64,
+    pub total_duration: i64,
+    /// Public landing page, `null` while the playlist is private
+    pub share_url: Option<String>,
+    pub share_token: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+pub struct PlaylistItemRequest {
+    pub lesson_id: i64
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+pub struct PlaylistOrderRequest {
+    /// Every listed lesson of the playlist, first to last
+    pub lesson_ids: Vec<i64>
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct PlaylistItemParam {
+    pub id: i64,
+    pub lesson_id: i64
+}
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PlaylistPaginationParams {
+    #[param(example = json!(20))]
+    pub limit: u32,
+    pub cursor: Option<String>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PlaylistCursor {
+    pub id: i64
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PlaylistItemCursor {
+    pub position: i32,
+    pub lesson_id: i64
+}
@@ -0,0 +1,18 @@
+use crate::db::playlist::entity::PlaylistEntity;
+use crate::module::user::playlist::dto::PlaylistResponse;
+use crate::utils::CONFIG;
+
+pub fn to_response(entity: PlaylistEntity) -> PlaylistResponse {
+    PlaylistResponse {
+        id: entity.id,
+        title: entity.title,
+        description: entity.description,
+        item_count: entity.item_count,
+        total_duration: entity.total_duration,
+        share_url: entity.share_token.as_ref()
+            .map(|token| format!("{}/playlist/{}", CONFIG.client_origin, token)),
+        share_token: entity.share_token,
+        created_at: entity.created_at,
+        updated_at: entity.updated_at
+    }
+}
@@ -0,0 +1,30 @@
+use axum::{Router, middleware};
+use axum::routing::{delete, get, post, put};
+use crate::middleware::auth::user_auth_middleware;
+use crate::module::user::playlist::controller::{add_playlist_item, create_playlist, delete_playlist, get_playlist, page_playlist, page_playlist_item, page_shared_playlist_item, remove_playlist_item, reorder_playlist_items, share_playlist, unshare_playlist, update_playlist, view_shared_playlist};
+use crate::state::AppState;
+
+pub fn routes() -> Router<AppState> {
+    // Public routes (no auth)
+    let public = Router::new()
+        .route("/playlist/shared/{token}", get(view_shared_playlist))
+        .route("/playlist/shared/{token}/item", get(page_shared_playlist_item));
+
+    let authenticated = Router::new()
+        .route("/playlist", post(create_playlist))
+        .route("/playlist", get(page_playlist))
+        .route("/playlist/{id}", get(get_playlist))
+        .route("/playlist/{id}", put(update_playlist))
+        .route("/playlist/{id}", delete(delete_playlist))
+        .route("/playlist/{id}/item", get(page_playlist_item))
+        .route("/playlist/{id}/item", post(add_playlist_item))
+        .route("/playlist/{id}/item/{lesson_id}", delete(remove_playlist_item))
+        .route("/playlist/{id}/item/order", put(reorder_playlist_items))
+        .route("/playlist/{id}/share", post(share_playlist))
+        .route("/playlist/{id}/share", delete(unshare_playlist))
+        .layer(middleware::from_fn(user_auth_middleware));
+
+    Router::new()
+        .merge(public)
+        .merge(authenticated)
+}
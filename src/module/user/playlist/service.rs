@@ -0,0 +1,226 @@
+use crate::db;
+use crate::db::lesson::entity::LessonWithAuthorTopic;
+use crate::db::playlist::entity::{PlaylistEntity, PlaylistInput};
+use crate::error::playlist::PlaylistError;
+use crate::error::AppError;
+use crate::module::common::chapter;
+use crate::module::user::playlist::dto::{PlaylistCursor, PlaylistItemCursor};
+use crate::utils;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use fluent_templates::LanguageIdentifier;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Playlists are private to their owner; other users get `NotFound`.
+pub async fn get(
+    db: &PgPool,
+    id: i64,
+    user_id: i64,
+    lang: LanguageIdentifier
+) -> Result<PlaylistEntity, AppError> {
+    match db::playlist::repo::get_by_id(db, id).await? {
+        Some(playlist) if playlist.user_id == user_id => Ok(playlist),
+        _ => Err(AppError::NotFound(lang))
+    }
+}
+
+pub async fn create(
+    db: &PgPool,
+    user_id: i64,
+    playlist: PlaylistInput,
+    lang: LanguageIdentifier
+) -> Result<PlaylistEntity, AppError> {
+    let id = db::playlist::repo::insert(db, user_id, playlist).await?;
+    get(db, id, user_id, lang).await
+}
+
+pub async fn update(
+    db: &PgPool,
+    id: i64,
+    user_id: i64,
+    playlist: PlaylistInput,
+    lang: LanguageIdentifier
+) -> Result<PlaylistEntity, AppError> {
+    if db::playlist::repo::update(db, id, user_id, playlist).await? == 0 {
+        return Err(AppError::NotFound(lang));
+    }
+    get(db, id, user_id, lang).await
+}
+
+pub async fn delete(
+    db: &PgPool,
+    id: i64,
+    user_id: i64,
+    lang: LanguageIdentifier
+) -> Result<(), AppError> {
+    if db::playlist::repo::delete(db, id, user_id).await? == 0 {
+        return Err(AppError::NotFound(lang));
+    }
+    Ok(())
+}
+
+pub async fn page(
+    db: &PgPool,
+    user_id: i64,
+    limit: u32,
+    cursor: Option<String>
+) -> Result<(Vec<PlaylistEntity>, Option<String>), AppError> {
+    let mut items = db::playlist::repo::page(
+        db,
+        user_id,
+        limit + 1,
+        utils::cursor::decode(cursor)
+    ).await?;
+
+    let next_cursor = if items.len() == (limit + 1) as usize {
+        items.remove(limit as usize);
+        utils::cursor::encode(PlaylistCursor { id: items.last().unwrap().id })
+    } else { None };
+
+    Ok((items, next_cursor))
+}
+
+pub async fn share(
+    db: &PgPool,
+    id: i64,
+    user_id: i64,
+    lang: LanguageIdentifier
+) -> Result<PlaylistEntity, AppError> {
+    // 122 random bits, unguessable without being sequential
+    let token = URL_SAFE_NO_PAD.encode(Uuid::new_v4().as_bytes());
+    if db::playlist::repo::share(db, id, user_id, token).await? == 0 {
+        return Err(AppError::NotFound(lang));
+    }
+    get(db, id, user_id, lang).await
+}
+
+/// Makes the playlist private again; the old link stops working.
+pub async fn unshare(
+    db: &PgPool,
+    id: i64,
+    user_id: i64,
+    lang: LanguageIdentifier
+) -> Result<PlaylistEntity, AppError> {
+    if db::playlist::repo::unshare(db, id, user_id).await? == 0 {
+        return Err(AppError::NotFound(lang));
+    }
+    get(db, id, user_id, lang).await
+}
+
+pub async fn add_item(
+    db: &PgPool,
+    id: i64,
+    user_id: i64,
+    lesson_id: i64,
+    lang: LanguageIdentifier
+) -> Result<PlaylistEntity, AppError> {
+    get(db, id, user_id, lang.clone()).await?;
+    if !db::lesson::repo::is_visible(db, lesson_id).await? {
+        return Err(AppError::NotFound(lang));
+    }
+
+    db::playlist::repo::add_item(db, id, lesson_id).await?;
+    get(db, id, user_id, lang).await
+}
+
+pub async fn remove_item(
+    db: &PgPool,
+    id: i64,
+    user_id: i64,
+    lesson_id: i64,
+    lang: LanguageIdentifier
+) -> Result<PlaylistEntity, AppError> {
+    get(db, id, user_id, lang.clone()).await?;
+    if db::playlist::repo::remove_item(db, id, lesson_id).await? == 0 {
+        return Err(AppError::NotFound(lang));
+    }
+    get(db, id, user_id, lang).await
+}
+
+/// Reorders the playlist. `lesson_ids` must contain every listed item
+/// exactly once.
+pub async fn reorder(
+    db: &PgPool,
+    id: i64,
+    user_id: i64,
+    lesson_ids: Vec<i64>,
+    lang: LanguageIdentifier
+) -> Result<(), AppError> {
+    get(db, id, user_id, lang.clone()).await?;
+
+    let mut tx = db.begin().await?;
+    let mut current = db::playlist::repo::lock_item_lesson_ids(&mut tx, id).await?;
+    let mut requested = lesson_ids.clone();
+    current.sort_unstable();
+    requested.sort_unstable();
+    if current != requested {
+        return Err(PlaylistError::PlaylistOrderMismatch(lang).into())
+    }
+
+    db::playlist::repo::update_positions(&mut tx, id, &lesson_ids).await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+async fn page_items(
+    db: &PgPool,
+    id: i64,
+    user_id: Option<i64>,
+    limit: u32,
+    cursor: Option<String>
+) -> Result<(Vec<LessonWithAuthorTopic>, Option<String>), AppError> {
+    let mut items = db::playlist::repo::items(
+        db,
+        id,
+        user_id,
+        limit + 1,
+        utils::cursor::decode(cursor)
+    ).await?;
+
+    let next_cursor = if items.len() == (limit + 1) as usize {
+        items.remove(limit as usize);
+        let last = items.last().unwrap();
+        utils::cursor::encode(PlaylistItemCursor {
+            position: last.item_position,
+            lesson_id: last.lesson.lesson.id
+        })
+    } else { None };
+
+    let mut lessons: Vec<LessonWithAuthorTopic> = items.into_iter().map(|item| item.lesson).collect();
+    chapter::service::attach(db, &mut lessons).await?;
+
+    Ok((lessons, next_cursor))
+}
+
+pub async fn items(
+    db: &PgPool,
+    id: i64,
+    user_id: i64,
+    limit: u32,
+    cursor: Option<String>,
+    lang: LanguageIdentifier
+) -> Result<(Vec<LessonWithAuthorTopic>, Option<String>), AppError> {
+    get(db, id, user_id, lang).await?;
+    page_items(db, id, Some(user_id), limit, cursor).await
+}
+
+pub async fn shared(
+    db: &PgPool,
+    token: String,
+    lang: LanguageIdentifier
+) -> Result<PlaylistEntity, AppError> {
+    db::playlist::repo::get_by_share_token(db, token)
+        .await?
+        .ok_or(AppError::NotFound(lang))
+}
+
+pub async fn shared_items(
+    db: &PgPool,
+    token: String,
+    limit: u32,
+    cursor: Option<String>,
+    lang: LanguageIdentifier
+) -> Result<(Vec<LessonWithAuthorTopic>, Option<String>), AppError> {
+    let playlist = shared(db, token, lang).await?;
+    page_items(db, playlist.id, None, limit, cursor).await
+}
@@ -0,0 +1,194 @@
+use crate::error::AppError;
+use crate::extractor::accept_language::AcceptLanguage;
+use crate::module::common::base::BaseResponse;
+use crate::module::common::translation;
+use crate::module::user::queue::dto::{QueueCurrentRequest, QueueItemParam, QueueItemRequest, QueueOrderRequest, QueueResponse, QueueVersionParams};
+use crate::module::user::queue::mapper;
+use crate::module::user::queue::service;
+use crate::state::AppState;
+use crate::utils::extractors::{ValidatedJson, ValidatedPath, ValidatedQuery};
+use crate::utils::jwt::Claims;
+use axum::extract::State;
+use axum::Extension;
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/queue",
+    security(("bearerAuth" = [])),
+    responses((status = 200, body = QueueResponse)),
+    tag = "Queue"
+)]
+pub async fn get_queue(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<QueueResponse>, AppError> {
+    let (queue, mut items) = service::get(&state.db, claims.sub).await?;
+    translation::service::localize_lessons(&state.db, &mut items, &lang).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(queue, items))
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/user/queue/item",
+    security(("bearerAuth" = [])),
+    request_body = QueueItemRequest,
+    responses((status = 200, body = QueueResponse)),
+    tag = "Queue"
+)]
+pub async fn enqueue_lesson(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(body): ValidatedJson<QueueItemRequest>
+) -> Result<BaseResponse<QueueResponse>, AppError> {
+    let (queue, mut items) = service::enqueue(
+        &state.db,
+        claims.sub,
+        body.version,
+        body.lesson_id,
+        lang.clone()
+    ).await?;
+    translation::service::localize_lessons(&state.db, &mut items, &lang).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(queue, items))
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/user/queue/item/next",
+    security(("bearerAuth" = [])),
+    request_body = QueueItemRequest,
+    responses((status = 200, body = QueueResponse)),
+    tag = "Queue"
+)]
+pub async fn play_lesson_next(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(body): ValidatedJson<QueueItemRequest>
+) -> Result<BaseResponse<QueueResponse>, AppError> {
+    let (queue, mut items) = service::play_next(
+        &state.db,
+        claims.sub,
+        body.version,
+        body.lesson_id,
+        lang.clone()
+    ).await?;
+    translation::service::localize_lessons(&state.db, &mut items, &lang).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(queue, items))
+    )
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/user/queue/item/{lesson_id}",
+    security(("bearerAuth" = [])),
+    params(QueueItemParam, QueueVersionParams),
+    responses((status = 200, body = QueueResponse)),
+    tag = "Queue"
+)]
+pub async fn dequeue_lesson(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(QueueItemParam { lesson_id }): ValidatedPath<QueueItemParam>,
+    ValidatedQuery(params): ValidatedQuery<QueueVersionParams>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<QueueResponse>, AppError> {
+    let (queue, mut items) = service::dequeue(
+        &state.db,
+        claims.sub,
+        params.version,
+        lesson_id,
+        lang.clone()
+    ).await?;
+    translation::service::localize_lessons(&state.db, &mut items, &lang).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(queue, items))
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/user/queue/item/order",
+    security(("bearerAuth" = [])),
+    request_body = QueueOrderRequest,
+    responses((status = 200, body = QueueResponse)),
+    tag = "Queue"
+)]
+pub async fn reorder_queue(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(body): ValidatedJson<QueueOrderRequest>
+) -> Result<BaseResponse<QueueResponse>, AppError> {
+    let (queue, mut items) = service::reorder(
+        &state.db,
+        claims.sub,
+        body.version,
+        body.lesson_ids,
+        lang.clone()
+    ).await?;
+    translation::service::localize_lessons(&state.db, &mut items, &lang).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(queue, items))
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/user/queue/current",
+    security(("bearerAuth" = [])),
+    request_body = QueueCurrentRequest,
+    responses((status = 200, body = QueueResponse)),
+    tag = "Queue"
+)]
+pub async fn set_queue_current(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(body): ValidatedJson<QueueCurrentRequest>
+) -> Result<BaseResponse<QueueResponse>, AppError> {
+    let (queue, mut items) = service::set_current(
+        &state.db,
+        claims.sub,
+        body.version,
+        body.lesson_id,
+        lang.clone()
+    ).await?;
+    translation::service::localize_lessons(&state.db, &mut items, &lang).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(queue, items))
+    )
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/user/queue",
+    security(("bearerAuth" = [])),
+    params(QueueVersionParams),
+    responses((status = 200, body = QueueResponse)),
+    tag = "Queue"
+)]
+pub async fn clear_queue(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedQuery(params): ValidatedQuery<QueueVersionParams>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<QueueResponse>, AppError> {
+    let (queue, items) = service::clear(&state.db, claims.sub, params.version, lang).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(queue, items))
+    )
+}
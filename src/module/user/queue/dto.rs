@@ -0,0 +1,54 @@
+use crate::module::user::lesson::dto::LessonResponse;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+pub struct QueueItemRequest {
+    /// Version of the queue the change is based on
+    pub version: i64,
+    pub lesson_id: i64
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+pub struct QueueOrderRequest {
+    pub version: i64,
+    /// Every listed lesson of the queue, first to last
+    pub lesson_ids: Vec<i64>
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+pub struct QueueCurrentRequest {
+    pub version: i64,
+    /// A queued lesson, or `null` to stop
+    pub lesson_id: Option<i64>
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct QueueItemParam {
+    pub lesson_id: i64
+}
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct QueueVersionParams {
+    pub version: i64
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct QueueCurrentResponse {
+    pub lesson_id: i64,
+    /// Where the user stopped in the lesson on any device
+    pub last_position_ms: i64
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct QueueResponse {
+    /// Send back with the next change; `0` before the first one
+    pub version: i64,
+    pub current: Option<QueueCurrentResponse>,
+    pub items: Vec<LessonResponse>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime
+}
@@ -0,0 +1,16 @@
+use crate::db::lesson::entity::LessonWithAuthorTopic;
+use crate::db::queue::entity::QueueEntity;
+use crate::module::user::lesson::mapper as lesson_mapper;
+use crate::module::user::queue::dto::{QueueCurrentResponse, QueueResponse};
+
+pub fn to_response(entity: QueueEntity, items: Vec<LessonWithAuthorTopic>) -> QueueResponse {
+    QueueResponse {
+        version: entity.version,
+        current: entity.current_lesson_id.map(|lesson_id| QueueCurrentResponse {
+            lesson_id,
+            last_position_ms: entity.current_position_ms.unwrap_or(0)
+        }),
+        items: items.into_iter().map(lesson_mapper::to_response).collect(),
+        updated_at: entity.updated_at
+    }
+}
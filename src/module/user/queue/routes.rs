@@ -0,0 +1,17 @@
+use axum::{Router, middleware};
+use axum::routing::{delete, get, post, put};
+use crate::middleware::auth::user_auth_middleware;
+use crate::module::user::queue::controller::{clear_queue, dequeue_lesson, enqueue_lesson, get_queue, play_lesson_next, reorder_queue, set_queue_current};
+use crate::state::AppState;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/queue", get(get_queue))
+        .route("/queue", delete(clear_queue))
+        .route("/queue/item", post(enqueue_lesson))
+        .route("/queue/item/next", post(play_lesson_next))
+        .route("/queue/item/{lesson_id}", delete(dequeue_lesson))
+        .route("/queue/item/order", put(reorder_queue))
+        .route("/queue/current", put(set_queue_current))
+        .layer(middleware::from_fn(user_auth_middleware))
+}
@@ -0,0 +1,176 @@
+use crate::db;
+use crate::db::lesson::entity::LessonWithAuthorTopic;
+use crate::db::queue::entity::QueueEntity;
+use crate::error::queue::QueueError;
+use crate::error::AppError;
+use crate::module::common::chapter;
+use fluent_templates::LanguageIdentifier;
+use sqlx::{PgPool, Postgres, Transaction};
+use time::OffsetDateTime;
+
+/// Locks the queue of the user and checks that the change is based on its
+/// latest version.
+async fn begin(
+    db: &PgPool,
+    user_id: i64,
+    version: i64,
+    lang: &LanguageIdentifier
+) -> Result<(Transaction<'static, Postgres>, QueueEntity), AppError> {
+    let mut tx = db.begin().await?;
+    let queue = db::queue::repo::lock(&mut tx, user_id).await?;
+    if queue.version != version {
+        return Err(QueueError::QueueVersionConflict(lang.clone(), queue.version).into());
+    }
+    Ok((tx, queue))
+}
+
+/// Users who never changed their queue get an empty one at version `0`.
+pub async fn get(
+    db: &PgPool,
+    user_id: i64
+) -> Result<(QueueEntity, Vec<LessonWithAuthorTopic>), AppError> {
+    let queue = db::queue::repo::get(db, user_id).await?.unwrap_or(QueueEntity {
+        version: 0,
+        current_lesson_id: None,
+        current_position_ms: None,
+        updated_at: OffsetDateTime::now_utc()
+    });
+
+    let mut items = db::queue::repo::items(db, user_id).await?;
+    chapter::service::attach(db, &mut items).await?;
+    Ok((queue, items))
+}
+
+/// Adds the lesson to the end of the queue, moving it there if it is queued
+/// already. It starts playing when nothing else is.
+pub async fn enqueue(
+    db: &PgPool,
+    user_id: i64,
+    version: i64,
+    lesson_id: i64,
+    lang: LanguageIdentifier
+) -> Result<(QueueEntity, Vec<LessonWithAuthorTopic>), AppError> {
+    if !db::lesson::repo::is_visible(db, lesson_id).await? {
+        return Err(AppError::NotFound(lang));
+    }
+
+    let (mut tx, queue) = begin(db, user_id, version, &lang).await?;
+    db::queue::repo::remove(&mut tx, user_id, lesson_id).await?;
+    db::queue::repo::append(&mut tx, user_id, lesson_id).await?;
+    db::queue::repo::bump(&mut tx, user_id, queue.current_lesson_id.or(Some(lesson_id))).await?;
+    tx.commit().await?;
+
+    get(db, user_id).await
+}
+
+/// Queues the lesson right after the one playing.
+pub async fn play_next(
+    db: &PgPool,
+    user_id: i64,
+    version: i64,
+    lesson_id: i64,
+    lang: LanguageIdentifier
+) -> Result<(QueueEntity, Vec<LessonWithAuthorTopic>), AppError> {
+    if !db::lesson::repo::is_visible(db, lesson_id).await? {
+        return Err(AppError::NotFound(lang));
+    }
+
+    let (mut tx, queue) = begin(db, user_id, version, &lang).await?;
+    if queue.current_lesson_id != Some(lesson_id) {
+        db::queue::repo::remove(&mut tx, user_id, lesson_id).await?;
+        let position = match queue.current_lesson_id {
+            Some(current) => db::queue::repo::get_position(&mut tx, user_id, current)
+                .await?
+                .map_or(0, |position| position + 1),
+            None => 0
+        };
+        db::queue::repo::insert_at(&mut tx, user_id, lesson_id, position).await?;
+    }
+    db::queue::repo::bump(&mut tx, user_id, queue.current_lesson_id.or(Some(lesson_id))).await?;
+    tx.commit().await?;
+
+    get(db, user_id).await
+}
+
+/// Removing the lesson that is playing moves playback on to the next one.
+pub async fn dequeue(
+    db: &PgPool,
+    user_id: i64,
+    version: i64,
+    lesson_id: i64,
+    lang: LanguageIdentifier
+) -> Result<(QueueEntity, Vec<LessonWithAuthorTopic>), AppError> {
+    let (mut tx, queue) = begin(db, user_id, version, &lang).await?;
+    let Some(position) = db::queue::repo::get_position(&mut tx, user_id, lesson_id).await? else {
+        return Err(AppError::NotFound(lang));
+    };
+
+    let current = if queue.current_lesson_id == Some(lesson_id) {
+        db::queue::repo::next_lesson_id(&mut tx, user_id, position).await?
+    } else {
+        queue.current_lesson_id
+    };
+    db::queue::repo::remove(&mut tx, user_id, lesson_id).await?;
+    db::queue::repo::bump(&mut tx, user_id, current).await?;
+    tx.commit().await?;
+
+    get(db, user_id).await
+}
+
+/// Reorders the queue. `lesson_ids` must contain every listed item exactly
+/// once.
+pub async fn reorder(
+    db: &PgPool,
+    user_id: i64,
+    version: i64,
+    lesson_ids: Vec<i64>,
+    lang: LanguageIdentifier
+) -> Result<(QueueEntity, Vec<LessonWithAuthorTopic>), AppError> {
+    let (mut tx, queue) = begin(db, user_id, version, &lang).await?;
+    let mut current = db::queue::repo::item_lesson_ids(&mut tx, user_id).await?;
+    let mut requested = lesson_ids.clone();
+    current.sort_unstable();
+    requested.sort_unstable();
+    if current != requested {
+        return Err(QueueError::QueueOrderMismatch(lang).into())
+    }
+
+    db::queue::repo::update_positions(&mut tx, user_id, &lesson_ids).await?;
+    db::queue::repo::bump(&mut tx, user_id, queue.current_lesson_id).await?;
+    tx.commit().await?;
+
+    get(db, user_id).await
+}
+
+/// Switches playback to a queued lesson, or stops it with `None`.
+pub async fn set_current(
+    db: &PgPool,
+    user_id: i64,
+    version: i64,
+    lesson_id: Option<i64>,
+    lang: LanguageIdentifier
+) -> Result<(QueueEntity, Vec<LessonWithAuthorTopic>), AppError> {
+    let (mut tx, _) = begin(db, user_id, version, &lang).await?;
+    if let Some(lesson_id) = lesson_id
+        && db::queue::repo::get_position(&mut tx, user_id, lesson_id).await?.is_none() {
+        return Err(AppError::NotFound(lang));
+    }
+    db::queue::repo::bump(&mut tx, user_id, lesson_id).await?;
+    tx.commit().await?;
+
+    get(db, user_id).await
+}
+
+pub async fn clear(
+    db: &PgPool,
+    user_id: i64,
+    version: i64,
+    lang: LanguageIdentifier
+) -> Result<(QueueEntity, Vec<LessonWithAuthorTopic>), AppError> {
+    let (mut tx, _) = begin(db, user_id, version, &lang).await?;
+    db::queue::repo::clear(&mut tx, user_id).await?;
+    db::queue::repo::bump(&mut tx, user_id, None).await?;
+    tx.commit().await?;
+
+    get(db, user_id).await
+}
@@ -0,0 +1,111 @@
+use crate::error::AppError;
+use crate::extractor::accept_language::AcceptLanguage;
+use crate::module::common::base::{BaseResponse, IdParam};
+use crate::module::common::paging::CursorPagingResponse;
+use crate::module::user::review::dto::{ReviewPaginationParams, ReviewRequest, ReviewResponse};
+use crate::module::user::review::mapper;
+use crate::module::user::review::service;
+use crate::state::AppState;
+use crate::utils::extractors::{ValidatedJson, ValidatedPath, ValidatedQuery};
+use crate::utils::jwt::Claims;
+use axum::extract::State;
+use axum::Extension;
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/lesson/{id}/review",
+    security(("bearerAuth" = [])),
+    params(IdParam, ReviewPaginationParams),
+    responses((status = 200, body = CursorPagingResponse<ReviewResponse>)),
+    tag = "Review"
+)]
+pub async fn page_review(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    ValidatedQuery(params): ValidatedQuery<ReviewPaginationParams>
+) -> Result<BaseResponse<CursorPagingResponse<ReviewResponse>>, AppError> {
+    let (items, next_cursor) = service::page(
+        &state.db,
+        id,
+        params.limit,
+        params.cursor,
+        lang
+    ).await?;
+
+    Ok(
+        BaseResponse::success(
+            CursorPagingResponse::new(
+                items.into_iter().map(mapper::to_response).collect(),
+                next_cursor
+            )
+        )
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/lesson/{id}/review/mine",
+    security(("bearerAuth" = [])),
+    params(IdParam),
+    responses((status = 200, body = Option<ReviewResponse>)),
+    tag = "Review"
+)]
+pub async fn get_own_review(
+    State(state): State<AppState>,
+    ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<Option<ReviewResponse>>, AppError> {
+    let review = service::get_own(&state.db, claims.sub, id).await?;
+
+    Ok(
+        BaseResponse::success(review.map(mapper::to_response))
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/user/lesson/{id}/review",
+    security(("bearerAuth" = [])),
+    params(IdParam),
+    request_body = ReviewRequest,
+    responses((status = 200, body = ReviewResponse)),
+    tag = "Review"
+)]
+pub async fn upsert_review(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(body): ValidatedJson<ReviewRequest>
+) -> Result<BaseResponse<ReviewResponse>, AppError> {
+    let review = service::upsert(
+        &state.db,
+        claims.sub,
+        id,
+        body.rating,
+        body.text,
+        lang
+    ).await?;
+
+    Ok(
+        BaseResponse::success(mapper::to_response(review))
+    )
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/user/lesson/{id}/review",
+    security(("bearerAuth" = [])),
+    params(IdParam),
+    tag = "Review"
+)]
+pub async fn delete_review(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(id): ValidatedPath<i64>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<()>, AppError> {
+    service::delete(&state.db, claims.sub, id, lang).await?;
+    Ok(BaseResponse::success(()))
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ReviewPaginationParams {
+    #[param(example = json!(20))]
+    pub limit: u32,
+    pub cursor: Option<String>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReviewCursor {
+    pub id: i64,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+pub struct ReviewRequest {
+    /// 1 to 5 stars
+    #[validate(range(min = 1, max = 5))]
+    pub rating: i16,
+    /// Optional; blank text is dropped
+    #[validate(length(max = 2000))]
+    pub text: Option<String>
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ReviewerResponse {
+    pub first_name: String,
+    pub avatar_path: Option<String>
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ReviewResponse {
+    pub id: i64,
+    pub lesson_id: i64,
+    pub rating: i16,
+    pub text: Option<String>,
+    pub user: ReviewerResponse,
+    /// Hidden by a moderator; only its author still sees it
+    pub hidden: bool,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime
+}
@@ -0,0 +1,18 @@
+use crate::db::review::entity::ReviewWithUserEntity;
+use crate::module::user::review::dto::{ReviewResponse, ReviewerResponse};
+
+pub fn to_response(entity: ReviewWithUserEntity) -> ReviewResponse {
+    ReviewResponse {
+        id: entity.review.id,
+        lesson_id: entity.review.lesson_id,
+        rating: entity.review.rating,
+        text: entity.review.text,
+        user: ReviewerResponse {
+            first_name: entity.user_first_name,
+            avatar_path: entity.user_avatar_path
+        },
+        hidden: entity.review.hidden_at.is_some(),
+        created_at: entity.review.created_at,
+        updated_at: entity.review.updated_at
+    }
+}
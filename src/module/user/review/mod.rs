@@ -0,0 +1,5 @@
+pub mod routes;
+pub mod controller;
+pub mod dto;
+pub mod mapper;
+pub mod service;
\ No newline at end of file
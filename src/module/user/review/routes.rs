@@ -0,0 +1,14 @@
+use axum::{Router, middleware};
+use axum::routing::{delete, get, put};
+use crate::middleware::auth::user_auth_middleware;
+use crate::module::user::review::controller::{delete_review, get_own_review, page_review, upsert_review};
+use crate::state::AppState;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/lesson/{id}/review", get(page_review))
+        .route("/lesson/{id}/review", put(upsert_review))
+        .route("/lesson/{id}/review", delete(delete_review))
+        .route("/lesson/{id}/review/mine", get(get_own_review))
+        .layer(middleware::from_fn(user_auth_middleware))
+}
@@ -0,0 +1,95 @@
+use crate::db;
+use crate::db::review::entity::ReviewWithUserEntity;
+use crate::error::review::ReviewError;
+use crate::error::AppError;
+use crate::module::common::review;
+use crate::module::user::review::dto::ReviewCursor;
+use crate::utils;
+use fluent_templates::LanguageIdentifier;
+use sqlx::PgPool;
+
+pub async fn page(
+    db: &PgPool,
+    lesson_id: i64,
+    limit: u32,
+    cursor: Option<String>,
+    lang: LanguageIdentifier
+) -> Result<(Vec<ReviewWithUserEntity>, Option<String>), AppError> {
+    if !db::lesson::repo::is_visible(db, lesson_id).await? {
+        return Err(AppError::NotFound(lang));
+    }
+    let mut items = db::review::repo::page(
+        db,
+        lesson_id,
+        limit + 1,
+        utils::cursor::decode(cursor)
+    ).await?;
+
+    let next_cursor = if items.len() == (limit + 1) as usize {
+        items.remove(limit as usize);
+        let last = items.last().unwrap();
+        utils::cursor::encode(ReviewCursor {
+            id: last.review.id,
+            created_at: last.review.created_at
+        })
+    } else { None };
+
+    Ok((items, next_cursor))
+}
+
+pub async fn get_own(
+    db: &PgPool,
+    user_id: i64,
+    lesson_id: i64
+) -> Result<Option<ReviewWithUserEntity>, AppError> {
+    Ok(db::review::repo::get_by_user(db, user_id, lesson_id).await?)
+}
+
+/// Rates the lesson, replacing an earlier review. Only users who completed
+/// the lesson or had a listen of it counted may review it, with or without a
+/// text.
+pub async fn upsert(
+    db: &PgPool,
+    user_id: i64,
+    lesson_id: i64,
+    rating: i16,
+    text: Option<String>,
+    lang: LanguageIdentifier
+) -> Result<ReviewWithUserEntity, AppError> {
+    if !db::lesson::repo::is_visible(db, lesson_id).await? {
+        return Err(AppError::NotFound(lang));
+    }
+    if !db::review::repo::has_progress(db, user_id, lesson_id).await? {
+        return Err(ReviewError::ReviewNeedsProgress(lang).into());
+    }
+    let text = text
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty());
+
+    let mut tx = db.begin().await?;
+    db::review::repo::upsert(&mut tx, user_id, lesson_id, rating, text).await?;
+    review::service::recount(&mut tx, lesson_id).await?;
+    tx.commit().await?;
+
+    db::review::repo::get_by_user(db, user_id, lesson_id)
+        .await?
+        .ok_or(AppError::NotFound(lang))
+}
+
+pub async fn delete(
+    db: &PgPool,
+    user_id: i64,
+    lesson_id: i64,
+    lang: LanguageIdentifier
+) -> Result<(), AppError> {
+    let mut tx = db.begin().await?;
+    if db::review::repo::delete(&mut tx, user_id, lesson_id).await? == 0 {
+        return match db::review::repo::get_by_user(db, user_id, lesson_id).await? {
+            Some(_) => Err(ReviewError::ReviewHidden(lang).into()),
+            None => Err(AppError::NotFound(lang))
+        };
+    }
+    review::service::recount(&mut tx, lesson_id).await?;
+    tx.commit().await?;
+    Ok(())
+}
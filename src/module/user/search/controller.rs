@@ -0,0 +1,41 @@
+use crate::error::AppError;
+use crate::extractor::accept_language::AcceptLanguage;
+use crate::module::common::base::BaseResponse;
+use crate::module::common::paging::CursorPagingResponse;
+use crate::module::user::search::dto::{SearchParams, SearchResultResponse};
+use crate::module::user::search::mapper;
+use crate::module::user::search::service;
+use crate::state::AppState;
+use crate::utils::extractors::ValidatedQuery;
+use axum::extract::State;
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/search",
+    security(("bearerAuth" = [])),
+    params(SearchParams),
+    responses((status = 200, body = CursorPagingResponse<SearchResultResponse>)),
+    tag = "Search"
+)]
+pub async fn search(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedQuery(params): ValidatedQuery<SearchParams>
+) -> Result<BaseResponse<CursorPagingResponse<SearchResultResponse>>, AppError> {
+    let results = service::search(
+        &state.db,
+        params.q,
+        params.limit,
+        params.cursor,
+        lang
+    ).await?;
+
+    Ok(
+        BaseResponse::success(
+            CursorPagingResponse::new(
+                results.0.into_iter().map(mapper::to_response).collect(),
+                results.1
+            )
+        )
+    )
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use crate::module::common::enums::SearchKind;
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchParams {
+    pub q: String,
+    pub limit: u32,
+    pub cursor: Option<String>
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SearchResultResponse {
+    pub kind: SearchKind,
+    pub id: i64,
+    pub title: String,
+    /// HTML-escaped title with matches wrapped in `<b>`, the only markup
+    pub title_highlight: String,
+    /// HTML-escaped description fragments with matches wrapped in `<b>`
+    pub snippet: Option<String>,
+    pub image_path: Option<String>,
+    pub author_id: i64,
+    pub author_name: String,
+    pub rank: f32
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SearchCursor {
+    pub rank: f32,
+    pub kind: String,
+    pub id: i64
+}
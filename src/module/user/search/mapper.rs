@@ -0,0 +1,22 @@
+use crate::db::search::entity::SearchResultEntity;
+use crate::module::common::enums::SearchKind;
+use crate::module::user::search::dto::SearchResultResponse;
+use crate::utils::search::highlight_to_html;
+
+pub fn to_response(entity: SearchResultEntity) -> SearchResultResponse {
+    SearchResultResponse {
+        kind: match entity.kind.as_str() {
+            "author" => SearchKind::Author,
+            "topic" => SearchKind::Topic,
+            _ => SearchKind::Lesson
+        },
+        id: entity.id,
+        title: entity.title,
+        title_highlight: highlight_to_html(&entity.title_highlight),
+        snippet: entity.snippet.as_deref().map(highlight_to_html),
+        image_path: entity.image_path,
+        author_id: entity.author_id,
+        author_name: entity.author_name,
+        rank: entity.rank
+    }
+}
@@ -0,0 +1,5 @@
+pub mod routes;
+pub mod controller;
+pub mod dto;
+pub mod service;
+mod mapper;
\ No newline at end of file
@@ -0,0 +1,10 @@
+use axum::{Router, routing::get, middleware};
+use crate::middleware::auth::user_auth_middleware;
+use crate::module::user::search::controller::search;
+use crate::state::AppState;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/search", get(search))
+        .layer(middleware::from_fn(user_auth_middleware))
+}
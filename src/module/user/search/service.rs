@@ -0,0 +1,43 @@
+use crate::db::search::entity::SearchResultEntity;
+use crate::error::AppError;
+use crate::module::common::translation;
+use crate::module::user::search::dto::SearchCursor;
+use crate::{db, utils};
+use fluent_templates::LanguageIdentifier;
+use sqlx::PgPool;
+
+pub async fn search(
+    db: &PgPool,
+    search: String,
+    limit: u32,
+    cursor: Option<String>,
+    lang: LanguageIdentifier
+) -> Result<(Vec<SearchResultEntity>, Option<String>), AppError> {
+    let Some(tsquery) = utils::search::to_tsquery(&search) else {
+        return Ok((vec![], None));
+    };
+
+    let mut items = db::search::repo::search(
+        db,
+        tsquery,
+        translation::service::locale(&lang),
+        limit + 1,
+        utils::cursor::decode(cursor)
+    ).await?;
+
+    let next_cursor = if items.len() == (limit + 1) as usize {
+        items.remove(limit as usize);
+        let last = items.last().unwrap();
+        utils::cursor::encode(
+            SearchCursor {
+                rank: last.rank,
+                kind: last.kind.clone(),
+                id: last.id
+            }
+        )
+    } else { None };
+
+    Ok(
+        (items, next_cursor)
+    )
+}
@@ -0,0 +1,123 @@
+use crate::error::AppError;
+use crate::extractor::accept_language::AcceptLanguage;
+use crate::module::common::base::{BaseResponse, ClientSnipIdParam, ShareTokenParam};
+use crate::module::user::share::dto::{SharedSnipResponse, SnipShareResponse};
+use crate::module::user::share::mapper;
+use crate::module::user::share::service;
+use crate::state::AppState;
+use crate::utils::extractors::ValidatedPath;
+use crate::utils::jwt::Claims;
+use axum::extract::State;
+use axum::Extension;
+
+#[utoipa::path(
+    post,
+    path = "/v1/user/lesson/snip/{client_snip_id}/share",
+    security(("bearerAuth" = [])),
+    params(ClientSnipIdParam),
+    responses((status = 200, body = SnipShareResponse)),
+    tag = "Share"
+)]
+pub async fn create_share(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(client_snip_id): ValidatedPath<String>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<SnipShareResponse>, AppError> {
+    let share = service::create(
+        &state.db,
+        client_snip_id,
+        claims.sub,
+        lang
+    ).await?;
+
+    Ok(
+        BaseResponse::success(
+            mapper::to_response(share)
+        )
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/lesson/snip/{client_snip_id}/share",
+    security(("bearerAuth" = [])),
+    params(ClientSnipIdParam),
+    responses((status = 200, body = Vec<SnipShareResponse>)),
+    tag = "Share"
+)]
+pub async fn list_shares(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(client_snip_id): ValidatedPath<String>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<Vec<SnipShareResponse>>, AppError> {
+    let shares = service::list(
+        &state.db,
+        client_snip_id,
+        claims.sub,
+        lang
+    ).await?;
+
+    Ok(
+        BaseResponse::success(
+            shares.into_iter()
+                .map(mapper::to_response)
+                .collect()
+        )
+    )
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/user/lesson/snip/share/{token}",
+    security(("bearerAuth" = [])),
+    params(ShareTokenParam),
+    responses((status = 200, body = SnipShareResponse)),
+    tag = "Share"
+)]
+pub async fn revoke_share(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(ShareTokenParam { token }): ValidatedPath<ShareTokenParam>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<SnipShareResponse>, AppError> {
+    let share = service::revoke(
+        &state.db,
+        token,
+        claims.sub,
+        lang
+    ).await?;
+
+    Ok(
+        BaseResponse::success(
+            mapper::to_response(share)
+        )
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/share/{token}",
+    params(ShareTokenParam),
+    responses((status = 200, body = SharedSnipResponse)),
+    tag = "Share"
+)]
+pub async fn view_share(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(ShareTokenParam { token }): ValidatedPath<ShareTokenParam>
+) -> Result<BaseResponse<SharedSnipResponse>, AppError> {
+    let (shared, audio_url, audio_expires_at) = service::view(
+        &state.db,
+        &state.s3_client,
+        token,
+        lang
+    ).await?;
+
+    Ok(
+        BaseResponse::success(
+            mapper::to_shared_response(shared, audio_url, audio_expires_at)
+        )
+    )
+}
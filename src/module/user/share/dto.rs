@@ -0,0 +1,50 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct SnipShareResponse {
+    pub token: String,
+    /// Landing page for the share link
+    pub url: String,
+    pub view_count: i64,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub last_viewed_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub revoked_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime
+}
+
+/// Open Graph tags for link previews
+#[derive(Serialize, Debug, ToSchema)]
+pub struct OpenGraphResponse {
+    pub title: String,
+    pub description: String,
+    pub image: Option<String>,
+    pub url: String,
+    pub audio: String,
+    pub r#type: String
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct SharedSnipResponse {
+    pub token: String,
+    pub lesson_title: String,
+    pub lesson_description: Option<String>,
+    pub author_name: String,
+    pub author_avatar_path: Option<String>,
+    pub topic_title: Option<String>,
+    pub cover_image_path: Option<String>,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub note_text: Option<String>,
+    /// Presigned MP3 of the snip range, rendered like a watermarked clip export
+    pub audio_url: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub audio_expires_at: OffsetDateTime,
+    pub view_count: i64,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    pub open_graph: OpenGraphResponse
+}
@@ -0,0 +1,63 @@
+use crate::db::snip_share::entity::{SharedSnipEntity, SnipShareEntity};
+use crate::module::user::share::dto::{OpenGraphResponse, SharedSnipResponse, SnipShareResponse};
+use crate::utils::CONFIG;
+use time::OffsetDateTime;
+
+fn share_url(token: &str) -> String {
+    format!("{}/share/{}", CONFIG.client_origin, token)
+}
+
+fn format_ms(ms: i64) -> String {
+    let seconds = ms / 1000;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+pub fn to_response(entity: SnipShareEntity) -> SnipShareResponse {
+    SnipShareResponse {
+        url: share_url(&entity.token),
+        token: entity.token,
+        view_count: entity.view_count,
+        last_viewed_at: entity.last_viewed_at,
+        revoked_at: entity.revoked_at,
+        created_at: entity.created_at
+    }
+}
+
+pub fn to_shared_response(
+    entity: SharedSnipEntity,
+    audio_url: String,
+    audio_expires_at: OffsetDateTime
+) -> SharedSnipResponse {
+    let description = entity.note_text.clone().unwrap_or_else(|| format!(
+        "{} · {}–{}",
+        entity.author_name,
+        format_ms(entity.start_ms),
+        format_ms(entity.end_ms)
+    ));
+
+    SharedSnipResponse {
+        open_graph: OpenGraphResponse {
+            title: entity.lesson_title.clone(),
+            description,
+            image: entity.cover_image_path.as_ref()
+                .map(|path| format!("{}/v1/file/{}", CONFIG.public_base_url, path)),
+            url: share_url(&entity.token),
+            audio: audio_url.clone(),
+            r#type: "music.song".to_string()
+        },
+        token: entity.token,
+        lesson_title: entity.lesson_title,
+        lesson_description: entity.lesson_description,
+        author_name: entity.author_name,
+        author_avatar_path: entity.author_avatar_path,
+        topic_title: entity.topic_title,
+        cover_image_path: entity.cover_image_path,
+        start_ms: entity.start_ms,
+        end_ms: entity.end_ms,
+        note_text: entity.note_text,
+        audio_url,
+        audio_expires_at,
+        view_count: entity.view_count,
+        created_at: entity.created_at
+    }
+}
@@ -0,0 +1,21 @@
+use axum::{Router, middleware};
+use axum::routing::{delete, get, post};
+use crate::middleware::auth::user_auth_middleware;
+use crate::module::user::share::controller::{create_share, list_shares, revoke_share, view_share};
+use crate::state::AppState;
+
+pub fn routes() -> Router<AppState> {
+    // Public routes (no auth)
+    let public = Router::new()
+        .route("/share/{token}", get(view_share));
+
+    let authenticated = Router::new()
+        .route("/lesson/snip/{client_snip_id}/share", post(create_share))
+        .route("/lesson/snip/{client_snip_id}/share", get(list_shares))
+        .route("/lesson/snip/share/{token}", delete(revoke_share))
+        .layer(middleware::from_fn(user_auth_middleware));
+
+    Router::new()
+        .merge(public)
+        .merge(authenticated)
+}
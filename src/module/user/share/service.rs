@@ -0,0 +1,84 @@
+use crate::db;
+use crate::db::snip_share::entity::{SharedSnipEntity, SnipShareEntity};
+use crate::error::AppError;
+use crate::module::common::clip;
+use aws_sdk_s3 as s3;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use fluent_templates::LanguageIdentifier;
+use sqlx::PgPool;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+async fn get_own_snip_id(
+    db: &PgPool,
+    client_snip_id: String,
+    user_id: i64,
+    lang: LanguageIdentifier
+) -> Result<i64, AppError> {
+    match db::snip::repo::get_by_client_snip_id(db, client_snip_id).await? {
+        Some(entity) if entity.snip.user_id == user_id => Ok(entity.snip.id),
+        _ => Err(AppError::NotFound(lang))
+    }
+}
+
+pub async fn create(
+    db: &PgPool,
+    client_snip_id: String,
+    user_id: i64,
+    lang: LanguageIdentifier
+) -> Result<SnipShareEntity, AppError> {
+    let snip_id = get_own_snip_id(db, client_snip_id, user_id, lang).await?;
+    // 122 random bits, unguessable without being sequential
+    let token = URL_SAFE_NO_PAD.encode(Uuid::new_v4().as_bytes());
+
+    Ok(db::snip_share::repo::insert(db, snip_id, user_id, token).await?)
+}
+
+pub async fn list(
+    db: &PgPool,
+    client_snip_id: String,
+    user_id: i64,
+    lang: LanguageIdentifier
+) -> Result<Vec<SnipShareEntity>, AppError> {
+    let snip_id = get_own_snip_id(db, client_snip_id, user_id, lang).await?;
+    Ok(db::snip_share::repo::list_by_snip(db, snip_id).await?)
+}
+
+pub async fn revoke(
+    db: &PgPool,
+    token: String,
+    user_id: i64,
+    lang: LanguageIdentifier
+) -> Result<SnipShareEntity, AppError> {
+    db::snip_share::repo::revoke(db, token, user_id)
+        .await?
+        .ok_or(AppError::NotFound(lang))
+}
+
+/// Resolves a share token for the public landing page, counting the view.
+/// Returns the shared snip with a presigned URL to its rendered clip; the
+/// lesson audio itself is never exposed.
+pub async fn view(
+    db: &PgPool,
+    s3_client: &s3::Client,
+    token: String,
+    lang: LanguageIdentifier
+) -> Result<(SharedSnipEntity, String, OffsetDateTime), AppError> {
+    let entity = db::snip_share::repo::view(db, token)
+        .await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
+    let snip = db::snip::repo::get_by_id(db, entity.snip_id)
+        .await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
+
+    let (audio_url, audio_expires_at) = clip::service::export(
+        db,
+        s3_client,
+        &snip,
+        true,
+        Some(snip.snip.user_id),
+        lang
+    ).await?;
+
+    Ok((entity, audio_url, audio_expires_at))
+}
@@ -2,7 +2,8 @@ use crate::error::AppError;
 use crate::extractor::accept_language::AcceptLanguage;
 use crate::module::common::base::{BaseResponse, ClientSnipIdParam, DeletedParams, DeletedResponse, LessonIdParam};
 use crate::module::common::paging::CursorPagingResponse;
-use crate::module::user::snip::dto::{SnipCURequest, SnipCountResponse, SnipPaginationParams, SnipResponse};
+use crate::module::common::{chapter, translation};
+use crate::module::user::snip::dto::{SnipCURequest, SnipCountResponse, SnipExportRequest, SnipExportResponse, SnipPaginationParams, SnipResponse, SnipTrashParams, TrashedSnipResponse};
 use crate::module::user::snip::mapper;
 use crate::module::user::snip::service;
 use crate::state::AppState;
@@ -34,7 +35,7 @@ pub async fn create_snip(
         return Err(AppError::BadRequest{lang: lang.clone(), message: t(&lang, strings::BAD_REQUEST)});
     }
 
-    let snip = service::create(
+    let mut snip = service::create(
         &state.db,
         body.client_snip_id,
         lesson_id,
@@ -43,8 +44,10 @@ pub async fn create_snip(
         body.end_ms,
         body.note_text,
         body.created_at,
-        lang
+        lang.clone()
     ).await?;
+    translation::service::localize_snips(&state.db, &mut [&mut snip], &lang).await?;
+    chapter::service::attach_snips(&state.db, &mut [&mut snip]).await?;
 
     let user_snip_count = service::count(
         &state.db,
@@ -76,15 +79,17 @@ pub async fn update_snip(
     ValidatedJson(body): ValidatedJson<SnipCURequest>,
 ) -> Result<BaseResponse<SnipResponse>, AppError> {
 
-    let snip = service::update(
+    let mut snip = service::update(
         &state.db,
         client_snip_id,
         claims.sub,
         body.start_ms,
         body.end_ms,
         body.note_text,
-        lang
+        lang.clone()
     ).await?;
+    translation::service::localize_snips(&state.db, &mut [&mut snip], &lang).await?;
+    chapter::service::attach_snips(&state.db, &mut [&mut snip]).await?;
 
     Ok(
         BaseResponse::success(
@@ -146,11 +151,12 @@ pub async fn delete_snip(
 )]
 pub async fn page_snip(
     State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
     ValidatedQuery(params): ValidatedQuery<SnipPaginationParams>,
     Extension(claims): Extension<Claims>
 ) -> Result<BaseResponse<CursorPagingResponse<SnipResponse>>, AppError> {
 
-    let topics = service::page(
+    let mut topics = service::page(
         &state.db,
         params.limit,
         params.cursor,
@@ -160,6 +166,15 @@ pub async fn page_snip(
         params.sort,
         params.order
     ).await?;
+    translation::service::localize_snips(
+        &state.db,
+        &mut topics.0.iter_mut().collect::<Vec<_>>(),
+        &lang
+    ).await?;
+    chapter::service::attach_snips(
+        &state.db,
+        &mut topics.0.iter_mut().collect::<Vec<_>>()
+    ).await?;
 
     Ok(
         BaseResponse::success(
@@ -195,6 +210,86 @@ pub async fn deleted_snips(
     )
 }
 
+#[utoipa::path(
+    get,
+    path = "/v1/user/lesson/snip/trash",
+    security(("bearerAuth" = [])),
+    params(SnipTrashParams),
+    responses((status = 200, body = CursorPagingResponse<TrashedSnipResponse>)),
+    tag = "Snip"
+)]
+pub async fn page_trashed_snip(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedQuery(params): ValidatedQuery<SnipTrashParams>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<CursorPagingResponse<TrashedSnipResponse>>, AppError> {
+
+    let mut snips = service::trashed(
+        &state.db,
+        params.limit,
+        params.cursor,
+        claims.sub
+    ).await?;
+    translation::service::localize_snips(
+        &state.db,
+        &mut snips.0.iter_mut().map(|s| &mut s.snip).collect::<Vec<_>>(),
+        &lang
+    ).await?;
+    chapter::service::attach_snips(
+        &state.db,
+        &mut snips.0.iter_mut().map(|s| &mut s.snip).collect::<Vec<_>>()
+    ).await?;
+
+    Ok(
+        BaseResponse::success(
+            CursorPagingResponse::new(
+                snips.0.into_iter()
+                    .map(mapper::to_trashed_response)
+                    .collect(),
+                snips.1
+            )
+        )
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/user/lesson/snip/{client_snip_id}/restore",
+    security(("bearerAuth" = [])),
+    params(ClientSnipIdParam),
+    responses((status = 200, body = SnipResponse)),
+    tag = "Snip"
+)]
+pub async fn restore_snip(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(client_snip_id): ValidatedPath<String>,
+    Extension(claims): Extension<Claims>
+) -> Result<BaseResponse<SnipResponse>, AppError> {
+
+    let mut snip = service::restore(
+        &state.db,
+        client_snip_id,
+        claims.sub,
+        lang.clone()
+    ).await?;
+    translation::service::localize_snips(&state.db, &mut [&mut snip], &lang).await?;
+    chapter::service::attach_snips(&state.db, &mut [&mut snip]).await?;
+
+    let user_snip_count = service::count(
+        &state.db,
+        claims.sub,
+        snip.snip.lesson_id
+    ).await.ok();
+
+    Ok(
+        BaseResponse::success(
+            mapper::to_response(snip, user_snip_count)
+        )
+    )
+}
+
 #[utoipa::path(
     get,
     path = "/v1/user/lesson/{lesson_id}/snip/count",
@@ -223,4 +318,39 @@ pub async fn count_snip(
             }
         )
     )
-}
\ No newline at end of file
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/user/lesson/snip/{client_snip_id}/export",
+    security(("bearerAuth" = [])),
+    params(ClientSnipIdParam),
+    request_body = SnipExportRequest,
+    responses((status = 200, body = SnipExportResponse)),
+    tag = "Snip"
+)]
+pub async fn export_snip(
+    State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
+    ValidatedPath(client_snip_id): ValidatedPath<String>,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(body): ValidatedJson<SnipExportRequest>
+) -> Result<BaseResponse<SnipExportResponse>, AppError> {
+    let (url, expires_at) = service::export(
+        &state.db,
+        &state.s3_client,
+        client_snip_id,
+        claims.sub,
+        body.watermark,
+        lang
+    ).await?;
+
+    Ok(
+        BaseResponse::success(
+            SnipExportResponse {
+                url,
+                expires_at
+            }
+        )
+    )
+}
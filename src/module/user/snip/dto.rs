@@ -30,11 +30,27 @@ pub struct SnipResponse {
     pub start_ms: i64,
     pub end_ms: i64,
     pub note_text: Option<String>,
+    /// Transcript segments overlapping `start_ms..end_ms`
+    pub transcript_text: Option<String>,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
     pub user_snip_count: Option<i64>
 }
 
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+pub struct SnipExportRequest {
+    /// Prepend the spoken intro and embed the cover art
+    #[serde(default)]
+    pub watermark: bool
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct SnipExportResponse {
+    pub url: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub expires_at: OffsetDateTime
+}
+
 #[derive(Serialize, Debug, ToSchema)]
 pub struct SnipCountResponse {
     pub lesson_id: i64,
@@ -63,6 +79,32 @@ pub struct SnipCursor{
     pub created_at: OffsetDateTime,
 }
 
+#[derive(Serialize, Debug, ToSchema)]
+pub struct TrashedSnipResponse {
+    #[serde(flatten)]
+    pub snip: SnipResponse,
+    #[serde(with = "time::serde::rfc3339")]
+    pub deleted_at: OffsetDateTime,
+    /// When the snip is permanently deleted
+    #[serde(with = "time::serde::rfc3339")]
+    pub purge_at: OffsetDateTime
+}
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SnipTrashParams {
+    #[param(example = json!(20))]
+    pub limit: u32,
+    pub cursor: Option<String>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TrashedSnipCursor{
+    pub id: i64,
+    #[serde(with = "time::serde::rfc3339")]
+    pub deleted_at: OffsetDateTime,
+}
+
 #[derive(Deserialize, PartialEq, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QuerySort {
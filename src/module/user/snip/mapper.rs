@@ -1,11 +1,19 @@
-use crate::db::snip::entity::SnipEntityWithLesson;
+use crate::db::snip::entity::{SnipEntityWithLesson, TrashedSnipEntity};
 use crate::module::common::author::dto::CommonAuthorResponse;
 use crate::module::common::base::FileResponse;
+use crate::module::common::chapter::mapper::to_responses as chapters_to_responses;
 use crate::module::common::lesson::dto::CommonLessonResponse;
 use crate::module::common::topic::dto::CommonTopicResponse;
-use crate::module::user::snip::dto::SnipResponse;
+use crate::module::common::trash;
+use crate::module::user::snip::dto::{SnipResponse, TrashedSnipResponse};
 
 pub fn to_response(entity: SnipEntityWithLesson, user_snip_count: Option<i64>) -> SnipResponse {
+    // only the chapters overlapping the snip range
+    let chapters = chapters_to_responses(&entity.chapters, entity.lesson_duration)
+        .into_iter()
+        .filter(|chapter| chapter.start_ms < entity.snip.end_ms && chapter.end_ms > entity.snip.start_ms)
+        .collect();
+
     SnipResponse {
         id: entity.snip.id,
         client_snip_id: entity.snip.client_snip_id,
@@ -31,7 +39,9 @@ pub fn to_response(entity: SnipEntityWithLesson, user_snip_count: Option<i64>) -
                         cover_image_path: entity.topic_cover_image_path,
                         created_at: entity.topic_created_at.unwrap(),
                         lesson_count: entity.topic_lesson_count.unwrap(),
-                        total_duration: entity.topic_total_duration.unwrap()
+                        total_duration: entity.topic_total_duration.unwrap(),
+                        rating_average: entity.topic_rating_average.unwrap_or_default(),
+                        rating_count: entity.topic_rating_count.unwrap_or_default()
                     }
                 )
             } else { None },
@@ -40,14 +50,27 @@ pub fn to_response(entity: SnipEntityWithLesson, user_snip_count: Option<i64>) -
                 size: entity.lesson_file_size,
                 duration: entity.lesson_duration
             },
+            chapters,
             listen_count: entity.lesson_listen_count,
             snip_count: entity.lesson_snip_count,
+            rating_average: entity.lesson_rating_average,
+            rating_count: entity.lesson_rating_count,
+            position: entity.lesson_position,
             created_at: entity.lesson_created_at
         },
         start_ms: entity.snip.start_ms,
         end_ms: entity.snip.end_ms,
         note_text: entity.snip.note_text,
+        transcript_text: entity.transcript_text,
         created_at: entity.snip.created_at,
         user_snip_count
     }
+}
+
+pub fn to_trashed_response(entity: TrashedSnipEntity) -> TrashedSnipResponse {
+    TrashedSnipResponse {
+        snip: to_response(entity.snip, None),
+        deleted_at: entity.deleted_at,
+        purge_at: trash::service::purge_at(entity.deleted_at)
+    }
 }
\ No newline at end of file
@@ -1,7 +1,7 @@
 use axum::{Router, middleware};
 use axum::routing::{delete, get, post, put};
 use crate::middleware::auth::user_auth_middleware;
-use crate::module::user::snip::controller::{count_snip, create_snip, delete_snip, deleted_snips, page_snip, update_snip};
+use crate::module::user::snip::controller::{count_snip, create_snip, delete_snip, deleted_snips, export_snip, page_snip, page_trashed_snip, restore_snip, update_snip};
 use crate::state::AppState;
 
 pub fn routes() -> Router<AppState> {
@@ -9,8 +9,11 @@ pub fn routes() -> Router<AppState> {
         .route("/lesson/{lesson_id}/snip", post(create_snip))
         .route("/lesson/snip/{client_snip_id}", put(update_snip))
         .route("/lesson/snip/{client_snip_id}", delete(delete_snip))
+        .route("/lesson/snip/{client_snip_id}/export", post(export_snip))
         .route("/lesson/snip", get(page_snip))
         .route("/lesson/snip/deleted", get(deleted_snips))
+        .route("/lesson/snip/trash", get(page_trashed_snip))
+        .route("/lesson/snip/{client_snip_id}/restore", post(restore_snip))
         .route("/lesson/{lesson_id}/snip/count", get(count_snip))
         .layer(middleware::from_fn(user_auth_middleware))
 }
\ No newline at end of file
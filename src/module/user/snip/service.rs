@@ -1,10 +1,12 @@
 use crate::{db, utils};
-use crate::db::snip::entity::{SnipEntity, SnipEntityWithLesson, SnipInput};
-use crate::module::user::snip::dto::{QuerySort, SnipCursor};
+use crate::db::snip::entity::{SnipEntity, SnipEntityWithLesson, SnipInput, TrashedSnipEntity};
+use crate::module::user::snip::dto::{QuerySort, SnipCursor, TrashedSnipCursor};
+use crate::module::common::{achievement, clip};
 use crate::module::common::paging::QueryOrder;
 use anyhow::Result;
+use aws_sdk_s3 as s3;
 use fluent_templates::LanguageIdentifier;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 use time::OffsetDateTime;
 use crate::error::AppError;
 use crate::error::snip::SnipError;
@@ -45,6 +47,7 @@ pub async fn create(
             &mut tx,
             lesson_id
         ).await?;
+        db::stat::repo::refresh(&mut tx, user_id, Some((created_at, created_at))).await?;
         if let Some(topic_id) = lesson.topic_id {
             db::topic::repo::increase_snip_count(
                 &mut tx,
@@ -53,6 +56,9 @@ pub async fn create(
         }
     }
     tx.commit().await?;
+    if insert_result.1 {
+        achievement::service::evaluate(db, user_id).await?;
+    }
     
     let entity = db::snip::repo::get_by_id(
         db,
@@ -112,13 +118,77 @@ pub async fn delete(
     let mut tx = db.begin().await?;
     let entity = db::snip::repo::delete(&mut tx, client_snip_id)
         .await?;
-    if let Some(entity) = &entity && entity.user_id != user_id {
-        return Err(SnipError::SnipNotOwnedDelete(lang).into());
+    if let Some(entity) = &entity {
+        if entity.user_id != user_id {
+            return Err(SnipError::SnipNotOwnedDelete(lang).into());
+        }
+        recount(&mut tx, entity).await?;
     }
     tx.commit().await?;
     Ok(entity)
 }
 
+async fn recount(connection: &mut PgConnection, entity: &SnipEntity) -> Result<()> {
+    db::lesson::repo::recount_snip_count(connection, entity.lesson_id).await?;
+    if let Some(topic_id) = entity.topic_id {
+        db::topic::repo::recount_stats(connection, topic_id).await?;
+    }
+    Ok(())
+}
+
+pub async fn restore(
+    db: &PgPool,
+    client_snip_id: String,
+    user_id: i64,
+    lang: LanguageIdentifier
+) -> Result<SnipEntityWithLesson> {
+    let mut tx = db.begin().await?;
+    let restored = db::snip::repo::restore(&mut tx, client_snip_id)
+        .await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
+    if restored.user_id != user_id {
+        return Err(SnipError::SnipNotOwnedRestore(lang).into());
+    }
+    recount(&mut tx, &restored).await?;
+    tx.commit().await?;
+
+    let entity = db::snip::repo::get_by_id(
+        db,
+        restored.id
+    ).await?.ok_or(AppError::NotFound(lang))?;
+
+    Ok(entity)
+}
+
+pub async fn trashed(
+    db: &PgPool,
+    limit: u32,
+    cursor: Option<String>,
+    user_id: i64
+) -> Result<(Vec<TrashedSnipEntity>, Option<String>)> {
+    let mut items = db::snip::repo::trashed(
+        db,
+        limit + 1,
+        utils::cursor::decode(cursor),
+        user_id
+    ).await?;
+
+    let next_cursor = if items.len() == (limit + 1) as usize {
+        items.remove(limit as usize);
+        let last = items.last().unwrap();
+        utils::cursor::encode(
+            TrashedSnipCursor {
+                id: last.snip.snip.id,
+                deleted_at: last.deleted_at,
+            }
+        )
+    } else { None };
+
+    Ok(
+        (items, next_cursor)
+    )
+}
+
 pub async fn deleted(
     db: &PgPool,
     since: OffsetDateTime
@@ -174,3 +244,22 @@ pub async fn count(
     Ok(count)
 }
 
+
+/// Exports a snip as a standalone MP3, see `clip::service::export`.
+pub async fn export(
+    db: &PgPool,
+    s3_client: &s3::Client,
+    client_snip_id: String,
+    user_id: i64,
+    watermark: bool,
+    lang: LanguageIdentifier
+) -> std::result::Result<(String, OffsetDateTime), AppError> {
+    let entity = db::snip::repo::get_by_client_snip_id(db, client_snip_id)
+        .await?
+        .ok_or(AppError::NotFound(lang.clone()))?;
+    if entity.snip.user_id != user_id {
+        return Err(SnipError::SnipNotOwnedExport(lang).into());
+    }
+
+    clip::service::export(db, s3_client, &entity, watermark, Some(user_id), lang).await
+}
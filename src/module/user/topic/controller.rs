@@ -1,9 +1,11 @@
 use axum::Extension;
 use axum::extract::State;
 use crate::error::AppError;
-use crate::module::common::base::{BaseResponse, DeletedParams, DeletedResponse};
+use crate::extractor::accept_language::AcceptLanguage;
+use crate::module::common::base::{BaseResponse, DeletedParams, DeletedResponse, PublishedResponse};
 use crate::module::common::paging::{CursorPagingResponse};
 use crate::module::common::topic::service;
+use crate::module::common::translation;
 use crate::module::user::topic::dto::{TopicPaginationParams, TopicResponse};
 use crate::module::user::topic::mapper;
 use crate::state::AppState;
@@ -20,10 +22,11 @@ use crate::utils::jwt::Claims;
 )]
 pub async fn page_topic(
     State(state): State<AppState>,
+    AcceptLanguage(lang): AcceptLanguage,
     ValidatedQuery(params): ValidatedQuery<TopicPaginationParams>,
     Extension(claims): Extension<Claims>
 ) -> Result<BaseResponse<CursorPagingResponse<TopicResponse>>, AppError> {
-    let topics = service::page_cursor(
+    let mut topics = service::page_cursor(
         &state.db,
         params.limit,
         params.cursor,
@@ -34,7 +37,8 @@ pub async fn page_topic(
         params.sort,
         params.order
     ).await?;
-    
+    translation::service::localize_topics(&state.db, &mut topics.0, &lang).await?;
+
     Ok(
         BaseResponse::success(
             CursorPagingResponse::new(
@@ -65,4 +69,26 @@ pub async fn deleted_topics(
             }).collect()
         )
     )
-}
\ No newline at end of file
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/user/topic/published",
+    security(("bearerAuth" = [])),
+    params(DeletedParams),
+    responses((status = 200, body = Vec<PublishedResponse>)),
+    tag = "Topic"
+)]
+pub async fn published_topics(
+    State(state): State<AppState>,
+    ValidatedQuery(params): ValidatedQuery<DeletedParams>
+) -> Result<BaseResponse<Vec<PublishedResponse>>, AppError> {
+    Ok(
+        BaseResponse::success(
+            service::published(&state.db, params.since).await?
+                .iter().map(|pair| {
+                PublishedResponse{ id: pair.0, published_at: pair.1 }
+            }).collect()
+        )
+    )
+}
@@ -31,6 +31,7 @@ pub struct TopicPaginationParams {
 pub struct TopicCursor {
     pub id: i64,
     pub snip_count: i64,
+    pub rating_average: f64,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
 }
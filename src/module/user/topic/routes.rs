@@ -1,5 +1,5 @@
 use crate::middleware::auth::user_auth_middleware;
-use crate::module::user::topic::controller::{deleted_topics, page_topic};
+use crate::module::user::topic::controller::{deleted_topics, page_topic, published_topics};
 use crate::state::AppState;
 use axum::{middleware, routing::get, Router};
 
@@ -7,5 +7,6 @@ pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/topic", get(page_topic))
         .route("/topic/deleted", get(deleted_topics))
+        .route("/topic/published", get(published_topics))
         .layer(middleware::from_fn(user_auth_middleware))
 }
\ No newline at end of file
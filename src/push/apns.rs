@@ -0,0 +1,96 @@
+use crate::push::{PushError, PushMessage, PushSender};
+use anyhow::Context;
+use async_trait::async_trait;
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use reqwest::StatusCode;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::sync::Mutex;
+use time::{Duration, OffsetDateTime};
+
+/// Apple accepts a provider token for an hour; it is renewed a bit earlier.
+const TOKEN_TTL: Duration = Duration::minutes(50);
+
+#[derive(Serialize)]
+struct ProviderClaims<'a> {
+    iss: &'a str,
+    iat: i64
+}
+
+/// Apple Push Notification service over HTTP/2 with a `.p8` signing key.
+pub struct ApnsSender {
+    client: reqwest::Client,
+    key: EncodingKey,
+    key_id: String,
+    team_id: String,
+    topic: String,
+    base_url: &'static str,
+    token: Mutex<Option<(String, OffsetDateTime)>>
+}
+
+impl ApnsSender {
+    pub fn new(key_path: &str, key_id: &str, team_id: &str, topic: &str, sandbox: bool) -> anyhow::Result<Self> {
+        let pem = std::fs::read(key_path).context("APNs key")?;
+        Ok(Self {
+            client: reqwest::Client::builder().http2_prior_knowledge().build()?,
+            key: EncodingKey::from_ec_pem(&pem).context("APNs key")?,
+            key_id: key_id.to_string(),
+            team_id: team_id.to_string(),
+            topic: topic.to_string(),
+            base_url: if sandbox { "https://api.sandbox.push.apple.com" } else { "https://api.push.apple.com" },
+            token: Mutex::new(None)
+        })
+    }
+
+    fn provider_token(&self) -> anyhow::Result<String> {
+        let now = OffsetDateTime::now_utc();
+        let mut token = self.token.lock().unwrap();
+        if let Some((value, issued_at)) = token.as_ref() && now - *issued_at < TOKEN_TTL {
+            return Ok(value.clone());
+        }
+
+        let mut header = Header::new(Algorithm::ES256);
+        header.kid = Some(self.key_id.clone());
+        let value = encode(&header, &ProviderClaims { iss: &self.team_id, iat: now.unix_timestamp() }, &self.key)?;
+        *token = Some((value.clone(), now));
+        Ok(value)
+    }
+}
+
+#[async_trait]
+impl PushSender for ApnsSender {
+    async fn send(&self, token: &str, message: &PushMessage) -> Result<(), PushError> {
+        let mut payload = json!({
+            "aps": {
+                "alert": { "title": message.title, "body": message.body },
+                "sound": "default"
+            }
+        });
+        for (key, value) in &message.data {
+            payload[*key] = Value::String(value.clone());
+        }
+
+        let response = self.client
+            .post(format!("{}/3/device/{}", self.base_url, token))
+            .bearer_auth(self.provider_token()?)
+            .header("apns-topic", &self.topic)
+            .header("apns-push-type", "alert")
+            .json(&payload)
+            .send()
+            .await
+            .context("APNs request")?;
+
+        match response.status() {
+            status if status.is_success() => Ok(()),
+            // Unregistered
+            StatusCode::GONE => Err(PushError::InvalidToken),
+            status => {
+                let body = response.text().await.unwrap_or_default();
+                if body.contains("BadDeviceToken") || body.contains("DeviceTokenNotForTopic") {
+                    return Err(PushError::InvalidToken);
+                }
+                Err(anyhow::anyhow!("APNs responded {status}: {body}").into())
+            }
+        }
+    }
+}
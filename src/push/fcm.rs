@@ -0,0 +1,69 @@
+use crate::push::{PushError, PushMessage, PushSender};
+use anyhow::Context;
+use async_trait::async_trait;
+use google_cloud_auth::credentials::{AccessTokenCredentials, Builder};
+use reqwest::StatusCode;
+use serde_json::{json, Map, Value};
+
+const SCOPE: &str = "https://www.googleapis.com/auth/firebase.messaging";
+
+/// Firebase Cloud Messaging HTTP v1. Credentials come from the application
+/// default credentials, e.g. `GOOGLE_APPLICATION_CREDENTIALS`.
+pub struct FcmSender {
+    client: reqwest::Client,
+    credentials: AccessTokenCredentials,
+    url: String
+}
+
+impl FcmSender {
+    pub fn new(project_id: &str) -> anyhow::Result<Self> {
+        let credentials = Builder::default()
+            .with_scopes([SCOPE])
+            .build_access_token_credentials()
+            .context("FCM credentials")?;
+        Ok(Self {
+            client: reqwest::Client::new(),
+            credentials,
+            url: format!("https://fcm.googleapis.com/v1/projects/{project_id}/messages:send")
+        })
+    }
+}
+
+#[async_trait]
+impl PushSender for FcmSender {
+    async fn send(&self, token: &str, message: &PushMessage) -> Result<(), PushError> {
+        let access_token = self.credentials.access_token().await
+            .context("FCM access token")?;
+        let data: Map<String, Value> = message.data.iter()
+            .map(|(key, value)| (key.to_string(), Value::String(value.clone())))
+            .collect();
+
+        let response = self.client
+            .post(&self.url)
+            .bearer_auth(access_token.token)
+            .json(&json!({
+                "message": {
+                    "token": token,
+                    "notification": { "title": message.title, "body": message.body },
+                    "data": data
+                }
+            }))
+            .send()
+            .await
+            .context("FCM request")?;
+
+        match response.status() {
+            status if status.is_success() => Ok(()),
+            // UNREGISTERED
+            StatusCode::NOT_FOUND => Err(PushError::InvalidToken),
+            status => {
+                let body = response.text().await.unwrap_or_default();
+                // INVALID_ARGUMENT also covers malformed tokens
+                if status == StatusCode::BAD_REQUEST && body.contains("registration token") {
+                    return Err(PushError::InvalidToken);
+                }
+                Err(anyhow::anyhow!("FCM responded {status}: {body}").into())
+            }
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::push::{PushError, PushMessage, PushSender};
+use async_trait::async_trait;
+
+/// Logs messages instead of delivering them. Only used with `PUSH_DEV_SINK`.
+pub struct LogSender {
+    name: &'static str
+}
+
+impl LogSender {
+    pub fn new(name: &'static str) -> Self {
+        Self { name }
+    }
+}
+
+#[async_trait]
+impl PushSender for LogSender {
+    async fn send(&self, _token: &str, message: &PushMessage) -> Result<(), PushError> {
+        println!("📨 {} push (not delivered): {}", self.name, message.title);
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::push::{PushError, PushMessage, PushSender};
+use async_trait::async_trait;
+use std::sync::Mutex;
+
+/// Keeps the most recent messages only.
+const CAPACITY: usize = 100;
+
+/// Collects messages instead of delivering them, for tests and local tools
+/// that build a [`Push`](crate::push::Push) with `Push::new`. Not picked by
+/// the configuration; see `PUSH_DEV_SINK` for that.
+#[allow(unused)]
+pub struct MemorySender {
+    sent: Mutex<Vec<(String, PushMessage)>>
+}
+
+#[allow(unused)]
+impl MemorySender {
+    pub fn new() -> Self {
+        Self { sent: Mutex::new(Vec::new()) }
+    }
+
+    /// Token and message of everything sent so far, oldest first.
+    pub fn sent(&self) -> Vec<(String, PushMessage)> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl PushSender for MemorySender {
+    async fn send(&self, token: &str, message: &PushMessage) -> Result<(), PushError> {
+        let mut sent = self.sent.lock().unwrap();
+        if sent.len() == CAPACITY {
+            sent.remove(0);
+        }
+        sent.push((token.to_string(), message.clone()));
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+pub mod apns;
+pub mod fcm;
+pub mod log;
+pub mod memory;
+pub mod telegram;
+
+use crate::module::common::enums::DevicePlatform;
+use crate::push::apns::ApnsSender;
+use crate::push::fcm::FcmSender;
+use crate::push::log::LogSender;
+use crate::push::telegram::TelegramSender;
+use crate::utils::CONFIG;
+use async_trait::async_trait;
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Clone)]
+pub struct PushMessage {
+    pub title: String,
+    pub body: String,
+    /// Passed to the app as is, e.g. the notification and lesson ids
+    pub data: Vec<(&'static str, String)>
+}
+
+#[derive(Debug, Error)]
+pub enum PushError {
+    /// The token is unregistered or belongs to another app; it should be dropped
+    #[error("device token is no longer valid")]
+    InvalidToken,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error)
+}
+
+#[async_trait]
+pub trait PushSender: Send + Sync {
+    async fn send(&self, token: &str, message: &PushMessage) -> Result<(), PushError>;
+}
+
+/// Senders by provider. Every push provider must be configured unless
+/// `PUSH_DEV_SINK` is set, which logs the pushes of an unconfigured one
+/// instead. Telegram uses the bot and can be pointed at a mock API. Tests
+/// pass their own senders, e.g. `MemorySender`, to [`Push::new`].
+#[derive(Clone)]
+pub struct Push {
+    fcm: Arc<dyn PushSender>,
+    apns: Arc<dyn PushSender>,
+    telegram: Arc<dyn PushSender>
+}
+
+impl Push {
+    pub fn new(fcm: Arc<dyn PushSender>, apns: Arc<dyn PushSender>, telegram: Arc<dyn PushSender>) -> Self {
+        Self { fcm, apns, telegram }
+    }
+
+    pub fn from_config() -> anyhow::Result<Self> {
+        let fcm: Arc<dyn PushSender> = match &CONFIG.fcm_project_id {
+            Some(project_id) => Arc::new(FcmSender::new(project_id)?),
+            None => Self::dev_sink("fcm", "FCM_PROJECT_ID")?
+        };
+        let apns: Arc<dyn PushSender> = match (&CONFIG.apns_key_path, &CONFIG.apns_key_id, &CONFIG.apns_team_id, &CONFIG.apns_topic) {
+            (Some(key_path), Some(key_id), Some(team_id), Some(topic)) => Arc::new(
+                ApnsSender::new(key_path, key_id, team_id, topic, CONFIG.apns_sandbox)?
+            ),
+            _ => Self::dev_sink("apns", "APNS_KEY_PATH, APNS_KEY_ID, APNS_TEAM_ID and APNS_TOPIC")?
+        };
+        let telegram = Arc::new(TelegramSender::new(&CONFIG.telegram_api_base_url, &CONFIG.telegram_bot_token)?);
+        Ok(Self::new(fcm, apns, telegram))
+    }
+
+    fn dev_sink(name: &'static str, missing: &str) -> anyhow::Result<Arc<dyn PushSender>> {
+        if !CONFIG.push_dev_sink {
+            anyhow::bail!("{missing} missing; set PUSH_DEV_SINK=true to log {name} pushes instead");
+        }
+        eprintln!("⚠️ {missing} missing: {name} pushes are only logged, NOT delivered (PUSH_DEV_SINK)");
+        Ok(Arc::new(LogSender::new(name)))
+    }
+
+    pub fn sender(&self, platform: DevicePlatform) -> &dyn PushSender {
+        match platform {
+            DevicePlatform::Android | DevicePlatform::Web => self.fcm.as_ref(),
+            DevicePlatform::Ios => self.apns.as_ref()
+        }
+    }
+
+    /// Sends to a chat id.
+    pub fn telegram(&self) -> &dyn PushSender {
+        self.telegram.as_ref()
+    }
+}
@@ -0,0 +1,141 @@
+use crate::push::{PushError, PushMessage, PushSender};
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Telegram allows about 30 messages a second overall and one a second per chat.
+const GLOBAL_INTERVAL: Duration = Duration::from_millis(34);
+const CHAT_INTERVAL: Duration = Duration::from_secs(1);
+/// Retries after 429, network errors and 5xx responses. The latter two wait
+/// twice as long each time; 429 waits as long as Telegram asks.
+const MAX_RETRIES: u32 = 4;
+const BASE_BACKOFF: Duration = Duration::from_millis(500);
+/// Past chat slots are dropped once the limiter holds this many chats.
+const CHAT_MEMORY: usize = 1024;
+
+#[derive(Deserialize)]
+struct ApiResponse {
+    description: Option<String>,
+    parameters: Option<ResponseParameters>
+}
+
+#[derive(Deserialize)]
+struct ResponseParameters {
+    retry_after: Option<u64>
+}
+
+/// Next free send slot overall and per chat.
+struct Limiter {
+    next: Instant,
+    chats: HashMap<String, Instant>
+}
+
+/// Bot API `sendMessage`, with the chat id as the token.
+pub struct TelegramSender {
+    client: reqwest::Client,
+    url: String,
+    limiter: Mutex<Limiter>
+}
+
+impl TelegramSender {
+    pub fn new(base_url: &str, bot_token: &str) -> anyhow::Result<Self> {
+        Ok(Self {
+            client: reqwest::Client::builder().timeout(Duration::from_secs(30)).build()?,
+            url: format!("{}/bot{}/sendMessage", base_url.trim_end_matches('/'), bot_token),
+            limiter: Mutex::new(Limiter { next: Instant::now(), chats: HashMap::new() })
+        })
+    }
+
+    /// Waits for the next slot that respects both the global and the chat rate.
+    async fn acquire(&self, chat_id: &str) {
+        let at = {
+            let now = Instant::now();
+            let mut limiter = self.limiter.lock().unwrap();
+            if limiter.chats.len() > CHAT_MEMORY {
+                limiter.chats.retain(|_, next| *next > now);
+            }
+            let at = [now, limiter.next, limiter.chats.get(chat_id).copied().unwrap_or(now)]
+                .into_iter()
+                .max()
+                .unwrap();
+            limiter.next = at + GLOBAL_INTERVAL;
+            limiter.chats.insert(chat_id.to_string(), at + CHAT_INTERVAL);
+            at
+        };
+        tokio::time::sleep_until(at).await;
+    }
+
+    /// Holds every send back, e.g. after Telegram answered 429.
+    fn pause(&self, wait: Duration) {
+        let mut limiter = self.limiter.lock().unwrap();
+        limiter.next = limiter.next.max(Instant::now() + wait);
+    }
+}
+
+#[async_trait]
+impl PushSender for TelegramSender {
+    async fn send(&self, token: &str, message: &PushMessage) -> Result<(), PushError> {
+        let payload = json!({
+            "chat_id": token,
+            "text": format!("<b>{}</b>\n{}", escape(&message.title), escape(&message.body)),
+            "parse_mode": "HTML"
+        });
+
+        let mut attempt = 0;
+        loop {
+            self.acquire(token).await;
+            let error = match self.client.post(&self.url).json(&payload).send().await {
+                Ok(response) => {
+                    let status = response.status();
+                    if status.is_success() {
+                        return Ok(());
+                    }
+                    let body = response.json::<ApiResponse>().await.ok();
+                    let description = body.as_ref()
+                        .and_then(|body| body.description.clone())
+                        .unwrap_or_default();
+                    match status {
+                        StatusCode::TOO_MANY_REQUESTS => {
+                            let retry_after = body
+                                .and_then(|body| body.parameters)
+                                .and_then(|parameters| parameters.retry_after)
+                                .unwrap_or(1);
+                            self.pause(Duration::from_secs(retry_after));
+                            if attempt == MAX_RETRIES {
+                                return Err(anyhow::anyhow!("Telegram rate limited: {description}").into());
+                            }
+                            attempt += 1;
+                            continue;
+                        }
+                        // Blocked by the user or the account is deleted
+                        StatusCode::FORBIDDEN => return Err(PushError::InvalidToken),
+                        StatusCode::BAD_REQUEST if description.contains("chat not found") => {
+                            return Err(PushError::InvalidToken);
+                        }
+                        status if status.is_server_error() => {
+                            anyhow::anyhow!("Telegram responded {status}: {description}")
+                        }
+                        status => return Err(anyhow::anyhow!("Telegram responded {status}: {description}").into())
+                    }
+                }
+                Err(err) => anyhow::Error::new(err).context("Telegram request")
+            };
+
+            if attempt == MAX_RETRIES {
+                return Err(error.into());
+            }
+            tokio::time::sleep(BASE_BACKOFF * 2u32.pow(attempt)).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Escapes text for the HTML parse mode.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
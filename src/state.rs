@@ -1,20 +1,23 @@
 use redis::Client;
 use sqlx::PgPool;
 use aws_sdk_s3 as s3;
+use crate::push::Push;
 
 #[derive(Clone)]
 pub struct AppState {
     pub db: PgPool,
     pub redis_client: Client,
-    pub s3_client: s3::Client
+    pub s3_client: s3::Client,
+    pub push: Push
 }
 
 impl AppState {
     pub fn new(
         db: PgPool,
         redis_client: Client,
-        s3_client: s3::Client
+        s3_client: s3::Client,
+        push: Push
     ) -> Self {
-        Self { db, redis_client, s3_client }
+        Self { db, redis_client, s3_client, push }
     }
 }
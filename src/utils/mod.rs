@@ -1,15 +1,22 @@
 pub mod telegram;
 pub mod jwt;
 pub mod cursor;
+pub mod search;
+pub mod subtitle;
 
 use crate::config::AppConfig;
+use fluent_templates::fluent_bundle::FluentValue;
 use fluent_templates::{static_loader, LanguageIdentifier, Loader};
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::sync::LazyLock;
 
 static_loader! {
     pub static LOCALES = {
         locales: "./src/locales",
         fallback_language: "en",
+        // no bidi isolation marks around arguments, push texts are shown as is
+        customise: |bundle| bundle.set_use_isolating(false),
     };
 }
 
@@ -19,6 +26,13 @@ pub fn t(lang_id: &LanguageIdentifier, key: &str) -> String {
     LOCALES.lookup(lang_id, key)
 }
 
+pub fn t_args(lang_id: &LanguageIdentifier, key: &str, args: Vec<(&'static str, FluentValue<'static>)>) -> String {
+    let args: HashMap<Cow<'static, str>, FluentValue> = args.into_iter()
+        .map(|(name, value)| (Cow::Borrowed(name), value))
+        .collect();
+    LOCALES.lookup_with_args(lang_id, key, &args)
+}
+
 pub mod extractors {
     use crate::error::{AppError};
     use axum::extract::{FromRequestParts, Path, Query, Request};
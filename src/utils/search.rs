@@ -0,0 +1,51 @@
+/// Put around matches by `ts_headline`. Private-use characters, so they
+/// cannot be confused with markup in the indexed text.
+const HIGHLIGHT_START: char = '\u{E000}';
+const HIGHLIGHT_STOP: char = '\u{E001}';
+
+/// `ts_headline` options for a whole highlighted field.
+pub const HIGHLIGHT_OPTIONS: &str = "HighlightAll=true, StartSel=\u{E000}, StopSel=\u{E001}";
+/// `ts_headline` options for a few fragments around the matches.
+pub const SNIPPET_OPTIONS: &str = "StartSel=\u{E000}, StopSel=\u{E001}, MaxWords=24, MinWords=8, MaxFragments=2";
+
+/// Apostrophe variants and Cyrillic hard/soft signs, dropped by `search_normalize`
+const SILENT_CHARS: &[char] = &['\'', '‘', '’', 'ʻ', 'ʼ', '`', '´', 'ъ', 'ь', 'Ъ', 'Ь'];
+
+/// Builds a prefix `tsquery` from free text: every word must match and each
+/// may be incomplete. Returns `None` when the text has no searchable words.
+/// Queries still have to pass through the SQL `search_normalize` function to
+/// match the indexed text.
+pub fn to_tsquery(search: &str) -> Option<String> {
+    let search: String = search.chars().filter(|c| !SILENT_CHARS.contains(c)).collect();
+    let terms: Vec<String> = search
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| format!("{}:*", word.to_lowercase()))
+        .collect();
+
+    if terms.is_empty() { None } else { Some(terms.join(" & ")) }
+}
+
+/// Turns a `ts_headline` result into HTML: the text is escaped and only the
+/// matches are wrapped in `<b>`, so clients can render it as is.
+pub fn highlight_to_html(headline: &str) -> String {
+    let mut html = String::with_capacity(headline.len());
+    let mut open = false;
+    for c in headline.chars() {
+        match c {
+            HIGHLIGHT_START if !open => { html.push_str("<b>"); open = true; }
+            HIGHLIGHT_STOP if open => { html.push_str("</b>"); open = false; }
+            HIGHLIGHT_START | HIGHLIGHT_STOP => {}
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            c => html.push(c)
+        }
+    }
+    if open {
+        html.push_str("</b>");
+    }
+    html
+}
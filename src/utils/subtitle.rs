@@ -0,0 +1,84 @@
+/// A cue parsed from a WebVTT or SRT file.
+pub struct Cue {
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub text: String,
+}
+
+/// Parses a run of ASCII digits, without a sign.
+fn parse_digits(value: &str) -> Option<i64> {
+    if value.is_empty() || !value.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+/// Parses `hh:mm:ss.mmm`, `mm:ss.mmm` (WebVTT) or `hh:mm:ss,mmm` (SRT).
+fn parse_timestamp(value: &str) -> Option<i64> {
+    let (clock, millis) = value.trim().split_once(['.', ','])?;
+    let millis = parse_digits(millis.get(..3)?)?;
+
+    let parts: Vec<i64> = clock
+        .split(':')
+        .map(parse_digits)
+        .collect::<Option<_>>()?;
+    let (hours, minutes, seconds) = match parts[..] {
+        [hours, minutes, seconds] => (hours, minutes, seconds),
+        [minutes, seconds] => (0, minutes, seconds),
+        _ => return None,
+    };
+    if minutes >= 60 || seconds >= 60 {
+        return None;
+    }
+
+    hours.checked_mul(3_600_000)?.checked_add((minutes * 60 + seconds) * 1000 + millis)
+}
+
+/// Removes WebVTT markup such as `<v Speaker>`, `<i>` and `<00:01.000>`.
+fn strip_tags(line: &str) -> String {
+    let mut text = String::with_capacity(line.len());
+    let mut in_tag = false;
+    for c in line.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&amp;", "&").replace("&lt;", "<").replace("&gt;", ">").replace("&nbsp;", " ")
+}
+
+/// Parses WebVTT or SRT content into cues. Blocks without a timing line
+/// (the `WEBVTT` header, `NOTE`, `STYLE`, `REGION`) are skipped. Returns the
+/// 1-based number of the first malformed block on error.
+pub fn parse(content: &str) -> Result<Vec<Cue>, usize> {
+    let content = content.trim_start_matches('\u{feff}').replace("\r\n", "\n").replace('\r', "\n");
+    let mut cues = Vec::new();
+
+    for (index, block) in content.split("\n\n").filter(|block| !block.trim().is_empty()).enumerate() {
+        let mut lines = block.lines().skip_while(|line| !line.contains("-->"));
+        let Some(timing) = lines.next() else { continue };
+
+        let (start, end) = timing.split_once("-->").ok_or(index + 1)?;
+        // WebVTT cue settings follow the end timestamp
+        let end = end.split_whitespace().next().unwrap_or_default();
+        let (Some(start_ms), Some(end_ms)) = (parse_timestamp(start), parse_timestamp(end)) else {
+            return Err(index + 1);
+        };
+
+        let text = lines
+            .map(strip_tags)
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        if text.is_empty() {
+            continue;
+        }
+
+        cues.push(Cue { start_ms, end_ms, text });
+    }
+
+    Ok(cues)
+}